@@ -0,0 +1,87 @@
+use std::env;
+use std::ffi::OsStr;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+#[allow(dead_code)]
+#[path = "src/geometry.rs"]
+mod geometry;
+#[allow(dead_code)]
+#[path = "src/normal.rs"]
+mod normal;
+#[allow(dead_code)]
+#[path = "src/obj.rs"]
+mod obj;
+#[allow(dead_code)]
+#[path = "src/weld.rs"]
+mod weld;
+
+// assets/*.obj を `pub mod <name> { VERTEX, INDEX, NORMAL, UV }` に変換して OUT_DIR/assets.rs に書き出す
+fn main() {
+    println!("cargo:rerun-if-changed=assets");
+    println!("cargo:rerun-if-changed=src/obj.rs");
+    println!("cargo:rerun-if-changed=src/geometry.rs");
+    println!("cargo:rerun-if-changed=src/normal.rs");
+    println!("cargo:rerun-if-changed=src/weld.rs");
+
+    let mut paths: Vec<_> = fs::read_dir("assets")
+        .expect("read assets")
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension() == Some(OsStr::new("obj")))
+        .collect();
+    paths.sort();
+
+    let mut out = String::new();
+    for path in paths.iter() {
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let name = module_name(path);
+        let source = fs::read_to_string(path).expect("read obj");
+        let geometry = obj::parse(&source)
+            .unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+            .geometry;
+
+        writeln!(out, "pub mod {} {{", name).unwrap();
+        write_array(&mut out, "VERTEX", "f32", &geometry.vertex, 3);
+        write_array(&mut out, "INDEX", "u16", &geometry.index, 3);
+        write_array(&mut out, "NORMAL", "f32", &geometry.normal, 3);
+        write_array(&mut out, "UV", "f32", &geometry.uv, 2);
+        writeln!(
+            out,
+            "    pub fn geometry() -> crate::geometry::Geometry {{\n        \
+             crate::geometry::Geometry {{\n            \
+             vertex: VERTEX.to_vec(),\n            \
+             normal: NORMAL.to_vec(),\n            \
+             uv: UV.to_vec(),\n            \
+             index: INDEX.to_vec(),\n        \
+             }}\n    }}"
+        )
+        .unwrap();
+        writeln!(out, "}}").unwrap();
+    }
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR");
+    fs::write(Path::new(&out_dir).join("assets.rs"), out).expect("write assets.rs");
+}
+
+fn module_name(path: &Path) -> String {
+    let stem = path.file_stem().unwrap().to_string_lossy();
+    let mut name: String = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    name
+}
+
+fn write_array<T: std::fmt::Debug>(out: &mut String, name: &str, ty: &str, values: &[T], row: usize) {
+    writeln!(out, "    pub static {}: &[{}] = &[", name, ty).unwrap();
+    for chunk in values.chunks(row * 4) {
+        let line: Vec<String> = chunk.iter().map(|v| format!("{:?}", v)).collect();
+        writeln!(out, "        {},", line.join(", ")).unwrap();
+    }
+    writeln!(out, "    ];").unwrap();
+}
@@ -1,5 +1,6 @@
-document.getElementById('canvas').setAttribute('width', document.documentElement.clientWidth);
-document.getElementById('canvas').setAttribute('height', document.documentElement.clientHeight);
-
 import('./pkg/index')
+    .then(m => {
+        // viewer.pause() / resume() / stop() / setCamera() などで制御できる
+        window.viewer = new m.Viewer('canvas');
+    })
     .catch(console.error);
@@ -0,0 +1,202 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlCanvasElement;
+
+use crate::controls::Controls;
+use crate::log;
+use crate::resize::{self, Resize};
+use crate::scene::Scene;
+
+struct State {
+    scene: Rc<RefCell<Scene>>,
+    controls: Option<Controls>,
+    canvas: Option<HtmlCanvasElement>,
+    resize: Option<Resize>,
+    paused: bool,
+    elapsed: f64,
+    last: Option<f64>,
+    handle: Option<i32>,
+    callback: Option<Closure<dyn FnMut(f64)>>,
+}
+
+// requestAnimationFrame で Scene::render を毎フレーム呼び出す
+#[wasm_bindgen]
+pub struct Animation {
+    state: Rc<RefCell<State>>,
+}
+
+impl Animation {
+    pub fn start(scene: Rc<RefCell<Scene>>) -> Result<Animation, JsValue> {
+        let state = Rc::new(RefCell::new(State {
+            scene,
+            controls: None,
+            canvas: None,
+            resize: None,
+            paused: false,
+            elapsed: 0.0,
+            last: None,
+            handle: None,
+            callback: None,
+        }));
+
+        let weak = Rc::downgrade(&state);
+        let callback = Closure::wrap(Box::new(move |timestamp: f64| {
+            if let Some(state) = weak.upgrade() {
+                frame(&state, timestamp);
+            }
+        }) as Box<dyn FnMut(f64)>);
+        state.borrow_mut().callback = Some(callback);
+
+        request(&mut state.borrow_mut())?;
+
+        Ok(Animation { state })
+    }
+
+    // ループと同じ期間だけ入力を受け付ける
+    pub fn set_controls(&self, controls: Controls) {
+        self.state.borrow_mut().controls = Some(controls);
+    }
+
+    // 毎フレームと canvas の ResizeObserver、window の resize で canvas の大きさを追従させる
+    pub fn observe_resize(&self, canvas: HtmlCanvasElement) -> Result<(), JsValue> {
+        let weak = Rc::downgrade(&self.state);
+        let observer = Resize::observe(&canvas, move || {
+            if let Some(state) = weak.upgrade() {
+                let mut state = state.borrow_mut();
+                // 停止中は resize した時だけ描き直す
+                if fit(&mut state) && state.paused {
+                    if let Err(e) = redraw(&mut state) {
+                        log::log(&format!("render error: {:?}", e));
+                    }
+                }
+            }
+        })?;
+
+        let mut state = self.state.borrow_mut();
+        state.canvas = Some(canvas);
+        state.resize = Some(observer);
+        fit(&mut state);
+
+        Ok(())
+    }
+
+    // 時間を進めずに 1 フレーム描く
+    pub fn redraw(&self) -> Result<(), JsValue> {
+        redraw(&mut self.state.borrow_mut())
+    }
+
+}
+
+#[wasm_bindgen]
+impl Animation {
+    pub fn pause(&self) -> Result<(), JsValue> {
+        let mut state = self.state.borrow_mut();
+        state.paused = true;
+        cancel(&mut state)
+    }
+
+    pub fn resume(&self) -> Result<(), JsValue> {
+        let mut state = self.state.borrow_mut();
+        if !state.paused || state.callback.is_none() {
+            return Ok(());
+        }
+        state.paused = false;
+        state.last = None;
+        request(&mut state)
+    }
+
+    // 以降は再開できない。コールバックを破棄して循環参照を切る
+    pub fn stop(&self) -> Result<(), JsValue> {
+        let mut state = self.state.borrow_mut();
+        state.paused = true;
+        cancel(&mut state)?;
+        state.callback = None;
+        state.controls = None;
+        state.resize = None;
+        Ok(())
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn paused(&self) -> bool {
+        self.state.borrow().paused
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn elapsed(&self) -> f64 {
+        self.state.borrow().elapsed
+    }
+}
+
+impl Drop for Animation {
+    fn drop(&mut self) {
+        let _ = self.stop();
+    }
+}
+
+fn frame(state: &Rc<RefCell<State>>, timestamp: f64) {
+    let mut state = state.borrow_mut();
+    state.handle = None;
+    if state.paused {
+        return;
+    }
+
+    // timestamp はミリ秒
+    let delta = state.last.map_or(0.0, |last| (timestamp - last) / 1000.0);
+    state.last = Some(timestamp);
+    state.elapsed += delta;
+
+    fit(&mut state);
+
+    let elapsed = state.elapsed as f32;
+    let result = state.scene.borrow_mut().render(elapsed, delta as f32);
+    if let Err(e) = result {
+        log::log(&format!("render error: {:?}", e));
+        state.paused = true;
+        return;
+    }
+
+    if let Err(e) = request(&mut state) {
+        log::log(&format!("requestAnimationFrame error: {:?}", e));
+    }
+}
+
+fn fit(state: &mut State) -> bool {
+    let size = state.canvas.as_ref().and_then(resize::fit_canvas);
+    if let Some((width, height)) = size {
+        state.scene.borrow_mut().resize(width, height);
+    }
+
+    size.is_some()
+}
+
+fn redraw(state: &mut State) -> Result<(), JsValue> {
+    fit(state);
+    let elapsed = state.elapsed as f32;
+    state.scene.borrow_mut().render(elapsed, 0.0).map_err(JsValue::from)
+}
+
+fn request(state: &mut State) -> Result<(), JsValue> {
+    let callback = match state.callback.as_ref() {
+        Some(callback) => callback,
+        None => return Ok(()),
+    };
+
+    let window = web_sys::window().ok_or("no window")?;
+    let handle = window.request_animation_frame(callback.as_ref().unchecked_ref())?;
+    state.handle = Some(handle);
+
+    Ok(())
+}
+
+fn cancel(state: &mut State) -> Result<(), JsValue> {
+    if let Some(handle) = state.handle.take() {
+        web_sys::window()
+            .ok_or("no window")?
+            .cancel_animation_frame(handle)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,454 @@
+use std::fmt::Debug;
+
+use web_sys::{
+    WebGlBuffer, WebGlFramebuffer, WebGlProgram, WebGlRenderbuffer, WebGlRenderingContext, WebGlShader, WebGlTexture,
+    WebGlUniformLocation,
+};
+
+// getActiveUniform / getActiveAttrib の結果
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActiveInfo {
+    pub name: String,
+    pub size: i32,
+    pub kind: u32,
+}
+
+// この crate が使う GL の呼び出し。WebGlRenderingContext、recording::Recorder と software::Software が実装する
+// 名前と引数は web-sys に合わせ、JS の型を受け渡すものだけ Rust の型にしている。エラーも JsValue ではなく String で返す
+// 定数は WebGlRenderingContext::TRIANGLES などをそのまま使う
+pub trait Backend: Clone + 'static {
+    type Buffer: Clone + Debug;
+    type Texture: Clone + Debug;
+    type Framebuffer: Clone + Debug;
+    type Renderbuffer: Clone + Debug;
+    type Shader: Clone + Debug;
+    type Program: Clone + Debug;
+    type UniformLocation: Clone + Debug;
+
+    // getExtension と同じく、使えればその場で有効になる
+    fn enable_extension(&self, name: &str) -> bool;
+
+    fn viewport(&self, x: i32, y: i32, width: i32, height: i32);
+    fn clear_color(&self, red: f32, green: f32, blue: f32, alpha: f32);
+    fn clear_depth(&self, depth: f32);
+    fn clear(&self, mask: u32);
+    fn enable(&self, cap: u32);
+    fn depth_func(&self, func: u32);
+    fn flush(&self);
+
+    fn create_buffer(&self) -> Option<Self::Buffer>;
+    fn delete_buffer(&self, buffer: Option<&Self::Buffer>);
+    fn bind_buffer(&self, target: u32, buffer: Option<&Self::Buffer>);
+    fn buffer_data_with_f32_array(&self, target: u32, data: &[f32], usage: u32);
+    fn buffer_data_with_u16_array(&self, target: u32, data: &[u16], usage: u32);
+    fn buffer_data_with_u8_array(&self, target: u32, data: &[u8], usage: u32);
+
+    fn create_texture(&self) -> Option<Self::Texture>;
+    fn delete_texture(&self, texture: Option<&Self::Texture>);
+    fn bind_texture(&self, target: u32, texture: Option<&Self::Texture>);
+    fn active_texture(&self, texture: u32);
+    #[allow(clippy::too_many_arguments)]
+    fn tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+        &self,
+        target: u32,
+        level: i32,
+        internal_format: i32,
+        width: i32,
+        height: i32,
+        border: i32,
+        format: u32,
+        kind: u32,
+        pixels: Option<&[u8]>,
+    ) -> Result<(), String>;
+    fn tex_parameteri(&self, target: u32, pname: u32, param: i32);
+    fn generate_mipmap(&self, target: u32);
+
+    fn create_framebuffer(&self) -> Option<Self::Framebuffer>;
+    fn delete_framebuffer(&self, framebuffer: Option<&Self::Framebuffer>);
+    fn bind_framebuffer(&self, target: u32, framebuffer: Option<&Self::Framebuffer>);
+    fn check_framebuffer_status(&self, target: u32) -> u32;
+    fn framebuffer_texture_2d(
+        &self,
+        target: u32,
+        attachment: u32,
+        textarget: u32,
+        texture: Option<&Self::Texture>,
+        level: i32,
+    );
+    fn create_renderbuffer(&self) -> Option<Self::Renderbuffer>;
+    fn delete_renderbuffer(&self, renderbuffer: Option<&Self::Renderbuffer>);
+    fn bind_renderbuffer(&self, target: u32, renderbuffer: Option<&Self::Renderbuffer>);
+    fn renderbuffer_storage(&self, target: u32, format: u32, width: i32, height: i32);
+    fn framebuffer_renderbuffer(
+        &self,
+        target: u32,
+        attachment: u32,
+        renderbuffer_target: u32,
+        renderbuffer: Option<&Self::Renderbuffer>,
+    );
+
+    fn create_shader(&self, kind: u32) -> Option<Self::Shader>;
+    fn delete_shader(&self, shader: Option<&Self::Shader>);
+    fn shader_source(&self, shader: &Self::Shader, source: &str);
+    fn compile_shader(&self, shader: &Self::Shader);
+    fn shader_compile_status(&self, shader: &Self::Shader) -> bool;
+    fn get_shader_info_log(&self, shader: &Self::Shader) -> Option<String>;
+    // GL には無い呼び出し。shader::compile が library の名前 ("pbr.frag" など) を compile した shader に付ける
+    // GLSL を実行できない Backend はこの名前で program を見分ける
+    fn label_shader(&self, _shader: &Self::Shader, _name: &str) {}
+
+    fn create_program(&self) -> Option<Self::Program>;
+    fn delete_program(&self, program: Option<&Self::Program>);
+    fn attach_shader(&self, program: &Self::Program, shader: &Self::Shader);
+    fn link_program(&self, program: &Self::Program);
+    fn program_link_status(&self, program: &Self::Program) -> bool;
+    fn get_program_info_log(&self, program: &Self::Program) -> Option<String>;
+    fn use_program(&self, program: Option<&Self::Program>);
+    fn active_uniform_count(&self, program: &Self::Program) -> u32;
+    fn active_attribute_count(&self, program: &Self::Program) -> u32;
+    fn active_uniform(&self, program: &Self::Program, index: u32) -> Option<ActiveInfo>;
+    fn active_attribute(&self, program: &Self::Program, index: u32) -> Option<ActiveInfo>;
+    fn get_uniform_location(&self, program: &Self::Program, name: &str) -> Option<Self::UniformLocation>;
+    fn get_attrib_location(&self, program: &Self::Program, name: &str) -> i32;
+
+    fn uniform1i(&self, location: Option<&Self::UniformLocation>, x: i32);
+    fn uniform1f(&self, location: Option<&Self::UniformLocation>, x: f32);
+    fn uniform2f(&self, location: Option<&Self::UniformLocation>, x: f32, y: f32);
+    fn uniform2fv_with_f32_array(&self, location: Option<&Self::UniformLocation>, data: &[f32]);
+    fn uniform3fv_with_f32_array(&self, location: Option<&Self::UniformLocation>, data: &[f32]);
+    fn uniform4fv_with_f32_array(&self, location: Option<&Self::UniformLocation>, data: &[f32]);
+    fn uniform_matrix4fv_with_f32_array(&self, location: Option<&Self::UniformLocation>, transpose: bool, data: &[f32]);
+
+    fn enable_vertex_attrib_array(&self, index: u32);
+    fn disable_vertex_attrib_array(&self, index: u32);
+    fn vertex_attrib_pointer_with_i32(&self, index: u32, size: i32, kind: u32, normalized: bool, stride: i32, offset: i32);
+    fn vertex_attrib4f(&self, index: u32, x: f32, y: f32, z: f32, w: f32);
+
+    fn draw_elements_with_i32(&self, mode: u32, count: i32, kind: u32, offset: i32);
+    fn draw_arrays(&self, mode: u32, first: i32, count: i32);
+}
+
+// 同じ名前の web-sys のメソッドに渡すだけ (inherent のメソッドが優先される)
+impl Backend for WebGlRenderingContext {
+    type Buffer = WebGlBuffer;
+    type Texture = WebGlTexture;
+    type Framebuffer = WebGlFramebuffer;
+    type Renderbuffer = WebGlRenderbuffer;
+    type Shader = WebGlShader;
+    type Program = WebGlProgram;
+    type UniformLocation = WebGlUniformLocation;
+
+    fn enable_extension(&self, name: &str) -> bool {
+        matches!(self.get_extension(name), Ok(Some(_)))
+    }
+
+    fn viewport(&self, x: i32, y: i32, width: i32, height: i32) {
+        WebGlRenderingContext::viewport(self, x, y, width, height)
+    }
+
+    fn clear_color(&self, red: f32, green: f32, blue: f32, alpha: f32) {
+        WebGlRenderingContext::clear_color(self, red, green, blue, alpha)
+    }
+
+    fn clear_depth(&self, depth: f32) {
+        WebGlRenderingContext::clear_depth(self, depth)
+    }
+
+    fn clear(&self, mask: u32) {
+        WebGlRenderingContext::clear(self, mask)
+    }
+
+    fn enable(&self, cap: u32) {
+        WebGlRenderingContext::enable(self, cap)
+    }
+
+    fn depth_func(&self, func: u32) {
+        WebGlRenderingContext::depth_func(self, func)
+    }
+
+    fn flush(&self) {
+        WebGlRenderingContext::flush(self)
+    }
+
+    fn create_buffer(&self) -> Option<WebGlBuffer> {
+        WebGlRenderingContext::create_buffer(self)
+    }
+
+    fn delete_buffer(&self, buffer: Option<&WebGlBuffer>) {
+        WebGlRenderingContext::delete_buffer(self, buffer)
+    }
+
+    fn bind_buffer(&self, target: u32, buffer: Option<&WebGlBuffer>) {
+        WebGlRenderingContext::bind_buffer(self, target, buffer)
+    }
+
+    // view は wasm のメモリを直接指すので、buffer_data が終わるまで Rust 側で確保し直さない
+    fn buffer_data_with_f32_array(&self, target: u32, data: &[f32], usage: u32) {
+        unsafe {
+            let view = js_sys::Float32Array::view(data);
+            self.buffer_data_with_array_buffer_view(target, &view, usage);
+        }
+    }
+
+    fn buffer_data_with_u16_array(&self, target: u32, data: &[u16], usage: u32) {
+        unsafe {
+            let view = js_sys::Uint16Array::view(data);
+            self.buffer_data_with_array_buffer_view(target, &view, usage);
+        }
+    }
+
+    fn buffer_data_with_u8_array(&self, target: u32, data: &[u8], usage: u32) {
+        unsafe {
+            let view = js_sys::Uint8Array::view(data);
+            self.buffer_data_with_array_buffer_view(target, &view, usage);
+        }
+    }
+
+    fn create_texture(&self) -> Option<WebGlTexture> {
+        WebGlRenderingContext::create_texture(self)
+    }
+
+    fn delete_texture(&self, texture: Option<&WebGlTexture>) {
+        WebGlRenderingContext::delete_texture(self, texture)
+    }
+
+    fn bind_texture(&self, target: u32, texture: Option<&WebGlTexture>) {
+        WebGlRenderingContext::bind_texture(self, target, texture)
+    }
+
+    fn active_texture(&self, texture: u32) {
+        WebGlRenderingContext::active_texture(self, texture)
+    }
+
+    fn tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+        &self,
+        target: u32,
+        level: i32,
+        internal_format: i32,
+        width: i32,
+        height: i32,
+        border: i32,
+        format: u32,
+        kind: u32,
+        pixels: Option<&[u8]>,
+    ) -> Result<(), String> {
+        WebGlRenderingContext::tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+            self,
+            target,
+            level,
+            internal_format,
+            width,
+            height,
+            border,
+            format,
+            kind,
+            pixels,
+        )
+        .map_err(|e| e.as_string().unwrap_or_else(|| format!("{:?}", e)))
+    }
+
+    fn tex_parameteri(&self, target: u32, pname: u32, param: i32) {
+        WebGlRenderingContext::tex_parameteri(self, target, pname, param)
+    }
+
+    fn generate_mipmap(&self, target: u32) {
+        WebGlRenderingContext::generate_mipmap(self, target)
+    }
+
+    fn create_framebuffer(&self) -> Option<WebGlFramebuffer> {
+        WebGlRenderingContext::create_framebuffer(self)
+    }
+
+    fn delete_framebuffer(&self, framebuffer: Option<&WebGlFramebuffer>) {
+        WebGlRenderingContext::delete_framebuffer(self, framebuffer)
+    }
+
+    fn bind_framebuffer(&self, target: u32, framebuffer: Option<&WebGlFramebuffer>) {
+        WebGlRenderingContext::bind_framebuffer(self, target, framebuffer)
+    }
+
+    fn check_framebuffer_status(&self, target: u32) -> u32 {
+        WebGlRenderingContext::check_framebuffer_status(self, target)
+    }
+
+    fn framebuffer_texture_2d(
+        &self,
+        target: u32,
+        attachment: u32,
+        textarget: u32,
+        texture: Option<&WebGlTexture>,
+        level: i32,
+    ) {
+        WebGlRenderingContext::framebuffer_texture_2d(self, target, attachment, textarget, texture, level)
+    }
+
+    fn create_renderbuffer(&self) -> Option<WebGlRenderbuffer> {
+        WebGlRenderingContext::create_renderbuffer(self)
+    }
+
+    fn delete_renderbuffer(&self, renderbuffer: Option<&WebGlRenderbuffer>) {
+        WebGlRenderingContext::delete_renderbuffer(self, renderbuffer)
+    }
+
+    fn bind_renderbuffer(&self, target: u32, renderbuffer: Option<&WebGlRenderbuffer>) {
+        WebGlRenderingContext::bind_renderbuffer(self, target, renderbuffer)
+    }
+
+    fn renderbuffer_storage(&self, target: u32, format: u32, width: i32, height: i32) {
+        WebGlRenderingContext::renderbuffer_storage(self, target, format, width, height)
+    }
+
+    fn framebuffer_renderbuffer(
+        &self,
+        target: u32,
+        attachment: u32,
+        renderbuffer_target: u32,
+        renderbuffer: Option<&WebGlRenderbuffer>,
+    ) {
+        WebGlRenderingContext::framebuffer_renderbuffer(self, target, attachment, renderbuffer_target, renderbuffer)
+    }
+
+    fn create_shader(&self, kind: u32) -> Option<WebGlShader> {
+        WebGlRenderingContext::create_shader(self, kind)
+    }
+
+    fn delete_shader(&self, shader: Option<&WebGlShader>) {
+        WebGlRenderingContext::delete_shader(self, shader)
+    }
+
+    fn shader_source(&self, shader: &WebGlShader, source: &str) {
+        WebGlRenderingContext::shader_source(self, shader, source)
+    }
+
+    fn compile_shader(&self, shader: &WebGlShader) {
+        WebGlRenderingContext::compile_shader(self, shader)
+    }
+
+    fn shader_compile_status(&self, shader: &WebGlShader) -> bool {
+        self.get_shader_parameter(shader, WebGlRenderingContext::COMPILE_STATUS)
+            .as_bool()
+            .unwrap_or(false)
+    }
+
+    fn get_shader_info_log(&self, shader: &WebGlShader) -> Option<String> {
+        WebGlRenderingContext::get_shader_info_log(self, shader)
+    }
+
+    fn create_program(&self) -> Option<WebGlProgram> {
+        WebGlRenderingContext::create_program(self)
+    }
+
+    fn delete_program(&self, program: Option<&WebGlProgram>) {
+        WebGlRenderingContext::delete_program(self, program)
+    }
+
+    fn attach_shader(&self, program: &WebGlProgram, shader: &WebGlShader) {
+        WebGlRenderingContext::attach_shader(self, program, shader)
+    }
+
+    fn link_program(&self, program: &WebGlProgram) {
+        WebGlRenderingContext::link_program(self, program)
+    }
+
+    fn program_link_status(&self, program: &WebGlProgram) -> bool {
+        self.get_program_parameter(program, WebGlRenderingContext::LINK_STATUS)
+            .as_bool()
+            .unwrap_or(false)
+    }
+
+    fn get_program_info_log(&self, program: &WebGlProgram) -> Option<String> {
+        WebGlRenderingContext::get_program_info_log(self, program)
+    }
+
+    fn use_program(&self, program: Option<&WebGlProgram>) {
+        WebGlRenderingContext::use_program(self, program)
+    }
+
+    fn active_uniform_count(&self, program: &WebGlProgram) -> u32 {
+        self.get_program_parameter(program, WebGlRenderingContext::ACTIVE_UNIFORMS)
+            .as_f64()
+            .unwrap_or(0.0) as u32
+    }
+
+    fn active_attribute_count(&self, program: &WebGlProgram) -> u32 {
+        self.get_program_parameter(program, WebGlRenderingContext::ACTIVE_ATTRIBUTES)
+            .as_f64()
+            .unwrap_or(0.0) as u32
+    }
+
+    fn active_uniform(&self, program: &WebGlProgram, index: u32) -> Option<ActiveInfo> {
+        self.get_active_uniform(program, index).map(|info| ActiveInfo {
+            name: info.name(),
+            size: info.size(),
+            kind: info.type_(),
+        })
+    }
+
+    fn active_attribute(&self, program: &WebGlProgram, index: u32) -> Option<ActiveInfo> {
+        self.get_active_attrib(program, index).map(|info| ActiveInfo {
+            name: info.name(),
+            size: info.size(),
+            kind: info.type_(),
+        })
+    }
+
+    fn get_uniform_location(&self, program: &WebGlProgram, name: &str) -> Option<WebGlUniformLocation> {
+        WebGlRenderingContext::get_uniform_location(self, program, name)
+    }
+
+    fn get_attrib_location(&self, program: &WebGlProgram, name: &str) -> i32 {
+        WebGlRenderingContext::get_attrib_location(self, program, name)
+    }
+
+    fn uniform1i(&self, location: Option<&WebGlUniformLocation>, x: i32) {
+        WebGlRenderingContext::uniform1i(self, location, x)
+    }
+
+    fn uniform1f(&self, location: Option<&WebGlUniformLocation>, x: f32) {
+        WebGlRenderingContext::uniform1f(self, location, x)
+    }
+
+    fn uniform2f(&self, location: Option<&WebGlUniformLocation>, x: f32, y: f32) {
+        WebGlRenderingContext::uniform2f(self, location, x, y)
+    }
+
+    fn uniform2fv_with_f32_array(&self, location: Option<&WebGlUniformLocation>, data: &[f32]) {
+        WebGlRenderingContext::uniform2fv_with_f32_array(self, location, data)
+    }
+
+    fn uniform3fv_with_f32_array(&self, location: Option<&WebGlUniformLocation>, data: &[f32]) {
+        WebGlRenderingContext::uniform3fv_with_f32_array(self, location, data)
+    }
+
+    fn uniform4fv_with_f32_array(&self, location: Option<&WebGlUniformLocation>, data: &[f32]) {
+        WebGlRenderingContext::uniform4fv_with_f32_array(self, location, data)
+    }
+
+    fn uniform_matrix4fv_with_f32_array(&self, location: Option<&WebGlUniformLocation>, transpose: bool, data: &[f32]) {
+        WebGlRenderingContext::uniform_matrix4fv_with_f32_array(self, location, transpose, data)
+    }
+
+    fn enable_vertex_attrib_array(&self, index: u32) {
+        WebGlRenderingContext::enable_vertex_attrib_array(self, index)
+    }
+
+    fn disable_vertex_attrib_array(&self, index: u32) {
+        WebGlRenderingContext::disable_vertex_attrib_array(self, index)
+    }
+
+    fn vertex_attrib_pointer_with_i32(&self, index: u32, size: i32, kind: u32, normalized: bool, stride: i32, offset: i32) {
+        WebGlRenderingContext::vertex_attrib_pointer_with_i32(self, index, size, kind, normalized, stride, offset)
+    }
+
+    fn vertex_attrib4f(&self, index: u32, x: f32, y: f32, z: f32, w: f32) {
+        WebGlRenderingContext::vertex_attrib4f(self, index, x, y, z, w)
+    }
+
+    fn draw_elements_with_i32(&self, mode: u32, count: i32, kind: u32, offset: i32) {
+        WebGlRenderingContext::draw_elements_with_i32(self, mode, count, kind, offset)
+    }
+
+    fn draw_arrays(&self, mode: u32, first: i32, count: i32) {
+        WebGlRenderingContext::draw_arrays(self, mode, first, count)
+    }
+}
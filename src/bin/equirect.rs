@@ -0,0 +1,44 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+use teapot::equirect::{self, Panorama};
+use teapot::texture::FACE_NAMES;
+
+// equirect <panorama.hdr|png> <output dir> [face size]
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 3 {
+        eprintln!("usage: {} <panorama> <output dir> [face size]", args[0]);
+        process::exit(1);
+    }
+
+    let size = match args.get(3).map(|size| size.parse::<u32>()) {
+        Some(Ok(size)) if size > 0 => size,
+        Some(_) => {
+            eprintln!("face size must be a positive integer");
+            process::exit(1);
+        }
+        None => 512,
+    };
+
+    if let Err(e) = run(&args[1], Path::new(&args[2]), size) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+fn run(input: &str, output: &Path, size: u32) -> Result<(), String> {
+    let source = fs::read(input).map_err(|e| format!("{}: {}", input, e))?;
+    let panorama = Panorama::decode(&source).map_err(|e| format!("{}: {}", input, e))?;
+
+    fs::create_dir_all(output).map_err(|e| format!("{}: {}", output.display(), e))?;
+    for (name, face) in FACE_NAMES.iter().zip(equirect::to_cubemap(&panorama, size).iter()) {
+        let path = output.join(format!("{}.png", name));
+        face.save(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        println!("{}", path.display());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use std::env;
+use std::process;
+
+use teapot::backend::Backend;
+use teapot::material::{Glass, Material, Pbr};
+use teapot::scene::Scene;
+use teapot::software::Software;
+use web_sys::WebGlRenderingContext;
+
+// render <output.png> [width] [height] [blinn-phong|pbr|glass]
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 2 {
+        eprintln!("usage: {} <output.png> [width] [height] [blinn-phong|pbr|glass]", args[0]);
+        process::exit(1);
+    }
+
+    let size = |index: usize, default: u32| match args.get(index).map(|size| size.parse::<u32>()) {
+        Some(Ok(size)) if size > 0 => size,
+        Some(_) => {
+            eprintln!("width and height must be positive integers");
+            process::exit(1);
+        }
+        None => default,
+    };
+    let (width, height) = (size(2, 640), size(3, 480));
+
+    let material = match args.get(4).map(|m| m.as_str()) {
+        None | Some("blinn-phong") => Material::default(),
+        Some("pbr") => Material::Pbr(Pbr::default()),
+        Some("glass") => Material::Glass(Glass::default()),
+        Some(other) => {
+            eprintln!("unknown material: {}", other);
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = run(&args[1], width, height, material) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+fn run(output: &str, width: u32, height: u32, material: Material) -> Result<(), String> {
+    let context = Software::new(width, height);
+    context.clear_depth(1.0);
+    context.enable(WebGlRenderingContext::DEPTH_TEST);
+    context.depth_func(WebGlRenderingContext::LEQUAL);
+
+    let mut scene = Scene::new_with_context(width as i32, height as i32, &context)?;
+    scene.set_material(material);
+    scene.render(0.0, 0.0)?;
+
+    context.save_png(output)?;
+    println!("{}", output);
+
+    Ok(())
+}
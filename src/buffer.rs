@@ -1,41 +1,78 @@
-use wasm_bindgen::prelude::*;
-use web_sys::{WebGlBuffer, WebGlRenderingContext};
+use std::ops::Deref;
 
-pub fn vertex_buffer(context: &WebGlRenderingContext, vertex: &[f32]) -> Result<WebGlBuffer, JsValue> {
-    let buffer = context.create_buffer().ok_or("create buffer")?;
-    context.bind_buffer(WebGlRenderingContext::ARRAY_BUFFER, Some(&buffer));
+use web_sys::WebGlRenderingContext;
+
+use crate::backend::Backend;
+
+// drop で delete_buffer する GL のバッファ
+pub struct Buffer<C: Backend = WebGlRenderingContext> {
+    context: C,
+    buffer: C::Buffer,
+}
+
+impl<C: Backend> Buffer<C> {
+    pub fn new(context: &C) -> Result<Buffer<C>, String> {
+        let buffer = context.create_buffer().ok_or("create buffer")?;
+
+        Ok(Buffer {
+            context: context.clone(),
+            buffer,
+        })
+    }
+}
+
+impl<C: Backend> Deref for Buffer<C> {
+    type Target = C::Buffer;
 
-    unsafe {
-        let vert_array = js_sys::Float32Array::view(vertex);
+    fn deref(&self) -> &C::Buffer {
+        &self.buffer
+    }
+}
 
-        context.buffer_data_with_array_buffer_view(
-            WebGlRenderingContext::ARRAY_BUFFER,
-            &vert_array,
-            WebGlRenderingContext::STATIC_DRAW,
-        );
+impl<C: Backend> Drop for Buffer<C> {
+    fn drop(&mut self) {
+        self.context.delete_buffer(Some(&self.buffer));
     }
+}
+
+pub fn vertex_buffer<C: Backend>(context: &C, vertex: &[f32]) -> Result<Buffer<C>, String> {
+    let buffer = Buffer::new(context)?;
+    context.bind_buffer(WebGlRenderingContext::ARRAY_BUFFER, Some(&buffer));
+    context.buffer_data_with_f32_array(
+        WebGlRenderingContext::ARRAY_BUFFER,
+        vertex,
+        WebGlRenderingContext::STATIC_DRAW,
+    );
 
     Ok(buffer)
 }
 
-pub fn index_buffer(context: &WebGlRenderingContext, indexes: &[u16]) -> Result<WebGlBuffer, JsValue> {
-    let buffer = context.create_buffer().ok_or("create buffer")?;
-    context.bind_buffer(WebGlRenderingContext::ELEMENT_ARRAY_BUFFER, Some(&buffer));
+// 頂点色など UNSIGNED_BYTE で読む頂点データ
+pub fn byte_buffer<C: Backend>(context: &C, data: &[u8]) -> Result<Buffer<C>, String> {
+    let buffer = Buffer::new(context)?;
+    context.bind_buffer(WebGlRenderingContext::ARRAY_BUFFER, Some(&buffer));
+    context.buffer_data_with_u8_array(
+        WebGlRenderingContext::ARRAY_BUFFER,
+        data,
+        WebGlRenderingContext::STATIC_DRAW,
+    );
 
-    unsafe {
-        let index_array = js_sys::Uint16Array::view(indexes);
+    Ok(buffer)
+}
 
-        context.buffer_data_with_array_buffer_view(
-            WebGlRenderingContext::ELEMENT_ARRAY_BUFFER,
-            &index_array,
-            WebGlRenderingContext::STATIC_DRAW,
-        );
-    }
+pub fn index_buffer<C: Backend>(context: &C, indexes: &[u16]) -> Result<Buffer<C>, String> {
+    let buffer = Buffer::new(context)?;
+    context.bind_buffer(WebGlRenderingContext::ELEMENT_ARRAY_BUFFER, Some(&buffer));
+    context.buffer_data_with_u16_array(
+        WebGlRenderingContext::ELEMENT_ARRAY_BUFFER,
+        indexes,
+        WebGlRenderingContext::STATIC_DRAW,
+    );
 
     Ok(buffer)
 }
 
-pub fn render_buffer(context: &WebGlRenderingContext, buffer: Option<&WebGlBuffer>, position: i32, num_vertex: i32) -> Result<(), JsValue> {
+pub fn render_buffer<C: Backend>(context: &C, buffer: Option<&C::Buffer>, position: i32, num_vertex: i32) -> Result<(), String> {
     context
         .bind_buffer(WebGlRenderingContext::ARRAY_BUFFER, buffer);
 
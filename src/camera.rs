@@ -0,0 +1,91 @@
+use nalgebra_glm::{Mat4, Vec3};
+
+// target を中心に回る orbit カメラ
+#[derive(Clone, Debug)]
+pub struct Camera {
+    pub target: Vec3,
+    pub distance: f32,
+    pub yaw: f32,
+    pub pitch: f32,
+
+    pub fov: f32,
+    pub aspect: f32,
+    pub near: f32,
+    pub far: f32,
+
+    pub min_distance: f32,
+    pub max_distance: f32,
+}
+
+const PITCH_LIMIT: f32 = std::f32::consts::FRAC_PI_2 - 0.01;
+
+impl Camera {
+    pub fn new(aspect: f32) -> Self {
+        // (0, 0, -10) から原点を見る
+        Camera {
+            target: Vec3::zeros(),
+            distance: 10.0,
+            yaw: std::f32::consts::PI,
+            pitch: 0.0,
+
+            fov: std::f32::consts::FRAC_PI_3,
+            aspect,
+            near: 0.1,
+            far: 200.0,
+
+            min_distance: 1.0,
+            max_distance: 80.0,
+        }
+    }
+
+    pub fn eye(&self) -> Vec3 {
+        let offset = Vec3::new(
+            self.yaw.sin() * self.pitch.cos(),
+            self.pitch.sin(),
+            self.yaw.cos() * self.pitch.cos(),
+        );
+
+        self.target + offset * self.distance
+    }
+
+    pub fn view_matrix(&self) -> Mat4 {
+        nalgebra_glm::look_at(&self.eye(), &self.target, &Vec3::y())
+    }
+
+    pub fn projection_matrix(&self) -> Mat4 {
+        nalgebra_glm::perspective(self.aspect, self.fov, self.near, self.far)
+    }
+
+    pub fn look_at(&mut self, eye: &Vec3, target: &Vec3) {
+        let offset = eye - target;
+        let distance = offset.norm();
+        if distance <= 0.0 {
+            return;
+        }
+
+        self.target = *target;
+        self.distance = distance.clamp(self.min_distance, self.max_distance);
+        self.pitch = (offset.y / distance).asin().clamp(-PITCH_LIMIT, PITCH_LIMIT);
+        self.yaw = offset.x.atan2(offset.z);
+    }
+
+    // radian
+    pub fn orbit(&mut self, yaw: f32, pitch: f32) {
+        self.yaw += yaw;
+        self.pitch = (self.pitch + pitch).clamp(-PITCH_LIMIT, PITCH_LIMIT);
+    }
+
+    // factor > 1 で遠ざかる
+    pub fn zoom(&mut self, factor: f32) {
+        self.distance = (self.distance * factor).clamp(self.min_distance, self.max_distance);
+    }
+
+    // 画面上の移動量 (distance を 1 とした割合) だけ target をずらす
+    pub fn pan(&mut self, dx: f32, dy: f32) {
+        let forward = (self.target - self.eye()).normalize();
+        let right = forward.cross(&Vec3::y()).normalize();
+        let up = right.cross(&forward);
+
+        self.target += (right * -dx + up * dy) * self.distance;
+    }
+}
@@ -0,0 +1,195 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Event, EventTarget, HtmlCanvasElement, MouseEvent, TouchEvent, TouchList, WheelEvent};
+
+use crate::camera::Camera;
+
+const ROTATE_SPEED: f32 = 0.005;
+const ZOOM_SPEED: f32 = 0.001;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    Orbit,
+    Pan,
+}
+
+#[derive(Default)]
+struct Pointer {
+    mode: Option<Mode>,
+    x: f32,
+    y: f32,
+    pinch: f32,
+}
+
+type Listener = Closure<dyn FnMut(Event)>;
+
+// マウス / ホイール / タッチでカメラを操作する。drop でイベントを外す
+pub struct Controls {
+    target: EventTarget,
+    listeners: Vec<(&'static str, Listener)>,
+}
+
+impl Controls {
+    pub fn attach(canvas: &HtmlCanvasElement, camera: Rc<RefCell<Camera>>) -> Result<Controls, JsValue> {
+        let pointer = Rc::new(RefCell::new(Pointer::default()));
+        let mut controls = Controls {
+            target: canvas.clone().into(),
+            listeners: Vec::new(),
+        };
+
+        // 左ドラッグで回転、右ドラッグで平行移動
+        {
+            let pointer = pointer.clone();
+            controls.listen("mousedown", move |event| {
+                let event = event.unchecked_ref::<MouseEvent>();
+                let mut pointer = pointer.borrow_mut();
+                pointer.mode = match event.button() {
+                    0 => Some(Mode::Orbit),
+                    2 => Some(Mode::Pan),
+                    _ => None,
+                };
+                pointer.x = event.client_x() as f32;
+                pointer.y = event.client_y() as f32;
+            })?;
+        }
+        {
+            let pointer = pointer.clone();
+            let camera = camera.clone();
+            let canvas = canvas.clone();
+            controls.listen("mousemove", move |event| {
+                let event = event.unchecked_ref::<MouseEvent>();
+                let mut pointer = pointer.borrow_mut();
+                let mode = match pointer.mode {
+                    Some(mode) => mode,
+                    None => return,
+                };
+
+                let (x, y) = (event.client_x() as f32, event.client_y() as f32);
+                drag(&mut camera.borrow_mut(), &canvas, mode, x - pointer.x, y - pointer.y);
+                pointer.x = x;
+                pointer.y = y;
+            })?;
+        }
+        for name in ["mouseup", "mouseleave"].iter() {
+            let pointer = pointer.clone();
+            controls.listen(name, move |_| pointer.borrow_mut().mode = None)?;
+        }
+        controls.listen("contextmenu", |event| event.prevent_default())?;
+
+        {
+            let camera = camera.clone();
+            controls.listen("wheel", move |event| {
+                event.prevent_default();
+                let event = event.unchecked_ref::<WheelEvent>();
+                camera
+                    .borrow_mut()
+                    .zoom((event.delta_y() as f32 * ZOOM_SPEED).exp());
+            })?;
+        }
+
+        // 1 本指で回転、2 本指でピンチ拡大縮小と平行移動
+        for name in ["touchstart", "touchend", "touchcancel"].iter() {
+            let pointer = pointer.clone();
+            controls.listen(name, move |event| {
+                let event = event.unchecked_ref::<TouchEvent>();
+                reset_touch(&mut pointer.borrow_mut(), &event.touches());
+            })?;
+        }
+        {
+            let pointer = pointer.clone();
+            let camera = camera.clone();
+            let canvas = canvas.clone();
+            controls.listen("touchmove", move |event| {
+                event.prevent_default();
+                let event = event.unchecked_ref::<TouchEvent>();
+                let touches = event.touches();
+                let mut pointer = pointer.borrow_mut();
+                let mut camera = camera.borrow_mut();
+
+                let (x, y, pinch) = match touch_center(&touches) {
+                    Some(center) => center,
+                    None => return,
+                };
+                match pointer.mode {
+                    Some(Mode::Orbit) => {
+                        drag(&mut camera, &canvas, Mode::Orbit, x - pointer.x, y - pointer.y)
+                    }
+                    Some(Mode::Pan) => {
+                        drag(&mut camera, &canvas, Mode::Pan, x - pointer.x, y - pointer.y);
+                        if pinch > 0.0 && pointer.pinch > 0.0 {
+                            camera.zoom(pointer.pinch / pinch);
+                        }
+                    }
+                    None => {}
+                }
+                pointer.x = x;
+                pointer.y = y;
+                pointer.pinch = pinch;
+            })?;
+        }
+
+        Ok(controls)
+    }
+
+    fn listen<F>(&mut self, name: &'static str, f: F) -> Result<(), JsValue>
+    where
+        F: FnMut(Event) + 'static,
+    {
+        let listener = Closure::wrap(Box::new(f) as Box<dyn FnMut(Event)>);
+        self.target
+            .add_event_listener_with_callback(name, listener.as_ref().unchecked_ref())?;
+        self.listeners.push((name, listener));
+
+        Ok(())
+    }
+}
+
+impl Drop for Controls {
+    fn drop(&mut self) {
+        for (name, listener) in self.listeners.iter() {
+            let _ = self
+                .target
+                .remove_event_listener_with_callback(name, listener.as_ref().unchecked_ref());
+        }
+    }
+}
+
+fn drag(camera: &mut Camera, canvas: &HtmlCanvasElement, mode: Mode, dx: f32, dy: f32) {
+    match mode {
+        Mode::Orbit => camera.orbit(-dx * ROTATE_SPEED, dy * ROTATE_SPEED),
+        Mode::Pan => {
+            let height = canvas.client_height().max(1) as f32;
+            camera.pan(dx / height, dy / height);
+        }
+    }
+}
+
+fn reset_touch(pointer: &mut Pointer, touches: &TouchList) {
+    pointer.mode = match touches.length() {
+        1 => Some(Mode::Orbit),
+        2 => Some(Mode::Pan),
+        _ => None,
+    };
+    if let Some((x, y, pinch)) = touch_center(touches) {
+        pointer.x = x;
+        pointer.y = y;
+        pointer.pinch = pinch;
+    }
+}
+
+// 最初の 2 本の指の中点と間隔
+fn touch_center(touches: &TouchList) -> Option<(f32, f32, f32)> {
+    let first = touches.get(0)?;
+    let (x0, y0) = (first.client_x() as f32, first.client_y() as f32);
+
+    match touches.get(1) {
+        Some(second) => {
+            let (x1, y1) = (second.client_x() as f32, second.client_y() as f32);
+            Some(((x0 + x1) * 0.5, (y0 + y1) * 0.5, (x1 - x0).hypot(y1 - y0)))
+        }
+        None => Some((x0, y0, 0.0)),
+    }
+}
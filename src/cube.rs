@@ -1,4 +1,4 @@
-pub static VERTEX:  &'static [f32] = &[
+pub static VERTEX:  &[f32] = &[
     -1.0, -1.0,  1.0,  1.0, -1.0,  1.0,  1.0,  1.0,  1.0, -1.0,  1.0,  1.0,
     -1.0, -1.0, -1.0, -1.0,  1.0, -1.0,  1.0,  1.0, -1.0,  1.0, -1.0, -1.0,
     -1.0,  1.0, -1.0, -1.0,  1.0,  1.0,  1.0,  1.0,  1.0,  1.0,  1.0, -1.0,
@@ -7,7 +7,7 @@ pub static VERTEX:  &'static [f32] = &[
     -1.0, -1.0, -1.0, -1.0, -1.0,  1.0, -1.0,  1.0,  1.0, -1.0,  1.0, -1.0
 ];
 
-pub static INDEX:  &'static [u16] = &[
+pub static INDEX:  &[u16] = &[
     0,  1,  2,  0,  2,  3,
     4,  5,  6,  4,  6,  7,
     8,  9, 10,  8, 10, 11,
@@ -16,11 +16,12 @@ pub static INDEX:  &'static [u16] = &[
     20, 21, 22, 20, 22, 23
 ];
 
-pub static NORMAL:  &'static [f32] = &[
-    -1.0, -1.0,  1.0,  1.0, -1.0,  1.0,  1.0,  1.0,  1.0, -1.0,  1.0,  1.0,
-    -1.0, -1.0, -1.0, -1.0,  1.0, -1.0,  1.0,  1.0, -1.0,  1.0, -1.0, -1.0,
-    -1.0,  1.0, -1.0, -1.0,  1.0,  1.0,  1.0,  1.0,  1.0,  1.0,  1.0, -1.0,
-    -1.0, -1.0, -1.0,  1.0, -1.0, -1.0,  1.0, -1.0,  1.0, -1.0, -1.0,  1.0,
-    1.0, -1.0, -1.0,  1.0,  1.0, -1.0,  1.0,  1.0,  1.0,  1.0, -1.0,  1.0,
-    -1.0, -1.0, -1.0, -1.0, -1.0,  1.0, -1.0,  1.0,  1.0, -1.0,  1.0, -1.0
-];
+// 面ごとの法線を生成する
+pub fn geometry() -> crate::geometry::Geometry {
+    crate::normal::flat(&crate::geometry::Geometry {
+        vertex: VERTEX.to_vec(),
+        normal: Vec::new(),
+        uv: Vec::new(),
+        index: INDEX.to_vec(),
+    })
+}
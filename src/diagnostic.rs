@@ -0,0 +1,261 @@
+use std::fmt;
+
+use wasm_bindgen::prelude::*;
+
+use crate::log;
+use crate::preprocessor::{Preprocessed, DEFINES};
+
+// エラー行の前後に表示する行数
+pub const CONTEXT_LINES: usize = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+// ドライバのログの 1 件。file と line は #include を辿った元の位置
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub file: Option<String>,
+    pub line: Option<usize>,
+    pub message: String,
+    // (元の行番号, 内容)。同じ file の前後 CONTEXT_LINES 行まで
+    pub context: Vec<(usize, String)>,
+}
+
+// shader の compile か program の link の失敗。stage は "pbr.frag" や "standard.vert + pbr.frag"
+#[derive(Clone, Debug)]
+pub struct ShaderError {
+    pub stage: String,
+    pub diagnostics: Vec<Diagnostic>,
+    // ドライバが返したそのままのログ
+    pub log: String,
+}
+
+impl ShaderError {
+    // 展開前の #include などの誤り
+    pub fn preprocess(stage: &str, message: String) -> ShaderError {
+        ShaderError {
+            stage: stage.to_string(),
+            diagnostics: vec![Diagnostic {
+                severity: Severity::Error,
+                file: None,
+                line: None,
+                message: message.clone(),
+                context: Vec::new(),
+            }],
+            log: message,
+        }
+    }
+
+    // ログの行番号を source の Origin で元の chunk の行に戻す
+    pub fn compile(stage: &str, log: &str, source: &Preprocessed) -> ShaderError {
+        let diagnostics = parse_log(log)
+            .into_iter()
+            .map(|(severity, line, message)| {
+                let origin = line.and_then(|line| source.origin(line).map(|origin| (line, origin)));
+                match origin {
+                    Some((line, origin)) => Diagnostic {
+                        severity,
+                        file: Some(origin.file.clone()),
+                        line: Some(origin.line),
+                        message,
+                        context: context(source, line),
+                    },
+                    None => Diagnostic {
+                        severity,
+                        file: None,
+                        line,
+                        message,
+                        context: Vec::new(),
+                    },
+                }
+            })
+            .collect();
+
+        ShaderError {
+            stage: stage.to_string(),
+            diagnostics,
+            log: log.to_string(),
+        }
+    }
+
+    // link のログは行番号を持たないことが多いので元の位置には戻さない
+    pub fn link(stage: &str, log: &str) -> ShaderError {
+        let diagnostics = parse_log(log)
+            .into_iter()
+            .map(|(severity, line, message)| Diagnostic {
+                severity,
+                file: None,
+                line,
+                message,
+                context: Vec::new(),
+            })
+            .collect();
+
+        ShaderError {
+            stage: stage.to_string(),
+            diagnostics,
+            log: log.to_string(),
+        }
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.diagnostics.iter().filter(|d| d.severity == Severity::Error)
+    }
+
+    // 最初のエラーだけの 1 行
+    pub fn summary(&self) -> String {
+        let first = self.errors().next().or_else(|| self.diagnostics.first());
+        let count = self.errors().count();
+
+        match first {
+            Some(first) if count > 1 => format!("{}: {} (and {} more)", self.stage, first.location_message(), count - 1),
+            Some(first) => format!("{}: {}", self.stage, first.location_message()),
+            None => format!("{}: {}", self.stage, self.log.trim()),
+        }
+    }
+
+    // 前後の行も付けて console.error に出す
+    pub fn report(&self) {
+        log::error(&self.to_string());
+    }
+}
+
+impl Diagnostic {
+    fn location_message(&self) -> String {
+        match (&self.file, self.line) {
+            (Some(file), Some(line)) => format!("{}:{}: {}", file, line, self.message),
+            (None, Some(line)) => format!("line {}: {}", line, self.message),
+            _ => self.message.clone(),
+        }
+    }
+}
+
+impl fmt::Display for ShaderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{} failed", self.stage)?;
+        if self.diagnostics.is_empty() {
+            return write!(f, "{}", self.log.trim());
+        }
+
+        for diagnostic in self.diagnostics.iter() {
+            let severity = match diagnostic.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            };
+            writeln!(f, "{}: {}", severity, diagnostic.location_message())?;
+
+            let width = diagnostic
+                .context
+                .iter()
+                .map(|(line, _)| line.to_string().len())
+                .max()
+                .unwrap_or(0);
+            for (line, text) in diagnostic.context.iter() {
+                let marker = if Some(*line) == diagnostic.line { ">" } else { " " };
+                writeln!(f, "{} {:>width$} | {}", marker, line, text.trim_end(), width = width)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<ShaderError> for String {
+    fn from(error: ShaderError) -> String {
+        error.summary()
+    }
+}
+
+impl From<ShaderError> for JsValue {
+    fn from(error: ShaderError) -> JsValue {
+        JsValue::from(error.summary())
+    }
+}
+
+// 展開後の line 行目の前後で、同じ file から来た行
+fn context(source: &Preprocessed, line: usize) -> Vec<(usize, String)> {
+    let file = match source.origin(line) {
+        Some(origin) if origin.file != DEFINES => &origin.file,
+        _ => return Vec::new(),
+    };
+
+    let first = line.saturating_sub(CONTEXT_LINES).max(1);
+    let last = (line + CONTEXT_LINES).min(source.line_count());
+    (first..=last)
+        .filter_map(|i| {
+            let origin = source.origin(i)?;
+            if &origin.file != file {
+                return None;
+            }
+            Some((origin.line, source.line(i)?.to_string()))
+        })
+        .collect()
+}
+
+// ドライバのログを (種類, 展開後の行番号, 内容) に分ける
+// ANGLE / Chrome: "ERROR: 0:12: 'foo' : undeclared identifier"
+// Mesa:           "0:12(5): error: 'foo' undeclared"
+// 行番号が読めない行は None、"2 compilation errors" のような集計の行は捨てる
+pub fn parse_log(log: &str) -> Vec<(Severity, Option<usize>, String)> {
+    log.lines()
+        .map(|line| line.trim_matches(|c: char| c.is_whitespace() || c == '\0'))
+        .filter(|line| !line.is_empty())
+        .filter_map(|line| {
+            let parsed = parse_angle(line).or_else(|| parse_mesa(line));
+            match parsed {
+                Some(parsed) => Some(parsed),
+                None if line.contains("compilation errors") || line.contains("compilation error.") => None,
+                None => {
+                    let (severity, message) = strip_severity(line);
+                    Some((severity, None, message.to_string()))
+                }
+            }
+        })
+        .collect()
+}
+
+fn parse_angle(line: &str) -> Option<(Severity, Option<usize>, String)> {
+    let (severity, rest) = if let Some(rest) = line.strip_prefix("ERROR:") {
+        (Severity::Error, rest)
+    } else if let Some(rest) = line.strip_prefix("WARNING:") {
+        (Severity::Warning, rest)
+    } else {
+        return None;
+    };
+
+    // "0:12: message"。source 番号は一つしか渡さないので読み捨てる
+    let rest = rest.trim_start();
+    let (_, rest) = rest.split_once(':')?;
+    let (number, message) = rest.split_once(':')?;
+    let number = number.trim();
+    if !number.chars().all(|c| c.is_ascii_digit()) && number != "?" {
+        return None;
+    }
+
+    Some((severity, number.parse().ok(), message.trim().to_string()))
+}
+
+fn parse_mesa(line: &str) -> Option<(Severity, Option<usize>, String)> {
+    let (location, rest) = line.split_once(": ")?;
+    let (_, position) = location.split_once(':')?;
+    let number = position.split('(').next()?;
+    let number: usize = number.parse().ok()?;
+
+    let (severity, message) = strip_severity(rest);
+    Some((severity, Some(number), message.to_string()))
+}
+
+fn strip_severity(line: &str) -> (Severity, &str) {
+    let lower = line.to_ascii_lowercase();
+    for (prefix, severity) in [("error:", Severity::Error), ("warning:", Severity::Warning)] {
+        if lower.starts_with(prefix) {
+            return (severity, line[prefix.len()..].trim());
+        }
+    }
+
+    (Severity::Error, line)
+}
@@ -0,0 +1,135 @@
+use std::f32::consts::PI;
+
+use image::codecs::hdr::HdrDecoder;
+use image::{ImageFormat, Rgba, RgbaImage};
+
+// 正距円筒図法 (equirectangular) のパノラマ。HDR はリニア、それ以外は 0..1 の値を持つ
+pub struct Panorama {
+    width: u32,
+    height: u32,
+    pixels: Vec<[f32; 3]>,
+    hdr: bool,
+}
+
+impl Panorama {
+    // .hdr (Radiance) と image が読める LDR 形式。大きさ 0 は sample できないのでエラー
+    pub fn decode(source: &[u8]) -> Result<Panorama, String> {
+        let panorama = Panorama::decode_pixels(source)?;
+        if panorama.width == 0 || panorama.height == 0 {
+            return Err("empty panorama".to_string());
+        }
+
+        Ok(panorama)
+    }
+
+    fn decode_pixels(source: &[u8]) -> Result<Panorama, String> {
+        if image::guess_format(source).ok() == Some(ImageFormat::Hdr) {
+            let decoder = HdrDecoder::new(source).map_err(|e| e.to_string())?;
+            let meta = decoder.metadata();
+            let pixels = decoder
+                .read_image_hdr()
+                .map_err(|e| e.to_string())?
+                .into_iter()
+                .map(|p| p.0)
+                .collect();
+
+            return Ok(Panorama {
+                width: meta.width,
+                height: meta.height,
+                pixels,
+                hdr: true,
+            });
+        }
+
+        let img = image::load_from_memory(source)
+            .map_err(|e| e.to_string())?
+            .into_rgb8();
+        let pixels = img
+            .pixels()
+            .map(|p| [p[0] as f32 / 255.0, p[1] as f32 / 255.0, p[2] as f32 / 255.0])
+            .collect();
+
+        Ok(Panorama {
+            width: img.width(),
+            height: img.height(),
+            pixels,
+            hdr: false,
+        })
+    }
+
+    // 方向ベクトルの色を bilinear で求める。経度方向は繋がっている
+    pub fn sample(&self, direction: [f32; 3]) -> [f32; 3] {
+        let [x, y, z] = direction;
+        let length = (x * x + y * y + z * z).sqrt().max(f32::EPSILON);
+
+        let longitude = x.atan2(-z);
+        let latitude = (y / length).clamp(-1.0, 1.0).asin();
+
+        let u = (0.5 + longitude / (2.0 * PI)) * self.width as f32 - 0.5;
+        let v = (0.5 - latitude / PI) * self.height as f32 - 0.5;
+
+        let x0 = u.floor();
+        let y0 = v.floor();
+        let fx = u - x0;
+        let fy = v - y0;
+
+        let mut color = [0.0; 3];
+        for (dy, wy) in [(0, 1.0 - fy), (1, fy)].iter() {
+            for (dx, wx) in [(0, 1.0 - fx), (1, fx)].iter() {
+                let p = self.pixel(x0 as i64 + dx, y0 as i64 + dy);
+                for (c, p) in color.iter_mut().zip(p.iter()) {
+                    *c += p * wx * wy;
+                }
+            }
+        }
+
+        color
+    }
+
+    fn pixel(&self, x: i64, y: i64) -> [f32; 3] {
+        let x = x.rem_euclid(self.width as i64) as usize;
+        let y = y.clamp(0, self.height as i64 - 1) as usize;
+
+        self.pixels[y * self.width as usize + x]
+    }
+
+    // HDR は Reinhard でトーンマップしてから gamma 補正する
+    fn to_rgba(&self, color: [f32; 3]) -> Rgba<u8> {
+        let map = |c: f32| {
+            let c = if self.hdr {
+                (c / (1.0 + c)).powf(1.0 / 2.2)
+            } else {
+                c
+            };
+            (c.clamp(0.0, 1.0) * 255.0).round() as u8
+        };
+
+        Rgba([map(color[0]), map(color[1]), map(color[2]), 255])
+    }
+}
+
+// texture::FACE_NAMES の順 (+X, -X, +Y, -Y, +Z, -Z) に size x size の 6 面を作る
+pub fn to_cubemap(panorama: &Panorama, size: u32) -> Vec<RgbaImage> {
+    (0..6)
+        .map(|face| {
+            RgbaImage::from_fn(size, size, |x, y| {
+                let s = 2.0 * (x as f32 + 0.5) / size as f32 - 1.0;
+                let t = 2.0 * (y as f32 + 0.5) / size as f32 - 1.0;
+
+                panorama.to_rgba(panorama.sample(face_direction(face, s, t)))
+            })
+        })
+        .collect()
+}
+
+// OpenGL の cubemap 規約での面上の座標 (s, t) に対応する方向
+pub(crate) fn face_direction(face: usize, s: f32, t: f32) -> [f32; 3] {
+    match face {
+        0 => [1.0, -t, -s],
+        1 => [-1.0, -t, s],
+        2 => [s, 1.0, t],
+        3 => [s, -1.0, -t],
+        4 => [s, -t, 1.0],
+        _ => [-s, -t, -1.0],
+    }
+}
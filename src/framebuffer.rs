@@ -0,0 +1,89 @@
+use std::ops::Deref;
+
+use web_sys::WebGlRenderingContext;
+
+use crate::backend::Backend;
+
+// drop で delete_framebuffer する framebuffer
+pub struct Framebuffer<C: Backend = WebGlRenderingContext> {
+    context: C,
+    framebuffer: C::Framebuffer,
+}
+
+impl<C: Backend> Framebuffer<C> {
+    pub fn new(context: &C) -> Result<Framebuffer<C>, String> {
+        let framebuffer = context
+            .create_framebuffer()
+            .ok_or("failed create framebuffer")?;
+
+        Ok(Framebuffer {
+            context: context.clone(),
+            framebuffer,
+        })
+    }
+
+    // bind した状態で attachment を揃えた後に呼ぶ
+    pub fn check_status(&self) -> Result<(), String> {
+        let status = self
+            .context
+            .check_framebuffer_status(WebGlRenderingContext::FRAMEBUFFER);
+
+        if status == WebGlRenderingContext::FRAMEBUFFER_COMPLETE {
+            Ok(())
+        } else {
+            Err(format!("framebuffer is incomplete: 0x{:x}", status))
+        }
+    }
+}
+
+impl<C: Backend> Deref for Framebuffer<C> {
+    type Target = C::Framebuffer;
+
+    fn deref(&self) -> &C::Framebuffer {
+        &self.framebuffer
+    }
+}
+
+impl<C: Backend> Drop for Framebuffer<C> {
+    fn drop(&mut self) {
+        self.context.delete_framebuffer(Some(&self.framebuffer));
+    }
+}
+
+// drop で delete_renderbuffer する renderbuffer
+pub struct Renderbuffer<C: Backend = WebGlRenderingContext> {
+    context: C,
+    renderbuffer: C::Renderbuffer,
+}
+
+impl<C: Backend> Renderbuffer<C> {
+    // format: DEPTH_COMPONENT16, RGBA4 など
+    pub fn new(context: &C, format: u32, width: i32, height: i32) -> Result<Renderbuffer<C>, String> {
+        let renderbuffer = context
+            .create_renderbuffer()
+            .ok_or("failed create renderbuffer")?;
+
+        context.bind_renderbuffer(WebGlRenderingContext::RENDERBUFFER, Some(&renderbuffer));
+        context.renderbuffer_storage(WebGlRenderingContext::RENDERBUFFER, format, width, height);
+        context.bind_renderbuffer(WebGlRenderingContext::RENDERBUFFER, None);
+
+        Ok(Renderbuffer {
+            context: context.clone(),
+            renderbuffer,
+        })
+    }
+}
+
+impl<C: Backend> Deref for Renderbuffer<C> {
+    type Target = C::Renderbuffer;
+
+    fn deref(&self) -> &C::Renderbuffer {
+        &self.renderbuffer
+    }
+}
+
+impl<C: Backend> Drop for Renderbuffer<C> {
+    fn drop(&mut self) {
+        self.context.delete_renderbuffer(Some(&self.renderbuffer));
+    }
+}
@@ -0,0 +1,42 @@
+// CPU 側のメッシュデータ (position: xyz, normal: xyz, uv: st)
+#[derive(Clone, Debug, Default)]
+pub struct Geometry {
+    pub vertex: Vec<f32>,
+    pub normal: Vec<f32>,
+    pub uv: Vec<f32>,
+    pub index: Vec<u16>,
+}
+
+impl Geometry {
+    pub fn vertex_count(&self) -> usize {
+        self.vertex.len() / 3
+    }
+
+    pub fn index_count(&self) -> usize {
+        self.index.len()
+    }
+
+    // 外接矩形の中心と、そこから一番遠い頂点までの距離
+    pub fn bounding_sphere(&self) -> ([f32; 3], f32) {
+        let mut min = [f32::MAX; 3];
+        let mut max = [f32::MIN; 3];
+        for p in self.vertex.chunks(3) {
+            for i in 0..3 {
+                min[i] = min[i].min(p[i]);
+                max[i] = max[i].max(p[i]);
+            }
+        }
+        if self.vertex.is_empty() {
+            return ([0.0; 3], 0.0);
+        }
+
+        let center = [(min[0] + max[0]) * 0.5, (min[1] + max[1]) * 0.5, (min[2] + max[2]) * 0.5];
+        let radius = self
+            .vertex
+            .chunks(3)
+            .map(|p| ((p[0] - center[0]).powi(2) + (p[1] - center[1]).powi(2) + (p[2] - center[2]).powi(2)).sqrt())
+            .fold(0.0, f32::max);
+
+        (center, radius)
+    }
+}
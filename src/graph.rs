@@ -0,0 +1,334 @@
+use nalgebra_glm::{Mat4, Quat, Vec3};
+
+use crate::material::Material;
+
+// 削除された node の番号が再利用されても古い NodeId では引けないように世代を持つ
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NodeId {
+    index: usize,
+    generation: u32,
+}
+
+// Scene が持つ GPU 側の形状の番号
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeshId(pub usize);
+
+// 親に対する平行移動・回転・拡大。行列は T * R * S
+#[derive(Clone, Debug)]
+pub struct Transform {
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub scale: Vec3,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform {
+            translation: Vec3::zeros(),
+            rotation: nalgebra_glm::quat_identity(),
+            scale: Vec3::new(1.0, 1.0, 1.0),
+        }
+    }
+}
+
+impl Transform {
+    pub fn matrix(&self) -> Mat4 {
+        let translate = nalgebra_glm::translate(&Mat4::identity(), &self.translation);
+        let rotate = nalgebra_glm::quat_to_mat4(&self.rotation);
+
+        nalgebra_glm::scale(&(translate * rotate), &self.scale)
+    }
+
+    // 行列を T * R * S に分解する。剪断や負の拡大は失われる
+    pub fn from_matrix(matrix: &Mat4) -> Transform {
+        let column = |i: usize| Vec3::new(matrix[(0, i)], matrix[(1, i)], matrix[(2, i)]);
+        let scale = Vec3::new(column(0).norm(), column(1).norm(), column(2).norm());
+
+        let mut rotation = *matrix;
+        for i in 0..3 {
+            let s = if scale[i] > 0.0 { scale[i] } else { 1.0 };
+            for j in 0..3 {
+                rotation[(j, i)] /= s;
+            }
+            rotation[(i, 3)] = 0.0;
+        }
+
+        Transform {
+            translation: column(3),
+            rotation: nalgebra_glm::quat_normalize(&nalgebra_glm::to_quat(&rotation)),
+            scale,
+        }
+    }
+}
+
+// node に付ける描画の部品
+#[derive(Clone, Debug)]
+pub struct Renderable {
+    pub mesh: MeshId,
+    pub material: Material,
+    pub cast_shadow: bool,
+}
+
+impl Renderable {
+    pub fn new(mesh: MeshId, material: Material) -> Renderable {
+        Renderable {
+            mesh,
+            material,
+            cast_shadow: true,
+        }
+    }
+}
+
+pub struct Node {
+    pub name: String,
+    // false なら子も描かない
+    pub visible: bool,
+    pub renderable: Option<Renderable>,
+
+    transform: Transform,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+
+    // update で親から順に計算し直す
+    world: Mat4,
+    world_visible: bool,
+    dirty: bool,
+}
+
+impl Node {
+    pub fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+
+    pub fn children(&self) -> &[NodeId] {
+        &self.children
+    }
+
+    // 直前の SceneGraph::update の時点の値
+    pub fn world_matrix(&self) -> &Mat4 {
+        &self.world
+    }
+}
+
+struct Slot {
+    generation: u32,
+    node: Option<Node>,
+}
+
+#[derive(Default)]
+pub struct SceneGraph {
+    slots: Vec<Slot>,
+    free: Vec<usize>,
+    roots: Vec<NodeId>,
+}
+
+impl SceneGraph {
+    pub fn new() -> SceneGraph {
+        SceneGraph::default()
+    }
+
+    // parent が None なら一番上に置く
+    pub fn add(&mut self, name: &str, parent: Option<NodeId>) -> Result<NodeId, String> {
+        if let Some(parent) = parent {
+            self.node(parent)?;
+        }
+
+        let node = Node {
+            name: name.to_string(),
+            visible: true,
+            renderable: None,
+
+            transform: Transform::default(),
+            parent,
+            children: Vec::new(),
+
+            world: Mat4::identity(),
+            world_visible: true,
+            dirty: true,
+        };
+
+        let id = match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index];
+                slot.node = Some(node);
+                NodeId {
+                    index,
+                    generation: slot.generation,
+                }
+            }
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    node: Some(node),
+                });
+                NodeId {
+                    index: self.slots.len() - 1,
+                    generation: 0,
+                }
+            }
+        };
+
+        match parent {
+            Some(parent) => self.node_mut(parent)?.children.push(id),
+            None => self.roots.push(id),
+        }
+
+        Ok(id)
+    }
+
+    // 子孫もまとめて消す
+    pub fn remove(&mut self, id: NodeId) -> Result<(), String> {
+        let parent = self.node(id)?.parent;
+        self.detach(id, parent)?;
+
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            let slot = &mut self.slots[id.index];
+            if let Some(node) = slot.node.take() {
+                stack.extend(node.children);
+            }
+            slot.generation = slot.generation.wrapping_add(1);
+            self.free.push(id.index);
+        }
+
+        Ok(())
+    }
+
+    pub fn get(&self, id: NodeId) -> Option<&Node> {
+        self.node(id).ok()
+    }
+
+    pub fn get_mut(&mut self, id: NodeId) -> Option<&mut Node> {
+        self.node_mut(id).ok()
+    }
+
+    pub fn find(&self, name: &str) -> Option<NodeId> {
+        self.iter().find(|(_, node)| node.name == name).map(|(id, _)| id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (NodeId, &Node)> {
+        self.slots.iter().enumerate().filter_map(|(index, slot)| {
+            slot.node.as_ref().map(|node| {
+                (
+                    NodeId {
+                        index,
+                        generation: slot.generation,
+                    },
+                    node,
+                )
+            })
+        })
+    }
+
+    pub fn set_transform(&mut self, id: NodeId, transform: Transform) -> Result<(), String> {
+        let node = self.node_mut(id)?;
+        node.transform = transform;
+        node.dirty = true;
+
+        Ok(())
+    }
+
+    // 自分の子孫は親にできない
+    pub fn set_parent(&mut self, id: NodeId, parent: Option<NodeId>) -> Result<(), String> {
+        if let Some(parent) = parent {
+            self.node(parent)?;
+            if parent == id || self.is_descendant(parent, id) {
+                return Err(format!("{:?} cannot be a child of its own descendant {:?}", id, parent));
+            }
+        }
+
+        let old = self.node(id)?.parent;
+        self.detach(id, old)?;
+
+        match parent {
+            Some(parent) => self.node_mut(parent)?.children.push(id),
+            None => self.roots.push(id),
+        }
+        let node = self.node_mut(id)?;
+        node.parent = parent;
+        node.dirty = true;
+
+        Ok(())
+    }
+
+    pub fn is_descendant(&self, id: NodeId, ancestor: NodeId) -> bool {
+        let mut current = self.get(id).and_then(|n| n.parent);
+        while let Some(parent) = current {
+            if parent == ancestor {
+                return true;
+            }
+            current = self.get(parent).and_then(|n| n.parent);
+        }
+
+        false
+    }
+
+    // 変更された node とその子孫だけ world 行列を計算し直す
+    pub fn update(&mut self) {
+        let mut stack: Vec<(NodeId, Mat4, bool, bool)> = self
+            .roots
+            .iter()
+            .rev()
+            .map(|id| (*id, Mat4::identity(), false, true))
+            .collect();
+
+        while let Some((id, parent_world, parent_changed, parent_visible)) = stack.pop() {
+            let node = match self.slots[id.index].node.as_mut() {
+                Some(node) => node,
+                None => continue,
+            };
+
+            let changed = parent_changed || node.dirty;
+            if changed {
+                node.world = parent_world * node.transform.matrix();
+                node.dirty = false;
+            }
+            node.world_visible = parent_visible && node.visible;
+
+            for child in node.children.iter().rev() {
+                stack.push((*child, node.world, changed, node.world_visible));
+            }
+        }
+    }
+
+    // 親も含めて見えていて、描画の部品を持つ node と world 行列
+    pub fn renderables(&self) -> impl Iterator<Item = (NodeId, &Renderable, &Mat4)> {
+        self.iter().filter_map(|(id, node)| {
+            if node.world_visible {
+                node.renderable.as_ref().map(|r| (id, r, &node.world))
+            } else {
+                None
+            }
+        })
+    }
+
+    fn detach(&mut self, id: NodeId, parent: Option<NodeId>) -> Result<(), String> {
+        let siblings = match parent {
+            Some(parent) => &mut self.node_mut(parent)?.children,
+            None => &mut self.roots,
+        };
+        siblings.retain(|child| *child != id);
+
+        Ok(())
+    }
+
+    fn node(&self, id: NodeId) -> Result<&Node, String> {
+        self.slots
+            .get(id.index)
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.node.as_ref())
+            .ok_or_else(|| format!("no node {:?}", id))
+    }
+
+    fn node_mut(&mut self, id: NodeId) -> Result<&mut Node, String> {
+        self.slots
+            .get_mut(id.index)
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.node.as_mut())
+            .ok_or_else(|| format!("no node {:?}", id))
+    }
+}
@@ -0,0 +1,409 @@
+use std::f32::consts::PI;
+
+use image::{Rgba, RgbaImage};
+use nalgebra_glm::Vec3;
+use web_sys::WebGlRenderingContext;
+
+use crate::backend::Backend;
+use crate::equirect::face_direction;
+use crate::program::Program;
+use crate::texture::{self, Texture};
+
+// split-sum 近似の image based lighting
+// 環境マップから roughness ごとの鏡面反射 (mip chain)、拡散反射 (SH 9 係数)、BRDF テーブルを作る
+
+// 鏡面反射 cubemap の 1 面の大きさ。mip level 0 が roughness 0
+pub const SPECULAR_SIZE: u32 = 128;
+// roughness 1 に対応する mip level (4x4)
+pub const SPECULAR_MAX_LOD: u32 = 5;
+pub const BRDF_LUT_SIZE: u32 = 64;
+
+const SPECULAR_SAMPLES: u32 = 32;
+const BRDF_SAMPLES: u32 = 256;
+// 拡散反射の積分に使う mip level の大きさ
+const IRRADIANCE_SIZE: u32 = 16;
+
+pub struct Prefiltered {
+    // level ごとに texture::FACE_NAMES の順の 6 面 (sRGB)
+    pub specular: Vec<Vec<RgbaImage>>,
+    // 放射照度を π で割ったもの (リニア)。shader の irradiance() と同じ基底
+    pub irradiance: [[f32; 3]; 9],
+}
+
+pub fn prefilter(faces: &[RgbaImage]) -> Prefiltered {
+    let source = CubeMap::from_faces(faces, SPECULAR_SIZE);
+
+    let specular = source
+        .levels
+        .iter()
+        .enumerate()
+        .map(|(level, data)| {
+            let roughness = (level as f32 / SPECULAR_MAX_LOD as f32).min(1.0);
+            let size = data.size;
+
+            (0..6)
+                .map(|face| {
+                    RgbaImage::from_fn(size, size, |x, y| {
+                        if level == 0 {
+                            return to_rgba(data.texel(face, x, y));
+                        }
+
+                        let (s, t) = texel_center(x, y, size);
+                        let normal = Vec3::from(face_direction(face, s, t)).normalize();
+                        to_rgba(source.prefilter(&normal, roughness))
+                    })
+                })
+                .collect()
+        })
+        .collect();
+
+    Prefiltered {
+        specular,
+        irradiance: source.irradiance(),
+    }
+}
+
+// x: dot(N, V), y: roughness。R が F0 の係数、G が足す値
+pub fn brdf_lut(size: u32) -> RgbaImage {
+    RgbaImage::from_fn(size, size, |x, y| {
+        let n_dot_v = (x as f32 + 0.5) / size as f32;
+        let roughness = (y as f32 + 0.5) / size as f32;
+
+        let view = Vec3::new((1.0 - n_dot_v * n_dot_v).sqrt(), 0.0, n_dot_v);
+        let normal = Vec3::z();
+        let (mut scale, mut bias) = (0.0, 0.0);
+        for i in 0..BRDF_SAMPLES {
+            let half = importance_sample_ggx(hammersley(i, BRDF_SAMPLES), &normal, roughness);
+            let light = half * (2.0 * view.dot(&half)) - view;
+
+            let n_dot_l = light.z.max(0.0);
+            let n_dot_h = half.z.max(0.0);
+            let v_dot_h = view.dot(&half).max(0.0);
+            if n_dot_l > 0.0 {
+                let visibility = geometry_smith(n_dot_v, n_dot_l, roughness) * v_dot_h / (n_dot_h * n_dot_v);
+                let fresnel = (1.0 - v_dot_h).powi(5);
+                scale += (1.0 - fresnel) * visibility;
+                bias += fresnel * visibility;
+            }
+        }
+
+        let map = |c: f32| ((c / BRDF_SAMPLES as f32).clamp(0.0, 1.0) * 255.0).round() as u8;
+        Rgba([map(scale), map(bias), 0, 255])
+    })
+}
+
+// GPU 側の環境。BRDF テーブルは環境に依らないので Scene が一つ持つ
+pub struct Environment<C: Backend = WebGlRenderingContext> {
+    pub specular: Texture<C>,
+    pub irradiance: [[f32; 3]; 9],
+}
+
+impl<C: Backend> Environment<C> {
+    pub fn new(context: &C, faces: &[RgbaImage]) -> Result<Environment<C>, String> {
+        let prefiltered = prefilter(faces);
+
+        Ok(Environment {
+            specular: texture::create_cubemap_levels(context, &prefiltered.specular)?,
+            irradiance: prefiltered.irradiance,
+        })
+    }
+}
+
+pub fn brdf_lut_texture<C: Backend>(context: &C) -> Result<Texture<C>, String> {
+    texture::create_texture(context, &brdf_lut(BRDF_LUT_SIZE))
+}
+
+pub struct IblUniforms<C: Backend = WebGlRenderingContext> {
+    specular: Option<C::UniformLocation>,
+    max_lod: Option<C::UniformLocation>,
+    size: Option<C::UniformLocation>,
+    irradiance: Option<C::UniformLocation>,
+    brdf_lut: Option<C::UniformLocation>,
+}
+
+impl<C: Backend> IblUniforms<C> {
+    pub fn new(program: &Program<C>) -> IblUniforms<C> {
+        IblUniforms {
+            specular: program.uniform_location("uSpecularMap"),
+            max_lod: program.uniform_location("uSpecularMaxLod"),
+            size: program.uniform_location("uSpecularSize"),
+            irradiance: program.uniform_location("uIrradiance"),
+            brdf_lut: program.uniform_location("uBrdfLut"),
+        }
+    }
+
+    // TEXTURE1 に鏡面反射、TEXTURE2 に BRDF テーブルを割り当てる
+    // specular は roughness に応じた mip chain を持つ cubemap (Environment::specular か mipmap 付きの動的な cubemap) と 1 面の大きさ
+    pub fn apply(
+        &self,
+        context: &C,
+        specular: Option<(&C::Texture, u32)>,
+        irradiance: Option<&[[f32; 3]; 9]>,
+        brdf_lut: Option<&C::Texture>,
+    ) {
+        let irradiance: Vec<f32> = irradiance
+            .map(|i| i.iter().flatten().copied().collect())
+            .unwrap_or_else(|| vec![0.0; 27]);
+
+        context.active_texture(WebGlRenderingContext::TEXTURE1);
+        context.bind_texture(WebGlRenderingContext::TEXTURE_CUBE_MAP, specular.map(|(texture, _)| texture));
+        context.uniform1i(self.specular.as_ref(), 1);
+
+        context.active_texture(WebGlRenderingContext::TEXTURE2);
+        context.bind_texture(WebGlRenderingContext::TEXTURE_2D, brdf_lut);
+        context.uniform1i(self.brdf_lut.as_ref(), 2);
+
+        context.active_texture(WebGlRenderingContext::TEXTURE0);
+
+        context.uniform1f(self.max_lod.as_ref(), SPECULAR_MAX_LOD as f32);
+        context.uniform1f(self.size.as_ref(), specular.map_or(1, |(_, size)| size) as f32);
+        context.uniform3fv_with_f32_array(self.irradiance.as_ref(), &irradiance);
+    }
+}
+
+struct Level {
+    size: u32,
+    faces: Vec<Vec<Vec3>>,
+}
+
+impl Level {
+    fn texel(&self, face: usize, x: u32, y: u32) -> Vec3 {
+        self.faces[face][(y * self.size + x) as usize]
+    }
+
+    // 面の中だけで bilinear (面の境目は端の texel で止める)
+    fn sample(&self, face: usize, s: f32, t: f32) -> Vec3 {
+        let max = self.size as f32 - 1.0;
+        let u = ((s + 1.0) * 0.5 * self.size as f32 - 0.5).clamp(0.0, max);
+        let v = ((t + 1.0) * 0.5 * self.size as f32 - 0.5).clamp(0.0, max);
+
+        let x0 = u.floor() as u32;
+        let y0 = v.floor() as u32;
+        let x1 = (x0 + 1).min(self.size - 1);
+        let y1 = (y0 + 1).min(self.size - 1);
+        let fx = u - x0 as f32;
+        let fy = v - y0 as f32;
+
+        let top = self.texel(face, x0, y0) * (1.0 - fx) + self.texel(face, x1, y0) * fx;
+        let bottom = self.texel(face, x0, y1) * (1.0 - fx) + self.texel(face, x1, y1) * fx;
+
+        top * (1.0 - fy) + bottom * fy
+    }
+}
+
+// リニアな色の cubemap と 1x1 までの box filter の mip chain
+struct CubeMap {
+    levels: Vec<Level>,
+}
+
+impl CubeMap {
+    fn from_faces(faces: &[RgbaImage], size: u32) -> CubeMap {
+        let base = Level {
+            size,
+            faces: faces
+                .iter()
+                .map(|face| {
+                    // 縮小する時は覆う範囲を平均する
+                    let width = face.width();
+                    let block = (width / size).max(1);
+                    (0..size * size)
+                        .map(|i| {
+                            let x0 = (i % size * width / size).min(width - block);
+                            let y0 = (i / size * width / size).min(width - block);
+
+                            let mut sum = Vec3::zeros();
+                            for y in y0..y0 + block {
+                                for x in x0..x0 + block {
+                                    sum += to_linear(face.get_pixel(x, y));
+                                }
+                            }
+                            sum / (block * block) as f32
+                        })
+                        .collect()
+                })
+                .collect(),
+        };
+
+        let mut levels = vec![base];
+        while let Some(last) = levels.last().filter(|l| l.size > 1) {
+            let size = last.size / 2;
+            let faces = (0..6)
+                .map(|face| {
+                    (0..size * size)
+                        .map(|i| {
+                            let (x, y) = (i % size * 2, i / size * 2);
+                            (last.texel(face, x, y)
+                                + last.texel(face, x + 1, y)
+                                + last.texel(face, x, y + 1)
+                                + last.texel(face, x + 1, y + 1))
+                                * 0.25
+                        })
+                        .collect()
+                })
+                .collect();
+
+            levels.push(Level { size, faces });
+        }
+
+        CubeMap { levels }
+    }
+
+    fn sample(&self, direction: &Vec3, lod: f32) -> Vec3 {
+        let (face, s, t) = direction_to_face(direction);
+        let lod = lod.clamp(0.0, (self.levels.len() - 1) as f32);
+        let lower = lod.floor() as usize;
+        let upper = (lower + 1).min(self.levels.len() - 1);
+        let f = lod - lower as f32;
+
+        self.levels[lower].sample(face, s, t) * (1.0 - f) + self.levels[upper].sample(face, s, t) * f
+    }
+
+    // GGX で重点サンプリングする。N = V = R と置く (Karis 2013)
+    // サンプルの立体角に合った mip level から引いてノイズを抑える
+    fn prefilter(&self, normal: &Vec3, roughness: f32) -> Vec3 {
+        let size = self.levels[0].size as f32;
+        let texel_solid_angle = 4.0 * PI / (6.0 * size * size);
+
+        let mut color = Vec3::zeros();
+        let mut weight = 0.0;
+        for i in 0..SPECULAR_SAMPLES {
+            let half = importance_sample_ggx(hammersley(i, SPECULAR_SAMPLES), normal, roughness);
+            let n_dot_h = normal.dot(&half).max(0.0);
+            let light = half * (2.0 * n_dot_h) - normal;
+
+            let n_dot_l = normal.dot(&light);
+            if n_dot_l > 0.0 {
+                // N = V なので pdf = D / 4
+                let pdf = distribution_ggx(n_dot_h, roughness) / 4.0 + 1e-4;
+                let sample_solid_angle = 1.0 / (SPECULAR_SAMPLES as f32 * pdf);
+                let lod = 0.5 * (sample_solid_angle / texel_solid_angle).log2() + 1.0;
+
+                color += self.sample(&light, lod) * n_dot_l;
+                weight += n_dot_l;
+            }
+        }
+
+        color / weight.max(f32::EPSILON)
+    }
+
+    // 2 次までの球面調和関数に射影し、cos で畳み込んだ係数を π で割って返す (Ramamoorthi 2001)
+    fn irradiance(&self) -> [[f32; 3]; 9] {
+        let level = self
+            .levels
+            .iter()
+            .find(|l| l.size <= IRRADIANCE_SIZE)
+            .unwrap_or(&self.levels[0]);
+        let size = level.size;
+
+        let mut coefficients = [Vec3::zeros(); 9];
+        for face in 0..6 {
+            for y in 0..size {
+                for x in 0..size {
+                    let (s, t) = texel_center(x, y, size);
+                    let direction = Vec3::from(face_direction(face, s, t));
+                    let solid_angle = (2.0 / size as f32).powi(2) / direction.norm_squared().powf(1.5);
+
+                    let color = level.texel(face, x, y);
+                    for (c, basis) in coefficients.iter_mut().zip(sh_basis(&direction.normalize()).iter()) {
+                        *c += color * (basis * solid_angle);
+                    }
+                }
+            }
+        }
+
+        // A0 = π, A1 = 2π/3, A2 = π/4 を掛けて π で割る
+        let band = [1.0, 2.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0, 0.25, 0.25, 0.25, 0.25, 0.25];
+        let mut irradiance = [[0.0; 3]; 9];
+        for ((out, c), a) in irradiance.iter_mut().zip(coefficients.iter()).zip(band.iter()) {
+            *out = [c.x * a, c.y * a, c.z * a];
+        }
+
+        irradiance
+    }
+}
+
+fn sh_basis(n: &Vec3) -> [f32; 9] {
+    [
+        0.282_095,
+        0.488_603 * n.y,
+        0.488_603 * n.z,
+        0.488_603 * n.x,
+        1.092_548 * n.x * n.y,
+        1.092_548 * n.y * n.z,
+        0.315_392 * (3.0 * n.z * n.z - 1.0),
+        1.092_548 * n.x * n.z,
+        0.546_274 * (n.x * n.x - n.y * n.y),
+    ]
+}
+
+// equirect::face_direction の逆
+pub fn direction_to_face(d: &Vec3) -> (usize, f32, f32) {
+    let (ax, ay, az) = (d.x.abs(), d.y.abs(), d.z.abs());
+
+    if ax >= ay && ax >= az {
+        if d.x > 0.0 {
+            (0, -d.z / ax, -d.y / ax)
+        } else {
+            (1, d.z / ax, -d.y / ax)
+        }
+    } else if ay >= az {
+        if d.y > 0.0 {
+            (2, d.x / ay, d.z / ay)
+        } else {
+            (3, d.x / ay, -d.z / ay)
+        }
+    } else if d.z > 0.0 {
+        (4, d.x / az, -d.y / az)
+    } else {
+        (5, -d.x / az, -d.y / az)
+    }
+}
+
+fn texel_center(x: u32, y: u32, size: u32) -> (f32, f32) {
+    (
+        2.0 * (x as f32 + 0.5) / size as f32 - 1.0,
+        2.0 * (y as f32 + 0.5) / size as f32 - 1.0,
+    )
+}
+
+fn hammersley(i: u32, count: u32) -> (f32, f32) {
+    (i as f32 / count as f32, i.reverse_bits() as f32 / 4_294_967_296.0)
+}
+
+fn importance_sample_ggx((u, v): (f32, f32), normal: &Vec3, roughness: f32) -> Vec3 {
+    let a = roughness * roughness;
+    let phi = 2.0 * PI * u;
+    let cos_theta = ((1.0 - v) / (1.0 + (a * a - 1.0) * v)).sqrt();
+    let sin_theta = (1.0 - cos_theta * cos_theta).sqrt();
+
+    let up = if normal.z.abs() < 0.999 { Vec3::z() } else { Vec3::x() };
+    let tangent = up.cross(normal).normalize();
+    let bitangent = normal.cross(&tangent);
+
+    (tangent * (phi.cos() * sin_theta) + bitangent * (phi.sin() * sin_theta) + normal * cos_theta).normalize()
+}
+
+fn distribution_ggx(n_dot_h: f32, roughness: f32) -> f32 {
+    let a2 = roughness.powi(4);
+    let d = n_dot_h * n_dot_h * (a2 - 1.0) + 1.0;
+
+    a2 / (PI * d * d).max(f32::EPSILON)
+}
+
+// IBL では k = α / 2
+fn geometry_smith(n_dot_v: f32, n_dot_l: f32, roughness: f32) -> f32 {
+    let k = roughness * roughness / 2.0;
+    let g = |c: f32| c / (c * (1.0 - k) + k);
+
+    g(n_dot_v) * g(n_dot_l)
+}
+
+fn to_linear(p: &Rgba<u8>) -> Vec3 {
+    Vec3::new(p[0] as f32, p[1] as f32, p[2] as f32).map(|c| (c / 255.0).powf(2.2))
+}
+
+fn to_rgba(color: Vec3) -> Rgba<u8> {
+    let map = |c: f32| (c.clamp(0.0, 1.0).powf(1.0 / 2.2) * 255.0).round() as u8;
+
+    Rgba([map(color.x), map(color.y), map(color.z), 255])
+}
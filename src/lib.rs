@@ -1,46 +1,43 @@
 use wasm_bindgen::prelude::*;
-use wasm_bindgen::JsCast;
-use web_sys::WebGlRenderingContext;
 
+pub mod animation;
+pub mod backend;
+pub mod bezier;
 pub mod buffer;
+pub mod camera;
+pub mod controls;
 pub mod log;
-pub mod teapot;
+pub mod normal;
 pub mod cube;
+pub mod diagnostic;
+pub mod equirect;
+pub mod framebuffer;
+pub mod geometry;
+pub mod graph;
+pub mod ibl;
+pub mod light;
+pub mod material;
+pub mod mesh;
+pub mod obj;
+pub mod plane;
+pub mod preprocessor;
+pub mod probe;
+pub mod program;
+pub mod recording;
+pub mod resize;
 pub mod scene;
 pub mod shader;
-
-#[wasm_bindgen(start)]
-pub fn start() -> Result<(), JsValue> {
-    let document = web_sys::window().unwrap().document().unwrap();
-    let canvas = document.get_element_by_id("canvas").unwrap();
-    let canvas: web_sys::HtmlCanvasElement = canvas.dyn_into::<web_sys::HtmlCanvasElement>()?;
-
-    let context = canvas
-        .get_context("webgl")?
-        .unwrap()
-        .dyn_into::<WebGlRenderingContext>()?;
-
-    let w = canvas.client_width();
-    let h = canvas.client_height();
-    context.viewport(0, 0, w, h);
-
-    init(w, h, &context)?;
-
-    context.clear_color(0.0, 0.0, 0.0, 1.0);
-
-    Ok(())
-}
-
-fn init(width: i32, height: i32, context: &WebGlRenderingContext) -> Result<(), JsValue> {
-    context.clear_color(0.0, 0.0, 0.0, 1.0);
-    context.clear_depth(1.0);
-    context.enable(WebGlRenderingContext::DEPTH_TEST);
-    context.depth_func(WebGlRenderingContext::LEQUAL);
-    context
-        .clear(WebGlRenderingContext::COLOR_BUFFER_BIT | WebGlRenderingContext::DEPTH_BUFFER_BIT);
-
-    let mut scene = scene::Scene::new_with_context(width, height, context)?;
-    scene.render()?;
-
-    Ok(())
+pub mod shadow;
+pub mod skybox;
+pub mod software;
+pub mod texture;
+pub mod viewer;
+pub mod weld;
+
+include!(concat!(env!("OUT_DIR"), "/assets.rs"));
+
+// id="canvas" の要素で viewer を始める
+#[wasm_bindgen]
+pub fn start() -> Result<viewer::Viewer, JsValue> {
+    viewer::Viewer::new(JsValue::from_str("canvas"))
 }
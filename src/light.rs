@@ -0,0 +1,133 @@
+use nalgebra_glm::Vec3;
+use web_sys::WebGlRenderingContext;
+
+use crate::backend::Backend;
+use crate::program::Program;
+
+// shader::compile が GLSL の MAX_LIGHTS として #define する
+pub const MAX_LIGHTS: usize = 4;
+
+// spot の内側と外側の差の下限。smoothstep の両端が等しいと GLSL では結果が未定義になる
+const MIN_SPOT_EDGE: f32 = 1e-4;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LightKind {
+    Directional,
+    Point,
+    Spot,
+}
+
+#[derive(Clone, Debug)]
+pub struct Light {
+    pub kind: LightKind,
+    pub position: Vec3,
+    pub direction: Vec3,
+    pub color: Vec3,
+    pub intensity: f32,
+    // 1 / (constant + linear * d + quadratic * d^2)
+    pub attenuation: Vec3,
+    // spot の内側と外側の半角 (radian)
+    pub inner_angle: f32,
+    pub outer_angle: f32,
+    // 影を落とすか。shadow map を作れるのは平行光源と spot だけ
+    pub cast_shadow: bool,
+}
+
+impl Light {
+    pub fn directional(direction: Vec3, color: Vec3, intensity: f32) -> Light {
+        Light {
+            kind: LightKind::Directional,
+            position: Vec3::zeros(),
+            direction: direction.normalize(),
+            color,
+            intensity,
+            attenuation: Vec3::new(1.0, 0.0, 0.0),
+            inner_angle: 0.0,
+            outer_angle: 0.0,
+            cast_shadow: true,
+        }
+    }
+
+    pub fn point(position: Vec3, color: Vec3, intensity: f32) -> Light {
+        Light {
+            kind: LightKind::Point,
+            position,
+            direction: -Vec3::y(),
+            color,
+            intensity,
+            attenuation: Vec3::new(1.0, 0.0, 0.001),
+            inner_angle: 0.0,
+            outer_angle: 0.0,
+            cast_shadow: false,
+        }
+    }
+
+    pub fn spot(position: Vec3, direction: Vec3, color: Vec3, intensity: f32, inner_angle: f32, outer_angle: f32) -> Light {
+        Light {
+            kind: LightKind::Spot,
+            direction: direction.normalize(),
+            inner_angle: inner_angle.min(outer_angle - MIN_SPOT_EDGE).max(0.0),
+            outer_angle,
+            cast_shadow: true,
+            ..Light::point(position, color, intensity)
+        }
+    }
+}
+
+pub struct LightUniforms<C: Backend = WebGlRenderingContext> {
+    count: Option<C::UniformLocation>,
+    position: Option<C::UniformLocation>,
+    direction: Option<C::UniformLocation>,
+    color: Option<C::UniformLocation>,
+    attenuation: Option<C::UniformLocation>,
+    cone: Option<C::UniformLocation>,
+}
+
+impl<C: Backend> LightUniforms<C> {
+    pub fn new(program: &Program<C>) -> LightUniforms<C> {
+        LightUniforms {
+            count: program.uniform_location("uLightCount"),
+            position: program.uniform_location("uLightPosition"),
+            direction: program.uniform_location("uLightDirection"),
+            color: program.uniform_location("uLightColor"),
+            attenuation: program.uniform_location("uLightAttenuation"),
+            cone: program.uniform_location("uLightCone"),
+        }
+    }
+
+    // 先頭の MAX_LIGHTS 個だけ使う
+    pub fn apply(&self, context: &C, lights: &[Light]) {
+        let mut position = [0.0; MAX_LIGHTS * 4];
+        let mut direction = [0.0; MAX_LIGHTS * 3];
+        let mut color = [0.0; MAX_LIGHTS * 3];
+        let mut attenuation = [0.0; MAX_LIGHTS * 3];
+        let mut cone = [0.0; MAX_LIGHTS * 2];
+
+        let lights = &lights[..lights.len().min(MAX_LIGHTS)];
+        for (i, light) in lights.iter().enumerate() {
+            // w = 0 は平行光源
+            let w = if light.kind == LightKind::Directional { 0.0 } else { 1.0 };
+            position[i * 4..i * 4 + 4].copy_from_slice(&[light.position.x, light.position.y, light.position.z, w]);
+            direction[i * 3..i * 3 + 3].copy_from_slice(light.direction.as_slice());
+            color[i * 3..i * 3 + 3].copy_from_slice((light.color * light.intensity).as_slice());
+            attenuation[i * 3..i * 3 + 3].copy_from_slice(light.attenuation.as_slice());
+
+            // spot 以外は cos(outer) = -2 として絞りを無効にする
+            cone[i * 2..i * 2 + 2].copy_from_slice(&match light.kind {
+                // inner_angle は pub なので、送る時にも cos の差を空けておく
+                LightKind::Spot => {
+                    let outer = light.outer_angle.cos();
+                    [light.inner_angle.cos().max(outer + MIN_SPOT_EDGE), outer]
+                }
+                _ => [-1.0, -2.0],
+            });
+        }
+
+        context.uniform1i(self.count.as_ref(), lights.len() as i32);
+        context.uniform4fv_with_f32_array(self.position.as_ref(), &position);
+        context.uniform3fv_with_f32_array(self.direction.as_ref(), &direction);
+        context.uniform3fv_with_f32_array(self.color.as_ref(), &color);
+        context.uniform3fv_with_f32_array(self.attenuation.as_ref(), &attenuation);
+        context.uniform2fv_with_f32_array(self.cone.as_ref(), &cone);
+    }
+}
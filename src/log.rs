@@ -1,11 +1,15 @@
+#[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
-use wasm_bindgen::JsCast;
 
+#[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = console)]
     pub fn log(s: &str);
 
+    #[wasm_bindgen(js_namespace = console)]
+    pub fn error(s: &str);
+
     #[wasm_bindgen(js_namespace = console, js_name = log)]
     pub fn log_u32(a: u32);
 
@@ -15,3 +19,29 @@ extern "C" {
     #[wasm_bindgen(js_namespace = console, js_name = log)]
     pub fn log_many(a: &str, b: &str);
 }
+
+// wasm 以外 (tests/recording.rs の cargo test など) は console が無いので標準エラー出力に出す。標準出力は bin の結果に使う
+#[cfg(not(target_arch = "wasm32"))]
+pub fn log(s: &str) {
+    eprintln!("{}", s);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn error(s: &str) {
+    eprintln!("{}", s);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn log_u32(a: u32) {
+    eprintln!("{}", a);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn log_i32(a: i32) {
+    eprintln!("{}", a);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn log_many(a: &str, b: &str) {
+    eprintln!("{} {}", a, b);
+}
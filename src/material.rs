@@ -0,0 +1,177 @@
+use nalgebra_glm::Vec3;
+use web_sys::WebGlRenderingContext;
+
+use crate::backend::Backend;
+use crate::program::Program;
+
+// 描画に使う shader は材質の種類で決まる
+#[derive(Clone, Debug)]
+pub enum Material {
+    BlinnPhong(BlinnPhong),
+    Pbr(Pbr),
+    Glass(Glass),
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Material::BlinnPhong(BlinnPhong::default())
+    }
+}
+
+// Blinn-Phong の材質。reflectivity で環境マップの映り込みと混ぜる
+#[derive(Clone, Debug)]
+pub struct BlinnPhong {
+    pub ambient: Vec3,
+    pub diffuse: Vec3,
+    pub specular: Vec3,
+    pub shininess: f32,
+    pub reflectivity: f32,
+}
+
+impl Default for BlinnPhong {
+    fn default() -> Self {
+        BlinnPhong {
+            ambient: Vec3::new(0.1, 0.1, 0.1),
+            diffuse: Vec3::new(1.0, 1.0, 1.0),
+            specular: Vec3::new(1.0, 1.0, 1.0),
+            shininess: 64.0,
+            reflectivity: 0.8,
+        }
+    }
+}
+
+// metallic-roughness の PBR 材質。base_color はリニア
+#[derive(Clone, Debug)]
+pub struct Pbr {
+    pub base_color: Vec3,
+    pub metallic: f32,
+    pub roughness: f32,
+    pub ambient_occlusion: f32,
+}
+
+impl Default for Pbr {
+    fn default() -> Self {
+        Pbr {
+            base_color: Vec3::new(1.0, 1.0, 1.0),
+            metallic: 1.0,
+            roughness: 0.3,
+            ambient_occlusion: 1.0,
+        }
+    }
+}
+
+impl Pbr {
+    // mediump でハイライトが潰れないように roughness の下限を設ける
+    pub const MIN_ROUGHNESS: f32 = 0.04;
+
+    // 金属 (真鍮など) と非金属 (陶器やプラスチック)
+    pub fn metal(base_color: Vec3, roughness: f32) -> Pbr {
+        Pbr {
+            base_color,
+            metallic: 1.0,
+            roughness,
+            ambient_occlusion: 1.0,
+        }
+    }
+
+    pub fn dielectric(base_color: Vec3, roughness: f32) -> Pbr {
+        Pbr {
+            base_color,
+            metallic: 0.0,
+            roughness,
+            ambient_occlusion: 1.0,
+        }
+    }
+}
+
+// 環境マップの屈折と反射を Schlick の Fresnel 項で混ぜる (面は 1 回だけ通す近似)
+#[derive(Clone, Debug)]
+pub struct Glass {
+    // 屈折率 (水 1.33, ガラス 1.5, ダイヤモンド 2.42)
+    pub ior: f32,
+    // 分散。R は ior - dispersion、B は ior + dispersion で屈折させる。0 なら分散なし
+    pub dispersion: f32,
+    // 屈折した光に掛ける色
+    pub tint: Vec3,
+}
+
+impl Default for Glass {
+    fn default() -> Self {
+        Glass {
+            ior: 1.5,
+            dispersion: 0.0,
+            tint: Vec3::new(1.0, 1.0, 1.0),
+        }
+    }
+}
+
+impl Glass {
+    pub fn water() -> Glass {
+        Glass {
+            ior: 1.33,
+            ..Glass::default()
+        }
+    }
+}
+
+// 使っていない uniform は location が None になり、設定しても何も起きない
+pub struct MaterialUniforms<C: Backend = WebGlRenderingContext> {
+    ambient: Option<C::UniformLocation>,
+    diffuse: Option<C::UniformLocation>,
+    specular: Option<C::UniformLocation>,
+    shininess: Option<C::UniformLocation>,
+    reflectivity: Option<C::UniformLocation>,
+
+    base_color: Option<C::UniformLocation>,
+    metallic: Option<C::UniformLocation>,
+    roughness: Option<C::UniformLocation>,
+    ambient_occlusion: Option<C::UniformLocation>,
+
+    ior: Option<C::UniformLocation>,
+    dispersion: Option<C::UniformLocation>,
+    tint: Option<C::UniformLocation>,
+}
+
+impl<C: Backend> MaterialUniforms<C> {
+    pub fn new(program: &Program<C>) -> MaterialUniforms<C> {
+        MaterialUniforms {
+            ambient: program.uniform_location("uAmbient"),
+            diffuse: program.uniform_location("uDiffuse"),
+            specular: program.uniform_location("uSpecular"),
+            shininess: program.uniform_location("uShininess"),
+            reflectivity: program.uniform_location("uReflectivity"),
+
+            base_color: program.uniform_location("uBaseColor"),
+            metallic: program.uniform_location("uMetallic"),
+            roughness: program.uniform_location("uRoughness"),
+            ambient_occlusion: program.uniform_location("uAmbientOcclusion"),
+
+            ior: program.uniform_location("uIor"),
+            dispersion: program.uniform_location("uDispersion"),
+            tint: program.uniform_location("uTint"),
+        }
+    }
+
+    pub fn apply(&self, context: &C, material: &Material) {
+        match material {
+            Material::BlinnPhong(material) => {
+                context.uniform3fv_with_f32_array(self.ambient.as_ref(), material.ambient.as_slice());
+                context.uniform3fv_with_f32_array(self.diffuse.as_ref(), material.diffuse.as_slice());
+                context.uniform3fv_with_f32_array(self.specular.as_ref(), material.specular.as_slice());
+                context.uniform1f(self.shininess.as_ref(), material.shininess);
+                context.uniform1f(self.reflectivity.as_ref(), material.reflectivity);
+            }
+            Material::Pbr(material) => {
+                context.uniform3fv_with_f32_array(self.base_color.as_ref(), material.base_color.as_slice());
+                context.uniform1f(self.metallic.as_ref(), material.metallic.clamp(0.0, 1.0));
+                context.uniform1f(self.roughness.as_ref(), material.roughness.clamp(Pbr::MIN_ROUGHNESS, 1.0));
+                context.uniform1f(self.ambient_occlusion.as_ref(), material.ambient_occlusion.clamp(0.0, 1.0));
+            }
+            Material::Glass(material) => {
+                context.uniform1f(self.ior.as_ref(), material.ior.max(f32::EPSILON));
+                context.uniform1f(self.dispersion.as_ref(), material.dispersion);
+                context.uniform3fv_with_f32_array(self.tint.as_ref(), material.tint.as_slice());
+            }
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use nalgebra_glm::Vec3;
+use web_sys::WebGlRenderingContext;
+
+use crate::backend::Backend;
+use crate::buffer::{self, Buffer};
+use crate::geometry::Geometry;
+use crate::program::Program;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AttributeType {
+    Byte,
+    UnsignedByte,
+    Short,
+    UnsignedShort,
+    Float,
+}
+
+impl AttributeType {
+    pub fn gl(self) -> u32 {
+        match self {
+            AttributeType::Byte => WebGlRenderingContext::BYTE,
+            AttributeType::UnsignedByte => WebGlRenderingContext::UNSIGNED_BYTE,
+            AttributeType::Short => WebGlRenderingContext::SHORT,
+            AttributeType::UnsignedShort => WebGlRenderingContext::UNSIGNED_SHORT,
+            AttributeType::Float => WebGlRenderingContext::FLOAT,
+        }
+    }
+
+    // 1 要素の byte 数
+    pub fn size(self) -> i32 {
+        match self {
+            AttributeType::Byte | AttributeType::UnsignedByte => 1,
+            AttributeType::Short | AttributeType::UnsignedShort => 2,
+            AttributeType::Float => 4,
+        }
+    }
+}
+
+// shader の attribute 一つ分の読み方。stride と offset は byte 単位 (stride 0 は詰めて並んでいる)
+#[derive(Clone, Debug)]
+pub struct Attribute {
+    pub name: String,
+    pub components: i32,
+    pub kind: AttributeType,
+    pub normalized: bool,
+    pub stride: i32,
+    pub offset: i32,
+}
+
+impl Attribute {
+    pub fn float(name: &str, components: i32) -> Attribute {
+        Attribute {
+            name: name.to_string(),
+            components,
+            kind: AttributeType::Float,
+            normalized: false,
+            stride: 0,
+            offset: 0,
+        }
+    }
+
+    // 0..255 を 0..1 として読む (頂点色など)
+    pub fn normalized_bytes(name: &str, components: i32) -> Attribute {
+        Attribute {
+            kind: AttributeType::UnsignedByte,
+            normalized: true,
+            ..Attribute::float(name, components)
+        }
+    }
+
+    // interleave されたバッファの中の位置
+    pub fn interleaved(self, stride: i32, offset: i32) -> Attribute {
+        Attribute {
+            stride,
+            offset,
+            ..self
+        }
+    }
+}
+
+// 一つの GL バッファと、そこから読む属性
+struct VertexBuffer<C: Backend> {
+    buffer: Buffer<C>,
+    attributes: Vec<Attribute>,
+}
+
+// 頂点バッファと index バッファを持ち、program の attribute に自分で繋ぐ
+pub struct Mesh<C: Backend = WebGlRenderingContext> {
+    context: C,
+    mode: u32,
+
+    vertex_buffers: Vec<VertexBuffer<C>>,
+    index: Option<Buffer<C>>,
+    count: i32,
+
+    // モデル座標での外接球 (中心, 半径)
+    bounds: (Vec3, f32),
+}
+
+impl<C: Backend> Mesh<C> {
+    // mode: TRIANGLES, LINES など
+    pub fn new(context: &C, mode: u32) -> Mesh<C> {
+        Mesh {
+            context: context.clone(),
+            mode,
+
+            vertex_buffers: Vec::new(),
+            index: None,
+            count: 0,
+
+            bounds: (Vec3::zeros(), 0.0),
+        }
+    }
+
+    // aPosition, aNormal, aTexCoord を一つのバッファに interleave する。無い属性は省く
+    pub fn from_geometry(context: &C, geometry: &Geometry) -> Result<Mesh<C>, String> {
+        let count = geometry.vertex_count();
+        let has_normal = geometry.normal.len() == count * 3;
+        let has_uv = geometry.uv.len() == count * 2;
+
+        let mut attributes = vec![Attribute::float("aPosition", 3)];
+        if has_normal {
+            attributes.push(Attribute::float("aNormal", 3));
+        }
+        if has_uv {
+            attributes.push(Attribute::float("aTexCoord", 2));
+        }
+        let floats: i32 = attributes.iter().map(|a| a.components).sum();
+
+        let mut offset = 0;
+        let attributes = attributes
+            .into_iter()
+            .map(|a| {
+                let components = a.components;
+                let a = a.interleaved(floats * 4, offset);
+                offset += components * 4;
+                a
+            })
+            .collect();
+
+        let mut data = Vec::with_capacity(count * floats as usize);
+        for i in 0..count {
+            data.extend_from_slice(&geometry.vertex[i * 3..i * 3 + 3]);
+            if has_normal {
+                data.extend_from_slice(&geometry.normal[i * 3..i * 3 + 3]);
+            }
+            if has_uv {
+                data.extend_from_slice(&geometry.uv[i * 2..i * 2 + 2]);
+            }
+        }
+
+        let mut mesh = Mesh::new(context, WebGlRenderingContext::TRIANGLES);
+        mesh.add_vertex_buffer(&data, attributes)?;
+        mesh.set_index(&geometry.index)?;
+
+        let (center, radius) = geometry.bounding_sphere();
+        mesh.bounds = (Vec3::from(center), radius);
+
+        Ok(mesh)
+    }
+
+    pub fn add_vertex_buffer(&mut self, data: &[f32], attributes: Vec<Attribute>) -> Result<(), String> {
+        Self::check(&attributes)?;
+        let buffer = buffer::vertex_buffer(&self.context, data)?;
+        self.vertex_buffers.push(VertexBuffer { buffer, attributes });
+
+        Ok(())
+    }
+
+    pub fn add_byte_buffer(&mut self, data: &[u8], attributes: Vec<Attribute>) -> Result<(), String> {
+        Self::check(&attributes)?;
+        let buffer = buffer::byte_buffer(&self.context, data)?;
+        self.vertex_buffers.push(VertexBuffer { buffer, attributes });
+
+        Ok(())
+    }
+
+    pub fn set_index(&mut self, index: &[u16]) -> Result<(), String> {
+        self.index = Some(buffer::index_buffer(&self.context, index)?);
+        self.count = index.len() as i32;
+
+        Ok(())
+    }
+
+    // index を使わない時に draw_arrays で描く頂点数
+    pub fn set_count(&mut self, count: i32) {
+        self.index = None;
+        self.count = count;
+    }
+
+    pub fn attributes(&self) -> impl Iterator<Item = &Attribute> {
+        self.vertex_buffers.iter().flat_map(|b| b.attributes.iter())
+    }
+
+    pub fn bounds(&self) -> (Vec3, f32) {
+        self.bounds
+    }
+
+    // program で使われていない属性は飛ばす。戻り値が drop されるまで attribute が有効になる
+    pub fn bind(&self, program: &Program<C>) -> Binding<C> {
+        let mut locations = Vec::new();
+
+        for vertex_buffer in self.vertex_buffers.iter() {
+            self.context
+                .bind_buffer(WebGlRenderingContext::ARRAY_BUFFER, Some(&vertex_buffer.buffer));
+
+            for attribute in vertex_buffer.attributes.iter() {
+                let location = match program.attribute(&attribute.name) {
+                    Some(active) => active.location,
+                    None => continue,
+                };
+
+                self.context.enable_vertex_attrib_array(location);
+                self.context.vertex_attrib_pointer_with_i32(
+                    location,
+                    attribute.components,
+                    attribute.kind.gl(),
+                    attribute.normalized,
+                    attribute.stride,
+                    attribute.offset,
+                );
+                locations.push(location);
+            }
+        }
+
+        self.context
+            .bind_buffer(WebGlRenderingContext::ELEMENT_ARRAY_BUFFER, self.index.as_deref());
+
+        Binding {
+            context: self.context.clone(),
+            locations,
+        }
+    }
+
+    // bind した後に呼ぶ
+    pub fn draw(&self) {
+        if self.index.is_some() {
+            self.context.draw_elements_with_i32(
+                self.mode,
+                self.count,
+                WebGlRenderingContext::UNSIGNED_SHORT,
+                0,
+            );
+        } else {
+            self.context.draw_arrays(self.mode, 0, self.count);
+        }
+    }
+
+    fn check(attributes: &[Attribute]) -> Result<(), String> {
+        for attribute in attributes.iter() {
+            if !(1..=4).contains(&attribute.components) {
+                return Err(format!("{}: components must be 1 to 4, got {}", attribute.name, attribute.components));
+            }
+            if attribute.stride < 0 || attribute.stride > 255 || attribute.offset < 0 {
+                return Err(format!(
+                    "{}: invalid stride {} or offset {}",
+                    attribute.name, attribute.stride, attribute.offset
+                ));
+            }
+            if attribute.offset % attribute.kind.size() != 0 || attribute.stride % attribute.kind.size() != 0 {
+                return Err(format!("{}: stride and offset must be multiples of {}", attribute.name, attribute.kind.size()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Mesh::bind で有効にした attribute を drop で無効に戻す
+pub struct Binding<C: Backend = WebGlRenderingContext> {
+    context: C,
+    locations: Vec<u32>,
+}
+
+impl<C: Backend> Drop for Binding<C> {
+    fn drop(&mut self) {
+        for location in self.locations.iter() {
+            self.context.disable_vertex_attrib_array(*location);
+        }
+    }
+}
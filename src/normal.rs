@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+use crate::geometry::Geometry;
+use crate::weld;
+
+// これ以上の角度で接する面同士は平均せず、角 (crease) として残す
+pub const DEFAULT_CREASE_ANGLE: f32 = std::f32::consts::FRAC_PI_3;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Weight {
+    Area,
+    Angle,
+}
+
+// 三角形ごとの単位法線
+pub fn face_normals(geometry: &Geometry) -> Vec<[f32; 3]> {
+    geometry
+        .index
+        .chunks(3)
+        .map(|face| normalize(face_cross(geometry, face)))
+        .collect()
+}
+
+// 各三角形の頂点に面法線を割り当てる
+pub fn flat(geometry: &Geometry) -> Geometry {
+    let normals = face_normals(geometry);
+
+    rebuild(geometry, |face, _| normals[face])
+}
+
+// 隣接する面法線を重み付きで平均する。crease_angle (radian) を超える面は含めない
+pub fn smooth(geometry: &Geometry, crease_angle: f32, weight: Weight) -> Geometry {
+    let normals = smooth_corners(geometry, crease_angle, weight);
+
+    rebuild(geometry, |face, corner| normals[face * 3 + corner])
+}
+
+// smooth と同じ法線を、頂点を作り直さずに index の並び (三角形の角) ごとに返す
+pub fn smooth_corners(geometry: &Geometry, crease_angle: f32, weight: Weight) -> Vec<[f32; 3]> {
+    let normals = face_normals(geometry);
+    let threshold = crease_angle.cos();
+
+    let mut positions: HashMap<[u32; 3], usize> = HashMap::new();
+    let mut corners: Vec<usize> = Vec::with_capacity(geometry.index.len());
+    for &index in geometry.index.iter() {
+        let key = position_key(position(geometry, index));
+        let next = positions.len();
+        corners.push(*positions.entry(key).or_insert(next));
+    }
+
+    let mut incident: Vec<Vec<(usize, f32)>> = vec![Vec::new(); positions.len()];
+    for (face, indexes) in geometry.index.chunks(3).enumerate() {
+        if indexes.len() < 3 {
+            break;
+        }
+        let area = length(face_cross(geometry, indexes));
+
+        for corner in 0..3 {
+            let w = match weight {
+                Weight::Area => area,
+                Weight::Angle => corner_angle(geometry, indexes, corner),
+            };
+            incident[corners[face * 3 + corner]].push((face, w));
+        }
+    }
+
+    let corner_normal = |face: usize, corner: usize| {
+        let own = normals[face];
+        let mut sum = [0.0; 3];
+
+        for &(other, w) in incident[corners[face * 3 + corner]].iter() {
+            if other == face || dot(own, normals[other]) >= threshold {
+                for (s, n) in sum.iter_mut().zip(normals[other].iter()) {
+                    *s += n * w;
+                }
+            }
+        }
+
+        if length(sum) > 0.0 {
+            normalize(sum)
+        } else {
+            own
+        }
+    };
+
+    (0..geometry.index.len() / 3)
+        .flat_map(|face| (0..3).map(move |corner| (face, corner)))
+        .map(|(face, corner)| corner_normal(face, corner))
+        .collect()
+}
+
+// 角ごとに法線を決めて頂点を作り直し、同じ頂点を溶接する
+fn rebuild<F>(geometry: &Geometry, mut normal_of: F) -> Geometry
+where
+    F: FnMut(usize, usize) -> [f32; 3],
+{
+    let has_uv = !geometry.uv.is_empty();
+    let mut out = Geometry::default();
+
+    for (face, indexes) in geometry.index.chunks(3).enumerate() {
+        if indexes.len() < 3 {
+            break;
+        }
+        for (corner, &index) in indexes.iter().enumerate() {
+            let i = index as usize;
+
+            out.index.push(out.vertex_count() as u16);
+            out.vertex.extend_from_slice(&position(geometry, index));
+            out.normal.extend_from_slice(&normal_of(face, corner));
+            if has_uv {
+                out.uv.extend_from_slice(&geometry.uv[i * 2..i * 2 + 2]);
+            }
+        }
+    }
+
+    weld::weld(&out).geometry
+}
+
+fn position(geometry: &Geometry, index: u16) -> [f32; 3] {
+    let i = index as usize * 3;
+    [geometry.vertex[i], geometry.vertex[i + 1], geometry.vertex[i + 2]]
+}
+
+fn position_key(p: [f32; 3]) -> [u32; 3] {
+    let bits = |v: f32| if v == 0.0 { 0 } else { v.to_bits() };
+    [bits(p[0]), bits(p[1]), bits(p[2])]
+}
+
+// 長さは三角形の面積の 2 倍
+fn face_cross(geometry: &Geometry, face: &[u16]) -> [f32; 3] {
+    if face.len() < 3 {
+        return [0.0; 3];
+    }
+    let p0 = position(geometry, face[0]);
+    let p1 = position(geometry, face[1]);
+    let p2 = position(geometry, face[2]);
+
+    cross(sub(p1, p0), sub(p2, p0))
+}
+
+fn corner_angle(geometry: &Geometry, face: &[u16], corner: usize) -> f32 {
+    let p = position(geometry, face[corner]);
+    let a = normalize(sub(position(geometry, face[(corner + 1) % 3]), p));
+    let b = normalize(sub(position(geometry, face[(corner + 2) % 3]), p));
+
+    dot(a, b).clamp(-1.0, 1.0).acos()
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn length(a: [f32; 3]) -> f32 {
+    dot(a, a).sqrt()
+}
+
+fn normalize(a: [f32; 3]) -> [f32; 3] {
+    let l = length(a);
+    if l > 0.0 {
+        [a[0] / l, a[1] / l, a[2] / l]
+    } else {
+        [0.0; 3]
+    }
+}
@@ -0,0 +1,172 @@
+use crate::geometry::Geometry;
+use crate::normal;
+
+// `g` / `o` で区切られた index の範囲
+#[derive(Clone, Debug)]
+pub struct Group {
+    pub name: String,
+    pub start: usize,
+    pub count: usize,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Obj {
+    pub geometry: Geometry,
+    pub groups: Vec<Group>,
+}
+
+pub fn parse(source: &str) -> Result<Obj, String> {
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut uvs: Vec<[f32; 2]> = Vec::new();
+    let mut normals: Vec<[f32; 3]> = Vec::new();
+
+    let mut obj = Obj::default();
+    let mut group = (String::from("default"), 0);
+    // vn の無い角の頂点
+    let mut missing_normals: Vec<usize> = Vec::new();
+
+    for (number, line) in source.lines().enumerate() {
+        let line = match line.find('#') {
+            Some(i) => &line[..i],
+            None => line,
+        };
+        let mut tokens = line.split_whitespace();
+
+        let keyword = match tokens.next() {
+            Some(keyword) => keyword,
+            None => continue,
+        };
+        let args: Vec<&str> = tokens.collect();
+        let error = |message: String| format!("line {}: {}", number + 1, message);
+
+        match keyword {
+            "v" => positions.push(parse_vec3(&args).map_err(error)?),
+            "vn" => normals.push(parse_vec3(&args).map_err(error)?),
+            "vt" => {
+                let u = parse_float(args.first()).map_err(error)?;
+                let v = args.get(1).map_or(Ok(0.0), |v| parse_float(Some(v))).map_err(error)?;
+                uvs.push([u, v]);
+            }
+            "f" => {
+                if args.len() < 3 {
+                    return Err(error(format!("face needs at least 3 vertices, got {}", args.len())));
+                }
+
+                let mut corners = Vec::with_capacity(args.len());
+                for arg in args.iter() {
+                    corners.push(
+                        parse_corner(arg, positions.len(), uvs.len(), normals.len()).map_err(error)?,
+                    );
+                }
+
+                // 多角形は扇状に三角形分割する
+                for i in 1..corners.len() - 1 {
+                    for corner in [corners[0], corners[i], corners[i + 1]].iter() {
+                        let geometry = &mut obj.geometry;
+                        if geometry.vertex_count() > u16::MAX as usize {
+                            return Err(error(String::from("too many vertices for u16 index")));
+                        }
+                        geometry.index.push(geometry.vertex_count() as u16);
+
+                        let (v, vt, vn) = *corner;
+                        if vn.is_none() {
+                            missing_normals.push(geometry.vertex_count());
+                        }
+                        geometry.vertex.extend_from_slice(&positions[v]);
+                        geometry.uv.extend_from_slice(&vt.map_or([0.0, 0.0], |i| uvs[i]));
+                        geometry.normal.extend_from_slice(&vn.map_or([0.0, 0.0, 0.0], |i| normals[i]));
+                    }
+                }
+            }
+            "g" | "o" => {
+                let name = if args.is_empty() { String::from("default") } else { args.join(" ") };
+                close_group(&mut obj, &group);
+                group = (name, obj.geometry.index_count());
+            }
+            _ => {}
+        }
+    }
+
+    close_group(&mut obj, &group);
+
+    // vn が無い角にだけ法線を生成し、書かれていた法線はそのまま使う
+    // 頂点は角ごとに作っているので、index の並びと頂点の番号は一致する
+    if !missing_normals.is_empty() {
+        let smoothed = normal::smooth_corners(&obj.geometry, normal::DEFAULT_CREASE_ANGLE, normal::Weight::Angle);
+        for &i in missing_normals.iter() {
+            obj.geometry.normal[i * 3..i * 3 + 3].copy_from_slice(&smoothed[i]);
+        }
+    }
+
+    Ok(obj)
+}
+
+fn close_group(obj: &mut Obj, group: &(String, usize)) {
+    let count = obj.geometry.index_count() - group.1;
+    if count > 0 {
+        obj.groups.push(Group {
+            name: group.0.clone(),
+            start: group.1,
+            count,
+        });
+    }
+}
+
+fn parse_float(token: Option<&&str>) -> Result<f32, String> {
+    let token = token.ok_or_else(|| String::from("missing value"))?;
+    token
+        .parse::<f32>()
+        .map_err(|_| format!("invalid number `{}`", token))
+}
+
+fn parse_vec3(args: &[&str]) -> Result<[f32; 3], String> {
+    Ok([
+        parse_float(args.first())?,
+        parse_float(args.get(1))?,
+        parse_float(args.get(2))?,
+    ])
+}
+
+// `a`, `a/b`, `a//c`, `a/b/c`
+fn parse_corner(
+    token: &str,
+    positions: usize,
+    uvs: usize,
+    normals: usize,
+) -> Result<(usize, Option<usize>, Option<usize>), String> {
+    let mut parts = token.split('/');
+
+    let v = match parts.next() {
+        Some(v) if !v.is_empty() => resolve_index(v, positions)?,
+        _ => return Err(format!("missing vertex index in `{}`", token)),
+    };
+    let vt = match parts.next() {
+        Some(vt) if !vt.is_empty() => Some(resolve_index(vt, uvs)?),
+        _ => None,
+    };
+    let vn = match parts.next() {
+        Some(vn) if !vn.is_empty() => Some(resolve_index(vn, normals)?),
+        _ => None,
+    };
+
+    Ok((v, vt, vn))
+}
+
+// 1 始まり、負の値は末尾からの相対位置
+fn resolve_index(token: &str, len: usize) -> Result<usize, String> {
+    let index = token
+        .parse::<i64>()
+        .map_err(|_| format!("invalid index `{}`", token))?;
+
+    let resolved = if index > 0 {
+        index - 1
+    } else {
+        len as i64 + index
+    };
+
+    if index == 0 || resolved < 0 || resolved >= len as i64 {
+        return Err(format!("index {} out of range", index));
+    }
+
+    Ok(resolved as usize)
+}
@@ -0,0 +1,28 @@
+use crate::geometry::Geometry;
+
+// 原点を中心とした y = 0 の正方形。法線は +Y、uv は 0..1
+pub fn geometry(size: f32) -> Geometry {
+    let h = size * 0.5;
+
+    Geometry {
+        vertex: vec![
+            -h, 0.0, -h,
+            -h, 0.0,  h,
+             h, 0.0,  h,
+             h, 0.0, -h,
+        ],
+        normal: vec![
+            0.0, 1.0, 0.0,
+            0.0, 1.0, 0.0,
+            0.0, 1.0, 0.0,
+            0.0, 1.0, 0.0,
+        ],
+        uv: vec![
+            0.0, 0.0,
+            0.0, 1.0,
+            1.0, 1.0,
+            1.0, 0.0,
+        ],
+        index: vec![0, 1, 2, 0, 2, 3],
+    }
+}
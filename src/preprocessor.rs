@@ -0,0 +1,170 @@
+use std::collections::{HashMap, HashSet};
+
+// 展開後の 1 行がどの chunk の何行目 (1 から) から来たか。#define で足した行は file が DEFINES
+#[derive(Clone, Debug, PartialEq)]
+pub struct Origin {
+    pub file: String,
+    pub line: usize,
+}
+
+pub const DEFINES: &str = "<defines>";
+
+// 展開した source と、その各行の Origin
+#[derive(Clone, Debug)]
+pub struct Preprocessed {
+    pub source: String,
+    origins: Vec<Origin>,
+}
+
+impl Preprocessed {
+    // line はドライバのログと同じく 1 から
+    pub fn origin(&self, line: usize) -> Option<&Origin> {
+        line.checked_sub(1).and_then(|i| self.origins.get(i))
+    }
+
+    pub fn line(&self, line: usize) -> Option<&str> {
+        line.checked_sub(1).and_then(|i| self.source.lines().nth(i))
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.origins.len()
+    }
+
+    fn push(&mut self, text: &str, file: &str, line: usize) {
+        self.source.push_str(text);
+        self.source.push('\n');
+        self.origins.push(Origin {
+            file: file.to_string(),
+            line,
+        });
+    }
+}
+
+// GLSL ES 1.0 に無い #include を展開し、#define を先頭に足す
+// chunk は一つの shader の中で一度だけ展開する (#pragma once と同じ)。#ifdef の中の #include も展開される
+#[derive(Default)]
+pub struct Preprocessor {
+    chunks: HashMap<String, String>,
+}
+
+impl Preprocessor {
+    pub fn new() -> Preprocessor {
+        Preprocessor::default()
+    }
+
+    // 同じ名前なら置き換える
+    pub fn add(&mut self, name: &str, source: &str) {
+        self.chunks.insert(name.to_string(), source.to_string());
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.chunks.get(name).map(|s| s.as_str())
+    }
+
+    // name の chunk を展開する。defines は (名前, 値) で、値が空なら "#define 名前" だけ書く
+    // #version がある時はその次の行に入れる
+    pub fn process(&self, name: &str, defines: &[(&str, &str)]) -> Result<Preprocessed, String> {
+        let source = self.chunk(name)?;
+
+        let mut output = Preprocessed {
+            source: String::new(),
+            origins: Vec::new(),
+        };
+        let mut body = source;
+        let mut first_line = 1;
+        let skipped = source.len() - source.trim_start().len();
+        let trimmed = &source[skipped..];
+        if trimmed.starts_with("#version") {
+            let end = trimmed.find('\n').map(|i| i + 1).unwrap_or(trimmed.len());
+            let version_line = source[..skipped].matches('\n').count() + 1;
+            output.push(trimmed[..end].trim_end(), name, version_line);
+            body = &trimmed[end..];
+            first_line = version_line + 1;
+        }
+
+        for (i, (define, value)) in defines.iter().enumerate() {
+            if !is_identifier(define) {
+                return Err(format!("{}: invalid #define name \"{}\"", name, define));
+            }
+            let text = if value.is_empty() {
+                format!("#define {}", define)
+            } else {
+                format!("#define {} {}", define, value)
+            };
+            output.push(&text, DEFINES, i + 1);
+        }
+
+        let mut included = HashSet::new();
+        included.insert(name.to_string());
+        let mut stack = vec![name.to_string()];
+        self.expand(body, first_line, &mut included, &mut stack, &mut output)?;
+
+        Ok(output)
+    }
+
+    // first_line: source の 1 行目が元の chunk の何行目か
+    fn expand(
+        &self,
+        source: &str,
+        first_line: usize,
+        included: &mut HashSet<String>,
+        stack: &mut Vec<String>,
+        output: &mut Preprocessed,
+    ) -> Result<(), String> {
+        for (i, line) in source.lines().enumerate() {
+            let number = first_line + i;
+            let include = match parse_include(line) {
+                Some(include) => include,
+                None => {
+                    output.push(line, &stack[stack.len() - 1], number);
+                    continue;
+                }
+            };
+            let at = || format!("{}:{}", stack[stack.len() - 1], number);
+
+            let include = include.map_err(|e| format!("{}: {}", at(), e))?;
+            if stack.iter().any(|s| s == include) {
+                return Err(format!("{}: circular #include \"{}\" ({})", at(), include, stack.join(" -> ")));
+            }
+            if !included.insert(include.to_string()) {
+                continue;
+            }
+            let chunk = self
+                .chunks
+                .get(include)
+                .ok_or_else(|| format!("{}: unknown #include \"{}\"", at(), include))?;
+
+            stack.push(include.to_string());
+            self.expand(chunk, 1, included, stack, output)?;
+            stack.pop();
+        }
+
+        Ok(())
+    }
+
+    fn chunk(&self, name: &str) -> Result<&str, String> {
+        self.get(name).ok_or_else(|| format!("unknown shader source \"{}\"", name))
+    }
+}
+
+// #include "name" の name。#include でない行は None
+fn parse_include(line: &str) -> Option<Result<&str, String>> {
+    let rest = line.trim().strip_prefix('#')?.trim_start().strip_prefix("include")?;
+    let rest = rest.trim();
+
+    let name = rest
+        .strip_prefix('"')
+        .and_then(|r| r.strip_suffix('"'))
+        .or_else(|| rest.strip_prefix('<').and_then(|r| r.strip_suffix('>')));
+
+    Some(match name {
+        Some(name) if !name.is_empty() => Ok(name),
+        _ => Err(format!("malformed #include {}", rest)),
+    })
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
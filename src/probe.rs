@@ -0,0 +1,157 @@
+use nalgebra_glm::{Mat4, Vec3};
+use web_sys::WebGlRenderingContext;
+
+use crate::backend::Backend;
+use crate::framebuffer::{Framebuffer, Renderbuffer};
+use crate::texture::{Texture, FACE_TARGETS};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProbeUpdate {
+    // 毎フレーム 6 面を描き直す
+    EveryFrame,
+    // request_update の後の 1 フレームだけ描き直す
+    OnDemand,
+}
+
+// 物体の中心から周りを描き込む cubemap。描いた結果を環境マップとして使う
+pub struct ReflectionProbe<C: Backend = WebGlRenderingContext> {
+    context: C,
+    size: i32,
+
+    texture: Texture<C>,
+    framebuffer: Framebuffer<C>,
+    _depth: Renderbuffer<C>,
+
+    update: ProbeUpdate,
+    dirty: bool,
+}
+
+impl<C: Backend> ReflectionProbe<C> {
+    // mipmap を作るので size は 2 のべき乗に切り上げる
+    pub fn new(context: &C, size: u32, update: ProbeUpdate) -> Result<ReflectionProbe<C>, String> {
+        let size = size.max(1).next_power_of_two() as i32;
+
+        let texture = Texture::new(context)?;
+        context.bind_texture(WebGlRenderingContext::TEXTURE_CUBE_MAP, Some(&texture));
+        for target in FACE_TARGETS.iter() {
+            context.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+                *target,
+                0,
+                WebGlRenderingContext::RGBA as i32,
+                size,
+                size,
+                0,
+                WebGlRenderingContext::RGBA,
+                WebGlRenderingContext::UNSIGNED_BYTE,
+                None,
+            )?;
+        }
+        context.generate_mipmap(WebGlRenderingContext::TEXTURE_CUBE_MAP);
+        for (name, value) in [
+            (WebGlRenderingContext::TEXTURE_MIN_FILTER, WebGlRenderingContext::LINEAR_MIPMAP_LINEAR),
+            (WebGlRenderingContext::TEXTURE_MAG_FILTER, WebGlRenderingContext::LINEAR),
+            (WebGlRenderingContext::TEXTURE_WRAP_S, WebGlRenderingContext::CLAMP_TO_EDGE),
+            (WebGlRenderingContext::TEXTURE_WRAP_T, WebGlRenderingContext::CLAMP_TO_EDGE),
+        ] {
+            context.tex_parameteri(WebGlRenderingContext::TEXTURE_CUBE_MAP, name, value as i32);
+        }
+        context.bind_texture(WebGlRenderingContext::TEXTURE_CUBE_MAP, None);
+
+        // 深度は 6 面で使い回す
+        let framebuffer = Framebuffer::new(context)?;
+        let depth = Renderbuffer::new(context, WebGlRenderingContext::DEPTH_COMPONENT16, size, size)?;
+        context.bind_framebuffer(WebGlRenderingContext::FRAMEBUFFER, Some(&framebuffer));
+        context.framebuffer_renderbuffer(
+            WebGlRenderingContext::FRAMEBUFFER,
+            WebGlRenderingContext::DEPTH_ATTACHMENT,
+            WebGlRenderingContext::RENDERBUFFER,
+            Some(&depth),
+        );
+        context.framebuffer_texture_2d(
+            WebGlRenderingContext::FRAMEBUFFER,
+            WebGlRenderingContext::COLOR_ATTACHMENT0,
+            FACE_TARGETS[0],
+            Some(&texture),
+            0,
+        );
+        let status = framebuffer.check_status();
+        context.bind_framebuffer(WebGlRenderingContext::FRAMEBUFFER, None);
+        status?;
+
+        Ok(ReflectionProbe {
+            context: context.clone(),
+            size,
+
+            texture,
+            framebuffer,
+            _depth: depth,
+
+            update,
+            dirty: true,
+        })
+    }
+
+    pub fn texture(&self) -> &Texture<C> {
+        &self.texture
+    }
+
+    // 1 面の大きさ (2 のべき乗に切り上げた後)
+    pub fn size(&self) -> u32 {
+        self.size as u32
+    }
+
+    pub fn set_update(&mut self, update: ProbeUpdate) {
+        self.update = update;
+    }
+
+    pub fn request_update(&mut self) {
+        self.dirty = true;
+    }
+
+    pub fn needs_update(&self) -> bool {
+        self.update == ProbeUpdate::EveryFrame || self.dirty
+    }
+
+    // texture::FACE_NAMES の順の面を描き始める。戻り値は center から見たその面の (view, projection)
+    pub fn begin_face(&self, face: usize, center: &Vec3, near: f32, far: f32) -> (Mat4, Mat4) {
+        self.context
+            .bind_framebuffer(WebGlRenderingContext::FRAMEBUFFER, Some(&self.framebuffer));
+        self.context.framebuffer_texture_2d(
+            WebGlRenderingContext::FRAMEBUFFER,
+            WebGlRenderingContext::COLOR_ATTACHMENT0,
+            FACE_TARGETS[face],
+            Some(&self.texture),
+            0,
+        );
+        self.context.viewport(0, 0, self.size, self.size);
+
+        // cubemap の面は t が下向きなので up を -Y (Y 面は Z) にする
+        let (direction, up) = match face {
+            0 => (Vec3::x(), -Vec3::y()),
+            1 => (-Vec3::x(), -Vec3::y()),
+            2 => (Vec3::y(), Vec3::z()),
+            3 => (-Vec3::y(), -Vec3::z()),
+            4 => (Vec3::z(), -Vec3::y()),
+            _ => (-Vec3::z(), -Vec3::y()),
+        };
+        let view = nalgebra_glm::look_at(center, &(center + direction), &up);
+        let projection = nalgebra_glm::perspective(1.0, std::f32::consts::FRAC_PI_2, near, far);
+
+        (view, projection)
+    }
+
+    // 6 面を描き終えたら mipmap を作り直して画面に戻す。viewport は呼び出し側で戻す
+    pub fn end(&mut self) {
+        self.context
+            .bind_framebuffer(WebGlRenderingContext::FRAMEBUFFER, None);
+
+        self.context
+            .bind_texture(WebGlRenderingContext::TEXTURE_CUBE_MAP, Some(&self.texture));
+        self.context
+            .generate_mipmap(WebGlRenderingContext::TEXTURE_CUBE_MAP);
+        self.context
+            .bind_texture(WebGlRenderingContext::TEXTURE_CUBE_MAP, None);
+
+        self.dirty = false;
+    }
+}
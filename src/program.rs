@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use web_sys::WebGlRenderingContext;
+
+use crate::backend::Backend;
+use crate::shader::Shader;
+
+// link 後に getActiveUniform で見つかった uniform。配列は "[0]" を除いた名前で持つ
+#[derive(Clone, Debug)]
+pub struct ActiveUniform<C: Backend = WebGlRenderingContext> {
+    pub name: String,
+    // FLOAT_VEC3, SAMPLER_CUBE など
+    pub kind: u32,
+    // 配列の要素数。配列でなければ 1
+    pub size: i32,
+    location: C::UniformLocation,
+}
+
+#[derive(Clone, Debug)]
+pub struct ActiveAttribute {
+    pub name: String,
+    pub kind: u32,
+    pub size: i32,
+    pub location: u32,
+}
+
+// drop で delete_program する program。使われている uniform と attribute を link 時に調べておく
+// shader の中で使われていない変数はコンパイラが消すので active にならない
+pub struct Program<C: Backend = WebGlRenderingContext> {
+    context: C,
+    program: C::Program,
+
+    uniforms: HashMap<String, ActiveUniform<C>>,
+    attributes: HashMap<String, ActiveAttribute>,
+}
+
+impl<C: Backend> Deref for Program<C> {
+    type Target = C::Program;
+
+    fn deref(&self) -> &C::Program {
+        &self.program
+    }
+}
+
+impl<C: Backend> Drop for Program<C> {
+    fn drop(&mut self) {
+        self.context.delete_program(Some(&self.program));
+    }
+}
+
+impl<C: Backend> Program<C> {
+    pub fn link(context: &C, vert_shader: &Shader<C>, frag_shader: &Shader<C>) -> Result<Program<C>, String> {
+        let program = context
+            .create_program()
+            .ok_or_else(|| String::from("create program error"))?;
+        let mut program = Program {
+            context: context.clone(),
+            program,
+
+            uniforms: HashMap::new(),
+            attributes: HashMap::new(),
+        };
+
+        context.attach_shader(&program, vert_shader);
+        context.attach_shader(&program, frag_shader);
+        context.link_program(&program);
+
+        if !context.program_link_status(&program) {
+            return Err(context
+                .get_program_info_log(&program)
+                .unwrap_or_else(|| String::from("create program error")));
+        }
+
+        program.reflect();
+
+        Ok(program)
+    }
+
+    fn reflect(&mut self) {
+        let uniform_count = self.context.active_uniform_count(&self.program);
+        let attribute_count = self.context.active_attribute_count(&self.program);
+
+        for i in 0..uniform_count {
+            let info = match self.context.active_uniform(&self.program, i) {
+                Some(info) => info,
+                None => continue,
+            };
+            let name = info.name;
+            let name = name.strip_suffix("[0]").unwrap_or(&name).to_string();
+            let location = match self.context.get_uniform_location(&self.program, &name) {
+                Some(location) => location,
+                None => continue,
+            };
+
+            self.uniforms.insert(
+                name.clone(),
+                ActiveUniform {
+                    name,
+                    kind: info.kind,
+                    size: info.size,
+                    location,
+                },
+            );
+        }
+
+        for i in 0..attribute_count {
+            let info = match self.context.active_attribute(&self.program, i) {
+                Some(info) => info,
+                None => continue,
+            };
+            let name = info.name;
+            let location = self.context.get_attrib_location(&self.program, &name);
+            if location < 0 {
+                continue;
+            }
+
+            self.attributes.insert(
+                name.clone(),
+                ActiveAttribute {
+                    name,
+                    kind: info.kind,
+                    size: info.size,
+                    location: location as u32,
+                },
+            );
+        }
+    }
+
+    // setter の前に呼ぶ
+    pub fn bind(&self) {
+        self.context.use_program(Some(&self.program));
+    }
+
+    pub fn uniforms(&self) -> impl Iterator<Item = &ActiveUniform<C>> {
+        self.uniforms.values()
+    }
+
+    pub fn attributes(&self) -> impl Iterator<Item = &ActiveAttribute> {
+        self.attributes.values()
+    }
+
+    pub fn uniform(&self, name: &str) -> Option<&ActiveUniform<C>> {
+        self.uniforms.get(name)
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&ActiveAttribute> {
+        self.attributes.get(name)
+    }
+
+    pub fn has_uniform(&self, name: &str) -> bool {
+        self.uniforms.contains_key(name)
+    }
+
+    // 材質や光源のように program によって有ったり無かったりする uniform 用。無ければ None
+    pub fn uniform_location(&self, name: &str) -> Option<C::UniformLocation> {
+        self.uniforms.get(name).map(|u| u.location.clone())
+    }
+
+    pub fn set_int(&self, name: &str, value: i32) -> Result<(), String> {
+        let uniform = self.check(name, &[WebGlRenderingContext::INT], 1)?;
+        self.context.uniform1i(Some(&uniform.location), value);
+
+        Ok(())
+    }
+
+    pub fn set_bool(&self, name: &str, value: bool) -> Result<(), String> {
+        let uniform = self.check(name, &[WebGlRenderingContext::BOOL], 1)?;
+        self.context.uniform1i(Some(&uniform.location), value as i32);
+
+        Ok(())
+    }
+
+    // unit は TEXTURE0 からの番号
+    pub fn set_sampler(&self, name: &str, unit: i32) -> Result<(), String> {
+        let kinds = [WebGlRenderingContext::SAMPLER_2D, WebGlRenderingContext::SAMPLER_CUBE];
+        let uniform = self.check(name, &kinds, 1)?;
+        self.context.uniform1i(Some(&uniform.location), unit);
+
+        Ok(())
+    }
+
+    pub fn set_float(&self, name: &str, value: f32) -> Result<(), String> {
+        let uniform = self.check(name, &[WebGlRenderingContext::FLOAT], 1)?;
+        self.context.uniform1f(Some(&uniform.location), value);
+
+        Ok(())
+    }
+
+    // vec2 / vec3 / vec4 は配列なら要素数分まとめて渡せる
+    pub fn set_vec2(&self, name: &str, value: &[f32]) -> Result<(), String> {
+        let uniform = self.check(name, &[WebGlRenderingContext::FLOAT_VEC2], Self::elements(name, value, 2)?)?;
+        self.context.uniform2fv_with_f32_array(Some(&uniform.location), value);
+
+        Ok(())
+    }
+
+    pub fn set_vec3(&self, name: &str, value: &[f32]) -> Result<(), String> {
+        let uniform = self.check(name, &[WebGlRenderingContext::FLOAT_VEC3], Self::elements(name, value, 3)?)?;
+        self.context.uniform3fv_with_f32_array(Some(&uniform.location), value);
+
+        Ok(())
+    }
+
+    pub fn set_vec4(&self, name: &str, value: &[f32]) -> Result<(), String> {
+        let uniform = self.check(name, &[WebGlRenderingContext::FLOAT_VEC4], Self::elements(name, value, 4)?)?;
+        self.context.uniform4fv_with_f32_array(Some(&uniform.location), value);
+
+        Ok(())
+    }
+
+    pub fn set_mat4(&self, name: &str, value: &[f32]) -> Result<(), String> {
+        let uniform = self.check(name, &[WebGlRenderingContext::FLOAT_MAT4], Self::elements(name, value, 16)?)?;
+        self.context
+            .uniform_matrix4fv_with_f32_array(Some(&uniform.location), false, value);
+
+        Ok(())
+    }
+
+    fn elements(name: &str, value: &[f32], components: usize) -> Result<i32, String> {
+        if value.is_empty() || !value.len().is_multiple_of(components) {
+            return Err(format!("{}: expected a multiple of {} floats, got {}", name, components, value.len()));
+        }
+
+        Ok((value.len() / components) as i32)
+    }
+
+    fn check(&self, name: &str, kinds: &[u32], elements: i32) -> Result<&ActiveUniform<C>, String> {
+        let uniform = self
+            .uniforms
+            .get(name)
+            .ok_or_else(|| format!("no active uniform {}", name))?;
+
+        if !kinds.contains(&uniform.kind) {
+            return Err(format!(
+                "{} is {}, not {}",
+                name,
+                type_name(uniform.kind),
+                kinds.iter().map(|k| type_name(*k)).collect::<Vec<_>>().join(" or ")
+            ));
+        }
+        if elements > uniform.size {
+            return Err(format!("{} has {} elements, got {}", name, uniform.size, elements));
+        }
+
+        Ok(uniform)
+    }
+}
+
+// エラー表示用の GLSL の型名
+pub fn type_name(kind: u32) -> &'static str {
+    match kind {
+        WebGlRenderingContext::FLOAT => "float",
+        WebGlRenderingContext::FLOAT_VEC2 => "vec2",
+        WebGlRenderingContext::FLOAT_VEC3 => "vec3",
+        WebGlRenderingContext::FLOAT_VEC4 => "vec4",
+        WebGlRenderingContext::INT => "int",
+        WebGlRenderingContext::INT_VEC2 => "ivec2",
+        WebGlRenderingContext::INT_VEC3 => "ivec3",
+        WebGlRenderingContext::INT_VEC4 => "ivec4",
+        WebGlRenderingContext::BOOL => "bool",
+        WebGlRenderingContext::BOOL_VEC2 => "bvec2",
+        WebGlRenderingContext::BOOL_VEC3 => "bvec3",
+        WebGlRenderingContext::BOOL_VEC4 => "bvec4",
+        WebGlRenderingContext::FLOAT_MAT2 => "mat2",
+        WebGlRenderingContext::FLOAT_MAT3 => "mat3",
+        WebGlRenderingContext::FLOAT_MAT4 => "mat4",
+        WebGlRenderingContext::SAMPLER_2D => "sampler2D",
+        WebGlRenderingContext::SAMPLER_CUBE => "samplerCube",
+        _ => "unknown",
+    }
+}
@@ -0,0 +1,701 @@
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use web_sys::WebGlRenderingContext;
+
+use crate::backend::{ActiveInfo, Backend};
+
+// Recorder が作った GL の物の番号。種類によらず通し番号
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Handle(pub u32);
+
+// uniform は program と名前で区別する
+#[derive(Clone, Debug, PartialEq)]
+pub struct Location {
+    pub program: Handle,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum UniformValue {
+    Int(i32),
+    Float(f32),
+    Vec2(Vec<f32>),
+    Vec3(Vec<f32>),
+    Vec4(Vec<f32>),
+    Mat4(Vec<f32>),
+}
+
+// Backend の呼び出しのうち GL の状態を変えるもの。問い合わせ (get_* や status) は残さない
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    Viewport(i32, i32, i32, i32),
+    ClearColor([f32; 4]),
+    ClearDepth(f32),
+    Clear(u32),
+    Enable(u32),
+    DepthFunc(u32),
+    Flush,
+
+    CreateBuffer(Handle),
+    DeleteBuffer(Option<Handle>),
+    BindBuffer { target: u32, buffer: Option<Handle> },
+    // bytes は送った byte 数
+    BufferData { target: u32, bytes: usize, usage: u32 },
+
+    CreateTexture(Handle),
+    DeleteTexture(Option<Handle>),
+    BindTexture { target: u32, texture: Option<Handle> },
+    ActiveTexture(u32),
+    TexImage2D { target: u32, level: i32, internal_format: i32, width: i32, height: i32, format: u32, kind: u32, bytes: Option<usize> },
+    TexParameter { target: u32, pname: u32, param: i32 },
+    GenerateMipmap(u32),
+
+    CreateFramebuffer(Handle),
+    DeleteFramebuffer(Option<Handle>),
+    BindFramebuffer { target: u32, framebuffer: Option<Handle> },
+    FramebufferTexture2D { target: u32, attachment: u32, textarget: u32, texture: Option<Handle>, level: i32 },
+    CreateRenderbuffer(Handle),
+    DeleteRenderbuffer(Option<Handle>),
+    BindRenderbuffer { target: u32, renderbuffer: Option<Handle> },
+    RenderbufferStorage { target: u32, format: u32, width: i32, height: i32 },
+    FramebufferRenderbuffer { target: u32, attachment: u32, renderbuffer: Option<Handle> },
+
+    CreateShader { shader: Handle, kind: u32 },
+    DeleteShader(Option<Handle>),
+    ShaderSource { shader: Handle, source: String },
+    CompileShader(Handle),
+    CreateProgram(Handle),
+    DeleteProgram(Option<Handle>),
+    AttachShader { program: Handle, shader: Handle },
+    LinkProgram(Handle),
+    UseProgram(Option<Handle>),
+
+    // location が None (使われていない uniform) の時も残す
+    Uniform { location: Option<Location>, value: UniformValue },
+
+    EnableVertexAttribArray(u32),
+    DisableVertexAttribArray(u32),
+    VertexAttribPointer { index: u32, size: i32, kind: u32, normalized: bool, stride: i32, offset: i32 },
+    VertexAttrib4f { index: u32, value: [f32; 4] },
+
+    DrawElements { mode: u32, count: i32, kind: u32, offset: i32 },
+    DrawArrays { mode: u32, first: i32, count: i32 },
+}
+
+// link 時に shader の source から読み取った変数
+#[derive(Debug, Default)]
+struct LinkedProgram {
+    shaders: Vec<Handle>,
+    uniforms: Vec<ActiveInfo>,
+    attributes: Vec<ActiveInfo>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    commands: Vec<Command>,
+    shaders: HashMap<Handle, (u32, String)>,
+    // Backend::label_shader で付いた名前。GL の状態ではないので Command には残さない
+    labels: HashMap<Handle, String>,
+    programs: HashMap<Handle, LinkedProgram>,
+}
+
+// ブラウザ無しで描画の命令列を調べるための Backend。GL の代わりに Command を溜める
+// compile と link は常に成功し、shader に書かれた uniform と attribute は全て active として扱う
+#[derive(Clone, Debug, Default)]
+pub struct Recorder {
+    state: Rc<RefCell<State>>,
+    next: Rc<Cell<u32>>,
+    extensions: Rc<HashSet<String>>,
+}
+
+impl Recorder {
+    pub fn new() -> Recorder {
+        Recorder::default()
+    }
+
+    // enable_extension が true を返す拡張 (WEBGL_depth_texture など)
+    pub fn with_extensions(extensions: &[&str]) -> Recorder {
+        Recorder {
+            extensions: Rc::new(extensions.iter().map(|e| e.to_string()).collect()),
+            ..Recorder::default()
+        }
+    }
+
+    pub fn commands(&self) -> Vec<Command> {
+        self.state.borrow().commands.clone()
+    }
+
+    // 今までの命令を返して空にする
+    pub fn take(&self) -> Vec<Command> {
+        std::mem::take(&mut self.state.borrow_mut().commands)
+    }
+
+    // Backend::clear (glClear) と区別する
+    pub fn clear_commands(&self) {
+        self.state.borrow_mut().commands.clear();
+    }
+
+    pub fn draw_count(&self) -> usize {
+        self.state
+            .borrow()
+            .commands
+            .iter()
+            .filter(|c| matches!(c, Command::DrawElements { .. } | Command::DrawArrays { .. }))
+            .count()
+    }
+
+    // uniform を設定した値を古い順に
+    pub fn uniform_values(&self, name: &str) -> Vec<UniformValue> {
+        self.state
+            .borrow()
+            .commands
+            .iter()
+            .filter_map(|c| match c {
+                Command::Uniform {
+                    location: Some(location),
+                    value,
+                } if location.name == name => Some(value.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // program に attach した shader の source
+    pub fn program_sources(&self, program: Handle) -> Vec<String> {
+        let state = self.state.borrow();
+        state
+            .programs
+            .get(&program)
+            .map(|p| {
+                p.shaders
+                    .iter()
+                    .filter_map(|s| state.shaders.get(s).map(|(_, source)| source.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    // program に attach した shader の library での名前 ("standard.vert", "pbr.frag" など)
+    pub fn program_labels(&self, program: Handle) -> Vec<String> {
+        let state = self.state.borrow();
+        state
+            .programs
+            .get(&program)
+            .map(|p| p.shaders.iter().filter_map(|s| state.labels.get(s).cloned()).collect())
+            .unwrap_or_default()
+    }
+
+    fn handle(&self) -> Handle {
+        let id = self.next.get() + 1;
+        self.next.set(id);
+        Handle(id)
+    }
+
+    fn record(&self, command: Command) {
+        self.state.borrow_mut().commands.push(command);
+    }
+
+    fn uniform(&self, location: Option<&Location>, value: UniformValue) {
+        self.record(Command::Uniform {
+            location: location.cloned(),
+            value,
+        });
+    }
+}
+
+impl Backend for Recorder {
+    type Buffer = Handle;
+    type Texture = Handle;
+    type Framebuffer = Handle;
+    type Renderbuffer = Handle;
+    type Shader = Handle;
+    type Program = Handle;
+    type UniformLocation = Location;
+
+    fn enable_extension(&self, name: &str) -> bool {
+        self.extensions.contains(name)
+    }
+
+    fn viewport(&self, x: i32, y: i32, width: i32, height: i32) {
+        self.record(Command::Viewport(x, y, width, height));
+    }
+
+    fn clear_color(&self, red: f32, green: f32, blue: f32, alpha: f32) {
+        self.record(Command::ClearColor([red, green, blue, alpha]));
+    }
+
+    fn clear_depth(&self, depth: f32) {
+        self.record(Command::ClearDepth(depth));
+    }
+
+    fn clear(&self, mask: u32) {
+        self.record(Command::Clear(mask));
+    }
+
+    fn enable(&self, cap: u32) {
+        self.record(Command::Enable(cap));
+    }
+
+    fn depth_func(&self, func: u32) {
+        self.record(Command::DepthFunc(func));
+    }
+
+    fn flush(&self) {
+        self.record(Command::Flush);
+    }
+
+    fn create_buffer(&self) -> Option<Handle> {
+        let buffer = self.handle();
+        self.record(Command::CreateBuffer(buffer));
+        Some(buffer)
+    }
+
+    fn delete_buffer(&self, buffer: Option<&Handle>) {
+        self.record(Command::DeleteBuffer(buffer.copied()));
+    }
+
+    fn bind_buffer(&self, target: u32, buffer: Option<&Handle>) {
+        self.record(Command::BindBuffer {
+            target,
+            buffer: buffer.copied(),
+        });
+    }
+
+    fn buffer_data_with_f32_array(&self, target: u32, data: &[f32], usage: u32) {
+        self.record(Command::BufferData {
+            target,
+            bytes: std::mem::size_of_val(data),
+            usage,
+        });
+    }
+
+    fn buffer_data_with_u16_array(&self, target: u32, data: &[u16], usage: u32) {
+        self.record(Command::BufferData {
+            target,
+            bytes: std::mem::size_of_val(data),
+            usage,
+        });
+    }
+
+    fn buffer_data_with_u8_array(&self, target: u32, data: &[u8], usage: u32) {
+        self.record(Command::BufferData {
+            target,
+            bytes: data.len(),
+            usage,
+        });
+    }
+
+    fn create_texture(&self) -> Option<Handle> {
+        let texture = self.handle();
+        self.record(Command::CreateTexture(texture));
+        Some(texture)
+    }
+
+    fn delete_texture(&self, texture: Option<&Handle>) {
+        self.record(Command::DeleteTexture(texture.copied()));
+    }
+
+    fn bind_texture(&self, target: u32, texture: Option<&Handle>) {
+        self.record(Command::BindTexture {
+            target,
+            texture: texture.copied(),
+        });
+    }
+
+    fn active_texture(&self, texture: u32) {
+        self.record(Command::ActiveTexture(texture));
+    }
+
+    fn tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+        &self,
+        target: u32,
+        level: i32,
+        internal_format: i32,
+        width: i32,
+        height: i32,
+        _border: i32,
+        format: u32,
+        kind: u32,
+        pixels: Option<&[u8]>,
+    ) -> Result<(), String> {
+        self.record(Command::TexImage2D {
+            target,
+            level,
+            internal_format,
+            width,
+            height,
+            format,
+            kind,
+            bytes: pixels.map(|p| p.len()),
+        });
+
+        Ok(())
+    }
+
+    fn tex_parameteri(&self, target: u32, pname: u32, param: i32) {
+        self.record(Command::TexParameter { target, pname, param });
+    }
+
+    fn generate_mipmap(&self, target: u32) {
+        self.record(Command::GenerateMipmap(target));
+    }
+
+    fn create_framebuffer(&self) -> Option<Handle> {
+        let framebuffer = self.handle();
+        self.record(Command::CreateFramebuffer(framebuffer));
+        Some(framebuffer)
+    }
+
+    fn delete_framebuffer(&self, framebuffer: Option<&Handle>) {
+        self.record(Command::DeleteFramebuffer(framebuffer.copied()));
+    }
+
+    fn bind_framebuffer(&self, target: u32, framebuffer: Option<&Handle>) {
+        self.record(Command::BindFramebuffer {
+            target,
+            framebuffer: framebuffer.copied(),
+        });
+    }
+
+    fn check_framebuffer_status(&self, _target: u32) -> u32 {
+        WebGlRenderingContext::FRAMEBUFFER_COMPLETE
+    }
+
+    fn framebuffer_texture_2d(&self, target: u32, attachment: u32, textarget: u32, texture: Option<&Handle>, level: i32) {
+        self.record(Command::FramebufferTexture2D {
+            target,
+            attachment,
+            textarget,
+            texture: texture.copied(),
+            level,
+        });
+    }
+
+    fn create_renderbuffer(&self) -> Option<Handle> {
+        let renderbuffer = self.handle();
+        self.record(Command::CreateRenderbuffer(renderbuffer));
+        Some(renderbuffer)
+    }
+
+    fn delete_renderbuffer(&self, renderbuffer: Option<&Handle>) {
+        self.record(Command::DeleteRenderbuffer(renderbuffer.copied()));
+    }
+
+    fn bind_renderbuffer(&self, target: u32, renderbuffer: Option<&Handle>) {
+        self.record(Command::BindRenderbuffer {
+            target,
+            renderbuffer: renderbuffer.copied(),
+        });
+    }
+
+    fn renderbuffer_storage(&self, target: u32, format: u32, width: i32, height: i32) {
+        self.record(Command::RenderbufferStorage {
+            target,
+            format,
+            width,
+            height,
+        });
+    }
+
+    fn framebuffer_renderbuffer(&self, target: u32, attachment: u32, _renderbuffer_target: u32, renderbuffer: Option<&Handle>) {
+        self.record(Command::FramebufferRenderbuffer {
+            target,
+            attachment,
+            renderbuffer: renderbuffer.copied(),
+        });
+    }
+
+    fn create_shader(&self, kind: u32) -> Option<Handle> {
+        let shader = self.handle();
+        self.state.borrow_mut().shaders.insert(shader, (kind, String::new()));
+        self.record(Command::CreateShader { shader, kind });
+        Some(shader)
+    }
+
+    fn delete_shader(&self, shader: Option<&Handle>) {
+        self.record(Command::DeleteShader(shader.copied()));
+    }
+
+    fn shader_source(&self, shader: &Handle, source: &str) {
+        if let Some((_, stored)) = self.state.borrow_mut().shaders.get_mut(shader) {
+            *stored = source.to_string();
+        }
+        self.record(Command::ShaderSource {
+            shader: *shader,
+            source: source.to_string(),
+        });
+    }
+
+    fn compile_shader(&self, shader: &Handle) {
+        self.record(Command::CompileShader(*shader));
+    }
+
+    fn label_shader(&self, shader: &Handle, name: &str) {
+        self.state.borrow_mut().labels.insert(*shader, name.to_string());
+    }
+
+    fn shader_compile_status(&self, _shader: &Handle) -> bool {
+        true
+    }
+
+    fn get_shader_info_log(&self, _shader: &Handle) -> Option<String> {
+        Some(String::new())
+    }
+
+    fn create_program(&self) -> Option<Handle> {
+        let program = self.handle();
+        self.state.borrow_mut().programs.insert(program, LinkedProgram::default());
+        self.record(Command::CreateProgram(program));
+        Some(program)
+    }
+
+    fn delete_program(&self, program: Option<&Handle>) {
+        self.record(Command::DeleteProgram(program.copied()));
+    }
+
+    fn attach_shader(&self, program: &Handle, shader: &Handle) {
+        if let Some(linked) = self.state.borrow_mut().programs.get_mut(program) {
+            linked.shaders.push(*shader);
+        }
+        self.record(Command::AttachShader {
+            program: *program,
+            shader: *shader,
+        });
+    }
+
+    fn link_program(&self, program: &Handle) {
+        let sources = self.program_sources(*program);
+        let (uniforms, attributes) = declarations(&sources);
+
+        if let Some(linked) = self.state.borrow_mut().programs.get_mut(program) {
+            linked.uniforms = uniforms;
+            linked.attributes = attributes;
+        }
+        self.record(Command::LinkProgram(*program));
+    }
+
+    fn program_link_status(&self, _program: &Handle) -> bool {
+        true
+    }
+
+    fn get_program_info_log(&self, _program: &Handle) -> Option<String> {
+        Some(String::new())
+    }
+
+    fn use_program(&self, program: Option<&Handle>) {
+        self.record(Command::UseProgram(program.copied()));
+    }
+
+    fn active_uniform_count(&self, program: &Handle) -> u32 {
+        self.state
+            .borrow()
+            .programs
+            .get(program)
+            .map(|p| p.uniforms.len() as u32)
+            .unwrap_or(0)
+    }
+
+    fn active_attribute_count(&self, program: &Handle) -> u32 {
+        self.state
+            .borrow()
+            .programs
+            .get(program)
+            .map(|p| p.attributes.len() as u32)
+            .unwrap_or(0)
+    }
+
+    fn active_uniform(&self, program: &Handle, index: u32) -> Option<ActiveInfo> {
+        self.state
+            .borrow()
+            .programs
+            .get(program)
+            .and_then(|p| p.uniforms.get(index as usize).cloned())
+    }
+
+    fn active_attribute(&self, program: &Handle, index: u32) -> Option<ActiveInfo> {
+        self.state
+            .borrow()
+            .programs
+            .get(program)
+            .and_then(|p| p.attributes.get(index as usize).cloned())
+    }
+
+    fn get_uniform_location(&self, program: &Handle, name: &str) -> Option<Location> {
+        let state = self.state.borrow();
+        let base = name.strip_suffix("[0]").unwrap_or(name);
+        let found = state
+            .programs
+            .get(program)?
+            .uniforms
+            .iter()
+            .any(|u| u.name.strip_suffix("[0]").unwrap_or(&u.name) == base);
+
+        if found {
+            Some(Location {
+                program: *program,
+                name: base.to_string(),
+            })
+        } else {
+            None
+        }
+    }
+
+    fn get_attrib_location(&self, program: &Handle, name: &str) -> i32 {
+        self.state
+            .borrow()
+            .programs
+            .get(program)
+            .and_then(|p| p.attributes.iter().position(|a| a.name == name))
+            .map(|i| i as i32)
+            .unwrap_or(-1)
+    }
+
+    fn uniform1i(&self, location: Option<&Location>, x: i32) {
+        self.uniform(location, UniformValue::Int(x));
+    }
+
+    fn uniform1f(&self, location: Option<&Location>, x: f32) {
+        self.uniform(location, UniformValue::Float(x));
+    }
+
+    fn uniform2f(&self, location: Option<&Location>, x: f32, y: f32) {
+        self.uniform(location, UniformValue::Vec2(vec![x, y]));
+    }
+
+    fn uniform2fv_with_f32_array(&self, location: Option<&Location>, data: &[f32]) {
+        self.uniform(location, UniformValue::Vec2(data.to_vec()));
+    }
+
+    fn uniform3fv_with_f32_array(&self, location: Option<&Location>, data: &[f32]) {
+        self.uniform(location, UniformValue::Vec3(data.to_vec()));
+    }
+
+    fn uniform4fv_with_f32_array(&self, location: Option<&Location>, data: &[f32]) {
+        self.uniform(location, UniformValue::Vec4(data.to_vec()));
+    }
+
+    fn uniform_matrix4fv_with_f32_array(&self, location: Option<&Location>, _transpose: bool, data: &[f32]) {
+        self.uniform(location, UniformValue::Mat4(data.to_vec()));
+    }
+
+    fn enable_vertex_attrib_array(&self, index: u32) {
+        self.record(Command::EnableVertexAttribArray(index));
+    }
+
+    fn disable_vertex_attrib_array(&self, index: u32) {
+        self.record(Command::DisableVertexAttribArray(index));
+    }
+
+    fn vertex_attrib_pointer_with_i32(&self, index: u32, size: i32, kind: u32, normalized: bool, stride: i32, offset: i32) {
+        self.record(Command::VertexAttribPointer {
+            index,
+            size,
+            kind,
+            normalized,
+            stride,
+            offset,
+        });
+    }
+
+    fn vertex_attrib4f(&self, index: u32, x: f32, y: f32, z: f32, w: f32) {
+        self.record(Command::VertexAttrib4f {
+            index,
+            value: [x, y, z, w],
+        });
+    }
+
+    fn draw_elements_with_i32(&self, mode: u32, count: i32, kind: u32, offset: i32) {
+        self.record(Command::DrawElements {
+            mode,
+            count,
+            kind,
+            offset,
+        });
+    }
+
+    fn draw_arrays(&self, mode: u32, first: i32, count: i32) {
+        self.record(Command::DrawArrays { mode, first, count });
+    }
+}
+
+// "uniform vec3 uLightColor[MAX_LIGHTS];" のような宣言を拾う。配列の大きさは #define も見る
+// 頂点 shader と fragment shader の両方にある uniform は一つにまとめる
+pub(crate) fn declarations(sources: &[String]) -> (Vec<ActiveInfo>, Vec<ActiveInfo>) {
+    let mut uniforms: Vec<ActiveInfo> = Vec::new();
+    let mut attributes: Vec<ActiveInfo> = Vec::new();
+
+    for source in sources.iter() {
+        let mut defines = HashMap::new();
+        for line in source.lines() {
+            let words: Vec<&str> = line
+                .split(|c: char| c.is_whitespace() || c == ';')
+                .filter(|w| !w.is_empty())
+                .collect();
+
+            match words.as_slice() {
+                ["#define", name, value, ..] => {
+                    defines.insert(name.to_string(), value.to_string());
+                }
+                ["uniform", rest @ ..] | ["attribute", rest @ ..] => {
+                    // 精度の修飾子を飛ばす
+                    let rest: Vec<&str> = rest
+                        .iter()
+                        .copied()
+                        .filter(|w| !matches!(*w, "lowp" | "mediump" | "highp"))
+                        .collect();
+                    let (kind, declarator) = match rest.as_slice() {
+                        [kind, declarator, ..] => (*kind, *declarator),
+                        _ => continue,
+                    };
+
+                    let (name, size) = match declarator.split_once('[') {
+                        Some((name, size)) => {
+                            let size = size.trim_end_matches(']');
+                            let size = defines.get(size).map(|s| s.as_str()).unwrap_or(size);
+                            (name, size.parse().unwrap_or(1))
+                        }
+                        None => (declarator, 1),
+                    };
+                    let info = ActiveInfo {
+                        name: if size > 1 { format!("{}[0]", name) } else { name.to_string() },
+                        size,
+                        kind: type_enum(kind),
+                    };
+
+                    let list = if words[0] == "uniform" { &mut uniforms } else { &mut attributes };
+                    if !list.iter().any(|i| i.name == info.name) {
+                        list.push(info);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    (uniforms, attributes)
+}
+
+fn type_enum(name: &str) -> u32 {
+    match name {
+        "float" => WebGlRenderingContext::FLOAT,
+        "vec2" => WebGlRenderingContext::FLOAT_VEC2,
+        "vec3" => WebGlRenderingContext::FLOAT_VEC3,
+        "vec4" => WebGlRenderingContext::FLOAT_VEC4,
+        "int" => WebGlRenderingContext::INT,
+        "ivec2" => WebGlRenderingContext::INT_VEC2,
+        "ivec3" => WebGlRenderingContext::INT_VEC3,
+        "ivec4" => WebGlRenderingContext::INT_VEC4,
+        "bool" => WebGlRenderingContext::BOOL,
+        "bvec2" => WebGlRenderingContext::BOOL_VEC2,
+        "bvec3" => WebGlRenderingContext::BOOL_VEC3,
+        "bvec4" => WebGlRenderingContext::BOOL_VEC4,
+        "mat2" => WebGlRenderingContext::FLOAT_MAT2,
+        "mat3" => WebGlRenderingContext::FLOAT_MAT3,
+        "mat4" => WebGlRenderingContext::FLOAT_MAT4,
+        "sampler2D" => WebGlRenderingContext::SAMPLER_2D,
+        "samplerCube" => WebGlRenderingContext::SAMPLER_CUBE,
+        _ => 0,
+    }
+}
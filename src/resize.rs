@@ -0,0 +1,58 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlCanvasElement, ResizeObserver, Window};
+
+// 表示サイズ (CSS px) x devicePixelRatio に描画バッファを合わせる。変わった時だけ新しいサイズを返す
+pub fn fit_canvas(canvas: &HtmlCanvasElement) -> Option<(i32, i32)> {
+    let ratio = web_sys::window().map_or(1.0, |window| window.device_pixel_ratio());
+    let width = ((canvas.client_width() as f64 * ratio).round() as u32).max(1);
+    let height = ((canvas.client_height() as f64 * ratio).round() as u32).max(1);
+
+    if canvas.width() == width && canvas.height() == height {
+        return None;
+    }
+
+    canvas.set_width(width);
+    canvas.set_height(height);
+
+    Some((width as i32, height as i32))
+}
+
+// canvas の ResizeObserver と window の resize を監視する。drop でどちらも外す
+// ResizeObserver は停止中に CSS やレイアウトで canvas だけが変わった時のため。window の resize は devicePixelRatio の変化と
+// ResizeObserver の無いブラウザのために残す
+pub struct Resize {
+    window: Window,
+    // ResizeObserver の無いブラウザでは constructor が例外を投げるので None
+    observer: Option<ResizeObserver>,
+    listener: Closure<dyn FnMut()>,
+}
+
+impl Resize {
+    pub fn observe<F>(target: &Element, f: F) -> Result<Resize, JsValue>
+    where
+        F: FnMut() + 'static,
+    {
+        let window = web_sys::window().ok_or("no window")?;
+        let listener = Closure::wrap(Box::new(f) as Box<dyn FnMut()>);
+        window.add_event_listener_with_callback("resize", listener.as_ref().unchecked_ref())?;
+
+        let observer = ResizeObserver::new(listener.as_ref().unchecked_ref()).ok();
+        if let Some(observer) = observer.as_ref() {
+            observer.observe(target);
+        }
+
+        Ok(Resize { window, observer, listener })
+    }
+}
+
+impl Drop for Resize {
+    fn drop(&mut self) {
+        if let Some(observer) = self.observer.as_ref() {
+            observer.disconnect();
+        }
+        let _ = self
+            .window
+            .remove_event_listener_with_callback("resize", self.listener.as_ref().unchecked_ref());
+    }
+}
@@ -1,286 +1,637 @@
-use wasm_bindgen::prelude::*;
-use web_sys::{WebGlBuffer, WebGlRenderingContext, WebGlTexture, WebGlUniformLocation };
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
 
-use crate::buffer;
+use image::RgbaImage;
+use web_sys::WebGlRenderingContext;
+
+use crate::backend::Backend;
+use crate::camera::Camera;
+use crate::geometry::Geometry;
+use crate::graph::{MeshId, NodeId, Renderable, SceneGraph, Transform};
+use crate::ibl::{self, Environment, IblUniforms};
+use crate::light::{self, Light, LightKind, LightUniforms};
+use crate::log;
+use crate::material::{BlinnPhong, Material, MaterialUniforms};
+use crate::mesh::Mesh;
+use crate::plane;
+use crate::probe::{ProbeUpdate, ReflectionProbe};
+use crate::program::Program;
+use crate::shader::ShaderCache;
+use crate::shadow::{ShadowMap, ShadowOptions, ShadowUniforms};
+use crate::skybox::Skybox;
 use crate::teapot;
-use crate::cube;
-use crate::shader;
-
-pub struct Scene<'a> {
-    context: &'a WebGlRenderingContext,
-    width: i32,
-    height: i32,
-    
-    teapot_vertex: Option<WebGlBuffer>,
-    teapot_index: Option<WebGlBuffer>,
-    teapot_normal: Option<WebGlBuffer>,
-    cube_vertex: Option<WebGlBuffer>,
-    cube_index: Option<WebGlBuffer>,
-    cube_normal: Option<WebGlBuffer>,
-
-    position: i32,
-    normal: i32,
-    color: i32,
-
-    m: Option<WebGlUniformLocation>,
-    mvp: Option<WebGlUniformLocation>,
-    eye: Option<WebGlUniformLocation>,
-    cube: Option<WebGlUniformLocation>,
-
-    cube_texture: Option<WebGlTexture>,
+use crate::texture::{self, Texture};
+use crate::weld;
+
+pub struct Scene<C: Backend = WebGlRenderingContext> {
+    context: C,
+    camera: Rc<RefCell<Camera>>,
+    viewport: (i32, i32),
+    spin: f32,
+    clear_color: [f32; 4],
+    lights: Vec<Light>,
+
+    graph: SceneGraph,
+    meshes: Vec<Mesh<C>>,
+    // set_model の行列を持つ node と、その子で spin で回りながら teapot を描く node
+    teapot_root: NodeId,
+    teapot: NodeId,
+    ground: Option<NodeId>,
+    ground_mesh: Option<MeshId>,
+    skybox: Skybox<C>,
+    shadow: Option<ShadowMap<C>>,
+    // teapot の中心から描いた動的な環境マップ。None なら cube_texture を映す
+    probe: Option<ReflectionProbe<C>>,
+
+    // fragment shader の名前ごとの technique。使われている材質の分だけ render の前に作る
+    shaders: ShaderCache<C>,
+    techniques: HashMap<&'static str, Technique<C>>,
+    texture_lod: bool,
+    derivatives: bool,
+
+    cube_texture: Option<Texture<C>>,
+    environment: Option<Environment<C>>,
+    brdf_lut: Option<Texture<C>>,
 }
 
-impl<'a> Scene<'a> {
+// 材質ごとの shader と、program によって有ったり無かったりする uniform の位置
+struct Technique<C: Backend> {
+    program: Rc<Program<C>>,
+
+    lights: LightUniforms<C>,
+    material: MaterialUniforms<C>,
+    ibl: IblUniforms<C>,
+    shadow: ShadowUniforms<C>,
+}
+
+impl<C: Backend> Technique<C> {
+    fn new(program: Rc<Program<C>>) -> Technique<C> {
+        Technique {
+            lights: LightUniforms::new(&program),
+            material: MaterialUniforms::new(&program),
+            ibl: IblUniforms::new(&program),
+            shadow: ShadowUniforms::new(&program),
+
+            program,
+        }
+    }
+}
+
+impl<C: Backend> Scene<C> {
     pub fn new_with_context(
         width: i32,
         height: i32,
-        context: &'a WebGlRenderingContext,
-    ) -> Result<Self, JsValue> {
-        let vert_shader = shader::vertex_shader(context)?;
-        let frag_shader = shader::fragment_shader(context)?;
-        let program = shader::create_program(context, &vert_shader, &frag_shader)?;
-        context.use_program(Some(&program));
-
-        let position = context.get_attrib_location(&program, "aPosition");
-        let normal = context.get_attrib_location(&program, "aNormal");
-        let color = context.get_attrib_location(&program, "aColor");
-        
-        let m = context.get_uniform_location(&program, "uModelMatrix"); // Model行列
-        let mvp = context.get_uniform_location(&program, "uMVPMatrix"); // ModelViewProjection行列
-        let eye = context.get_uniform_location(&program, "eyePosition");
-        let cube = context.get_uniform_location(&program, "cubeTexture");
-
-        let cube_texture = Self::create_texture(context).ok();
+        context: &C,
+    ) -> Result<Self, String> {
+        // 無ければ pbr.frag は微分から見積もった bias で近い level を引く
+        let texture_lod = context.enable_extension("EXT_shader_texture_lod");
+        let derivatives = !texture_lod && context.enable_extension("OES_standard_derivatives");
+
+        let check: &[u8] = std::include_bytes!("check.png");
+        let faces = texture::decode_faces(&[check; 6])?;
+        let cube_texture = texture::create_cubemap(context, &faces).ok();
+        let environment = Environment::new(context, &faces).ok();
+        let brdf_lut = ibl::brdf_lut_texture(context).ok();
+        let teapot_geometry = Self::weld("teapot", &teapot::geometry());
+
+        // WebGL の実装によっては framebuffer が作れないので、その時は影なしで描く
+        let shadow = ShadowMap::new(context, ShadowOptions::default())
+            .map_err(|e| log::log(&format!("shadow map is disabled: {}", e)))
+            .ok();
 
         // カメラ
-        Ok(Scene {
-            width,
-            height,
+        let camera = Rc::new(RefCell::new(Camera::new(width as f32 / height.max(1) as f32)));
+
+        let translate = 
+            nalgebra_glm::translate(&nalgebra_glm::identity(), &nalgebra_glm::vec3(0.0, 0.0, 50.0));
+        let model = 
+            nalgebra_glm::rotate(&translate, std::f32::consts::FRAC_PI_4, &nalgebra_glm::vec3(-50.0, 0.0, 50.0));
 
-            context,
+        let mut graph = SceneGraph::new();
+        let teapot_root = graph.add("teapot", None)?;
+        graph.set_transform(teapot_root, Transform::from_matrix(&model))?;
+        let teapot = graph.add("teapot spin", Some(teapot_root))?;
+        if let Some(node) = graph.get_mut(teapot) {
+            node.renderable = Some(Renderable::new(MeshId(0), Material::default()));
+        }
 
-            position,
-            normal,
-            color,
+        let mut scene = Scene {
+            camera,
+            viewport: (width, height),
+            spin: 0.5,
+            clear_color: [0.0, 0.0, 0.0, 1.0],
+            // カメラ側の斜め上から照らす
+            lights: vec![Light::directional(
+                nalgebra_glm::vec3(-0.3, -0.6, 1.0),
+                nalgebra_glm::vec3(1.0, 1.0, 1.0),
+                1.0,
+            )],
 
-            teapot_vertex: buffer::vertex_buffer(context, teapot::VERTEX).ok(),
-            teapot_index: buffer::index_buffer(context, teapot::INDEX).ok(),
-            teapot_normal: buffer::vertex_buffer(context, teapot::NORMAL).ok(),
+            context: context.clone(),
 
-            cube_vertex: buffer::vertex_buffer(context, cube::VERTEX).ok(),
-            cube_index: buffer::index_buffer(context, cube::INDEX).ok(),
-            cube_normal: buffer::vertex_buffer(context, cube::NORMAL).ok(),
+            graph,
+            meshes: vec![Mesh::from_geometry(context, &teapot_geometry)?],
+            teapot_root,
+            teapot,
+            ground: None,
+            ground_mesh: None,
+
+            skybox: Skybox::new(context)?,
+            shadow,
+            probe: None,
           
-            m,
-            mvp,
-            eye,
-            cube,
+            shaders: ShaderCache::new(context),
+            techniques: HashMap::new(),
+            texture_lod,
+            derivatives,
+
+            cube_texture,
+            environment,
+            brdf_lut,
+        };
+        // shader の間違いは最初に分かるようにする
+        scene.prepare_techniques()?;
 
-            cube_texture: cube_texture,
-        })
+        Ok(scene)
     }
 
-    pub fn render(&mut self) -> Result<(), JsValue> {
-        // 視点座標
-        let projection_matrix = 
-            nalgebra_glm::perspective((self.width / self.height) as f32, 1.0472, 0.1, 200.0); 
-        
-        let center = nalgebra_glm::vec3(0.0, 0.0, 0.0);
-        let up = nalgebra_glm::vec3(0.0, 1.0, 0.0);
-        let eye = nalgebra_glm::vec3(0.0, 0.0, -10.0);
-        let view_matrix = nalgebra_glm::look_at(&eye, &center, &up);
-        let pv = projection_matrix * view_matrix;
-
-        let mut color = Vec::default();
-        for _ in 0..(cube::VERTEX.len() / 3) {
-            color.push(1.0);
-            color.push(1.0);
-            color.push(1.0);
-            color.push(1.0);
+    // 描画バッファの大きさが変わった時に viewport と縦横比を合わせる
+    pub fn resize(&mut self, width: i32, height: i32) {
+        self.context.viewport(0, 0, width, height);
+        self.viewport = (width, height);
+        self.camera.borrow_mut().aspect = width as f32 / height.max(1) as f32;
+    }
+
+    pub fn camera(&self) -> Rc<RefCell<Camera>> {
+        self.camera.clone()
+    }
+
+    pub fn graph(&self) -> &SceneGraph {
+        &self.graph
+    }
+
+    // node を足したり動かしたりする。描画の時に world 行列を計算し直す
+    pub fn graph_mut(&mut self) -> &mut SceneGraph {
+        &mut self.graph
+    }
+
+    // graph::Renderable から参照する形状を登録する
+    pub fn add_mesh(&mut self, geometry: &Geometry) -> Result<MeshId, String> {
+        self.meshes.push(Mesh::from_geometry(&self.context, geometry)?);
+
+        Ok(MeshId(self.meshes.len() - 1))
+    }
+
+    pub fn set_mesh(&mut self, mesh: MeshId, geometry: &Geometry) -> Result<(), String> {
+        let replacement = Mesh::from_geometry(&self.context, geometry)?;
+        let slot = self
+            .meshes
+            .get_mut(mesh.0)
+            .ok_or_else(|| format!("no mesh {:?}", mesh))?;
+        *slot = replacement;
+
+        Ok(())
+    }
+
+    // spin で回る teapot の node。子を付けると一緒に回る
+    pub fn teapot(&self) -> NodeId {
+        self.teapot
+    }
+
+    // teapot のモデル行列。spin (radian/秒) の回転はこの後に掛ける
+    pub fn set_model(&mut self, model: nalgebra_glm::Mat4) -> Result<(), String> {
+        self.graph.set_transform(self.teapot_root, Transform::from_matrix(&model))?;
+
+        Ok(())
+    }
+
+    pub fn set_spin(&mut self, spin: f32) {
+        self.spin = spin;
+    }
+
+    pub fn set_clear_color(&mut self, color: [f32; 4]) {
+        self.clear_color = color;
+    }
+
+    // 描画に使うのは先頭の light::MAX_LIGHTS 個まで
+    pub fn add_light(&mut self, light: Light) -> usize {
+        self.lights.push(light);
+        self.lights.len() - 1
+    }
+
+    pub fn light_mut(&mut self, index: usize) -> Option<&mut Light> {
+        self.lights.get_mut(index)
+    }
+
+    pub fn remove_light(&mut self, index: usize) -> Option<Light> {
+        if index < self.lights.len() {
+            Some(self.lights.remove(index))
+        } else {
+            None
         }
+    }
 
-        // cube
-        buffer::render_buffer(
-            self.context, 
-            self.cube_vertex.as_ref(), 
-            self.position, 
-            3
-        )?;
-        buffer::render_buffer(
-            self.context, 
-            self.cube_normal.as_ref(), 
-            self.normal, 
-            3
-        )?;
-        buffer::render_buffer(
-            self.context, 
-            buffer::vertex_buffer(self.context, color.as_slice()).ok().as_ref(), 
-            self.color, 
-            4
-        )?;
-        self.context
-            .bind_buffer(
-                WebGlRenderingContext::ELEMENT_ARRAY_BUFFER, 
-                self.cube_index.as_ref()
-            );
+    pub fn clear_lights(&mut self) {
+        self.lights.clear();
+    }
 
-        let scale = 
-            nalgebra_glm::scale(&nalgebra_glm::identity(), &nalgebra_glm::vec3(100.0, 100.0, 100.0));
-        self.context
-            .uniform_matrix4fv_with_f32_array(self.m.as_ref(), false, scale.as_slice());
+    pub fn lights(&self) -> &[Light] {
+        &self.lights
+    }
+
+    // teapot の材質。種類によって使う shader が変わる
+    pub fn set_material(&mut self, material: Material) {
+        if let Some(renderable) = self.graph.get_mut(self.teapot).and_then(|n| n.renderable.as_mut()) {
+            renderable.material = material;
+        }
+    }
+
+    pub fn material(&self) -> Option<&Material> {
+        self.graph
+            .get(self.teapot)
+            .and_then(|n| n.renderable.as_ref())
+            .map(|r| &r.material)
+    }
+
+    // +X, -X, +Y, -Y, +Z, -Z の順の画像データ
+    pub fn set_environment(&mut self, sources: &[&[u8]]) -> Result<(), String> {
+        let faces = texture::decode_faces(sources)?;
+        self.set_environment_faces(&faces)
+    }
+
+    // PBR 用の前計算もここで行う。空の面や大きさの揃わない面は Err
+    pub fn set_environment_faces(&mut self, faces: &[RgbaImage]) -> Result<(), String> {
+        texture::check_faces(faces)?;
+        self.cube_texture = Some(texture::create_cubemap(&self.context, faces)?);
+        self.environment = Some(Environment::new(&self.context, faces)?);
+
+        Ok(())
+    }
+
+    // bezier::tessellate などで作った形状に差し替える
+    pub fn set_teapot(&mut self, geometry: &Geometry) -> Result<(), String> {
+        let mesh = self
+            .graph
+            .get(self.teapot)
+            .and_then(|n| n.renderable.as_ref())
+            .map(|r| r.mesh)
+            .ok_or("teapot node has been removed")?;
+
+        self.set_mesh(mesh, geometry)
+    }
+
+    // world 座標の y = height に一辺 size の床を置く
+    pub fn set_ground(&mut self, height: f32, size: f32) -> Result<(), String> {
+        let geometry = plane::geometry(size);
+        let mesh = match self.ground_mesh {
+            Some(mesh) => {
+                self.set_mesh(mesh, &geometry)?;
+                mesh
+            }
+            None => self.add_mesh(&geometry)?,
+        };
+        self.ground_mesh = Some(mesh);
+
+        let ground = match self.ground.filter(|id| self.graph.get(*id).is_some()) {
+            Some(ground) => ground,
+            None => {
+                let ground = self.graph.add("ground", None)?;
+                if let Some(node) = self.graph.get_mut(ground) {
+                    node.renderable = Some(Renderable {
+                        mesh,
+                        material: Material::BlinnPhong(BlinnPhong {
+                            ambient: nalgebra_glm::vec3(0.2, 0.2, 0.2),
+                            diffuse: nalgebra_glm::vec3(0.8, 0.8, 0.8),
+                            specular: nalgebra_glm::vec3(0.1, 0.1, 0.1),
+                            shininess: 16.0,
+                            reflectivity: 0.0,
+                        }),
+                        // 自分自身に影を落とさない
+                        cast_shadow: false,
+                    });
+                }
+                ground
+            }
+        };
+        self.ground = Some(ground);
+
+        self.graph.set_transform(ground, Transform {
+            translation: nalgebra_glm::vec3(0.0, height, 0.0),
+            ..Transform::default()
+        })?;
+
+        Ok(())
+    }
+
+    pub fn remove_ground(&mut self) {
+        if let Some(ground) = self.ground.take() {
+            // 既に graph_mut から消されていても構わない
+            let _ = self.graph.remove(ground);
+        }
+    }
+
+    // 床が無い時は何もしない
+    pub fn set_ground_material(&mut self, material: Material) {
+        let ground = self.ground.and_then(|id| self.graph.get_mut(id));
+        if let Some(renderable) = ground.and_then(|n| n.renderable.as_mut()) {
+            renderable.material = material;
+        }
+    }
+
+    // size: 1 面の画素数。None で静的な環境マップに戻す
+    pub fn set_reflection_probe(&mut self, size: Option<u32>, update: ProbeUpdate) -> Result<(), String> {
+        self.probe = match size {
+            Some(size) => Some(ReflectionProbe::new(&self.context, size, update)?),
+            None => None,
+        };
+
+        Ok(())
+    }
+
+    // ProbeUpdate::OnDemand の時に次のフレームで描き直す
+    pub fn request_reflection_update(&mut self) {
+        if let Some(probe) = self.probe.as_mut() {
+            probe.request_update();
+        }
+    }
+
+    // 大きさが変わる時は shadow map を作り直す
+    pub fn set_shadow_options(&mut self, options: ShadowOptions) -> Result<(), String> {
+        match self.shadow.as_mut() {
+            Some(shadow) if shadow.options().size == options.size => shadow.set_options(options),
+            _ => self.shadow = Some(ShadowMap::new(&self.context, options)?),
+        }
+
+        Ok(())
+    }
+
+    // elapsed: 開始からの秒数, delta: 前フレームからの秒数
+    pub fn render(&mut self, elapsed: f32, _delta: f32) -> Result<(), String> {
+        let spin = Transform {
+            rotation: nalgebra_glm::quat_angle_axis(elapsed * self.spin, &nalgebra_glm::vec3(0.0, 1.0, 0.0)),
+            ..Transform::default()
+        };
+        // teapot の node が graph_mut から消されていたら回さない
+        let _ = self.graph.set_transform(self.teapot, spin);
+        self.graph.update();
+        self.prepare_techniques()?;
+
+        self.render_shadow()?;
+        self.render_probe()?;
+
+        let [r, g, b, a] = self.clear_color;
+        self.context.clear_color(r, g, b, a);
         self.context
-            .uniform_matrix4fv_with_f32_array(self.mvp.as_ref(), false, (pv * scale).as_slice());
+            .clear(WebGlRenderingContext::COLOR_BUFFER_BIT | WebGlRenderingContext::DEPTH_BUFFER_BIT);
+
+        // 視点座標
+        let (eye, view, projection) = {
+            let camera = self.camera.borrow();
+            (camera.eye(), camera.view_matrix(), camera.projection_matrix())
+        };
+        let pv = projection * view;
+
+        let reflection = self.probe.as_ref().map(|p| &**p.texture());
+        for (id, renderable, world) in self.graph.renderables() {
+            let reflection = reflection.filter(|_| id == self.teapot);
+            self.draw(renderable, world, &eye, &pv, reflection)?;
+        }
+
+        // 背景は最後に一番奥に描く
+        self.skybox
+            .render(&view, &projection, self.cube_texture.as_deref())?;
+
+        self.context.flush();
+
         self.context
-            .uniform3fv_with_f32_array(self.eye.as_ref(), eye.as_slice());
+            .bind_buffer(WebGlRenderingContext::ARRAY_BUFFER, None);
+
+        Ok(())
+    }
+
+    // 影を落とす最初の平行光源か spot から、影を落とす node の深度を描く
+    fn render_shadow(&mut self) -> Result<(), String> {
+        let shadow = match self.shadow.as_mut() {
+            Some(shadow) => shadow,
+            None => return Ok(()),
+        };
+
+        let light = self
+            .lights
+            .iter()
+            .take(light::MAX_LIGHTS)
+            .enumerate()
+            .find(|(_, l)| l.cast_shadow && l.kind != LightKind::Point);
+
+        // 影を落とす物全体の外接球に shadow map を合わせる
+        let meshes = &self.meshes;
+        let casters: Vec<(&Mesh<C>, &nalgebra_glm::Mat4)> = self
+            .graph
+            .renderables()
+            .filter(|(_, r, _)| r.cast_shadow)
+            .filter_map(|(_, r, world)| Some((meshes.get(r.mesh.0)?, world)))
+            .collect();
+        let bounds = casters
+            .iter()
+            .map(|(mesh, world)| Self::world_bounds(&mesh.bounds(), world))
+            .reduce(Self::merge_bounds);
+
+        let matrix = match (light, bounds) {
+            (Some((index, l)), Some((center, radius))) => {
+                ShadowMap::<C>::light_matrix(l, &center, radius).map(|m| (index, m))
+            }
+            _ => None,
+        };
+        let (index, matrix) = match matrix {
+            Some(matrix) => matrix,
+            None => {
+                shadow.clear();
+                return Ok(());
+            }
+        };
+
+        shadow.begin(index, matrix)?;
+        for (mesh, world) in casters {
+            shadow.draw(&(matrix * world), mesh)?;
+        }
+        shadow.end();
+
+        let (width, height) = self.viewport;
+        self.context.viewport(0, 0, width, height);
+
+        Ok(())
+    }
+
+    // teapot の中心から周りを 6 面に描く
+    fn render_probe(&mut self) -> Result<(), String> {
+        let probe = match self.probe.as_ref() {
+            Some(probe) if probe.needs_update() => probe,
+            _ => return Ok(()),
+        };
+
+        let teapot = self.graph.get(self.teapot);
+        let bounds = teapot
+            .and_then(|n| Some((self.meshes.get(n.renderable.as_ref()?.mesh.0)?, n.world_matrix())))
+            .map(|(mesh, world)| Self::world_bounds(&mesh.bounds(), world));
+        let (center, radius) = match bounds {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+
+        // teapot 自身は外接球の内側なので near で切れるが、念のため描かない
+        let far = self.camera.borrow().far;
+        let [r, g, b, a] = self.clear_color;
+
+        for face in 0..6 {
+            let (view, projection) = probe.begin_face(face, &center, radius.max(0.01), far);
+            self.context.clear_color(r, g, b, a);
+            self.context
+                .clear(WebGlRenderingContext::COLOR_BUFFER_BIT | WebGlRenderingContext::DEPTH_BUFFER_BIT);
+
+            let pv = projection * view;
+            for (id, renderable, world) in self.graph.renderables() {
+                if id != self.teapot {
+                    self.draw(renderable, world, &center, &pv, None)?;
+                }
+            }
+
+            self.skybox
+                .render(&view, &projection, self.cube_texture.as_deref())?;
+        }
+
+        if let Some(probe) = self.probe.as_mut() {
+            probe.end();
+        }
+
+        let (width, height) = self.viewport;
+        self.context.viewport(0, 0, width, height);
+
+        Ok(())
+    }
+
+    // reflection: 静的な環境マップの代わりに映す cubemap (mipmap 付き)
+    fn draw(
+        &self,
+        renderable: &Renderable,
+        model: &nalgebra_glm::Mat4,
+        eye: &nalgebra_glm::Vec3,
+        pv: &nalgebra_glm::Mat4,
+        reflection: Option<&C::Texture>,
+    ) -> Result<(), String> {
+        let mesh = match self.meshes.get(renderable.mesh.0) {
+            Some(mesh) => mesh,
+            None => return Err(format!("no mesh {:?}", renderable.mesh)),
+        };
+        let material = &renderable.material;
+
+        let (fragment, _) = self.shading(material);
+        let technique = self
+            .techniques
+            .get(fragment)
+            .ok_or_else(|| format!("no technique for {}", fragment))?;
+
+        let program = &technique.program;
+        program.bind();
+
+        program.set_vec3("eyePosition", eye.as_slice())?;
+        technique.lights
+            .apply(&self.context, &self.lights);
+        technique.material
+            .apply(&self.context, material);
+        let specular = match (reflection, &self.probe) {
+            (Some(reflection), Some(probe)) => Some((reflection, probe.size())),
+            _ => self.environment.as_ref().map(|e| (&*e.specular, ibl::SPECULAR_SIZE)),
+        };
+        technique.ibl
+            .apply(&self.context, specular, self.environment.as_ref().map(|e| &e.irradiance), self.brdf_lut.as_deref());
+        technique.shadow
+            .apply(&self.context, self.shadow.as_ref());
 
         self.context
             .active_texture(WebGlRenderingContext::TEXTURE0);
         self.context
-            .bind_texture(WebGlRenderingContext::TEXTURE_CUBE_MAP, self.cube_texture.as_ref());
-        self.context
-            .uniform1i(self.cube.as_ref(), 0);
-        /*
-        self.context
-            .uniform1i(self.reflection.as_ref(), 0);
-        */
-        self.context.draw_elements_with_i32(
-            WebGlRenderingContext::TRIANGLES,
-            cube::INDEX.len() as i32,
-            WebGlRenderingContext::UNSIGNED_SHORT,
-            0,
-        );
-
-        // teapot
-        let mut color = Vec::default();
-        for _ in 0..(teapot::VERTEX.len() / 3) {
-            color.push(1.0);
-            color.push(1.0);
-            color.push(1.0);
-            color.push(1.0);
+            .bind_texture(WebGlRenderingContext::TEXTURE_CUBE_MAP, reflection.or(self.cube_texture.as_deref()));
+        // PBR は cubeTexture の代わりに uSpecularMap を引く
+        if program.has_uniform("cubeTexture") {
+            program.set_sampler("cubeTexture", 0)?;
         }
-        buffer::render_buffer(
-            self.context, 
-            self.teapot_vertex.as_ref(), 
-            self.position, 
-            3
-        )?;
-        buffer::render_buffer(
-            self.context, 
-            self.teapot_normal.as_ref(), 
-            self.normal, 
-            3
-        )?;
-        buffer::render_buffer(
-            self.context, 
-            buffer::vertex_buffer(self.context, color.as_slice()).ok().as_ref(), 
-            self.color, 
-            4
-        )?;
-        self.context
-            .bind_buffer(
-                WebGlRenderingContext::ELEMENT_ARRAY_BUFFER, 
-                self.teapot_index.as_ref()
-            );
 
-        let translate = 
-            nalgebra_glm::translate(&nalgebra_glm::identity(), &nalgebra_glm::vec3(0.0, 0.0, 50.0));
-        let rotate = 
-            nalgebra_glm::rotate(&translate, 0.785398, &nalgebra_glm::vec3(-50.0, 0.0, 50.0));
-        self.context
-            .uniform_matrix4fv_with_f32_array(self.m.as_ref(), false, rotate.as_slice());
-        self.context
-            .uniform_matrix4fv_with_f32_array(self.mvp.as_ref(), false, (pv * rotate).as_slice());
-        /*
-        self.context
-            .uniform1i(self.reflection.as_ref(), 1);
-        */
-        self.context.draw_elements_with_i32(
-            WebGlRenderingContext::TRIANGLES,
-            teapot::INDEX.len() as i32,
-            WebGlRenderingContext::UNSIGNED_SHORT,
-            0,
-        );
+        // mesh に頂点色が無い時は白を入れる
+        if let Some(color) = program.attribute("aColor") {
+            self.context
+                .vertex_attrib4f(color.location, 1.0, 1.0, 1.0, 1.0);
+        }
+        let _binding = mesh.bind(program);
 
-        self.context.flush();
+        program.set_mat4("uModelMatrix", model.as_slice())?;
+        program.set_mat4("uMVPMatrix", (pv * model).as_slice())?;
+        mesh.draw();
 
-        self.context
-            .bind_buffer(WebGlRenderingContext::ARRAY_BUFFER, None);
+        Ok(())
+    }
+
+    // 材質の fragment shader と variant の #define
+    fn shading(&self, material: &Material) -> (&'static str, &'static [(&'static str, &'static str)]) {
+        match material {
+            Material::BlinnPhong(_) => ("blinn_phong.frag", &[]),
+            Material::Pbr(_) if self.texture_lod => ("pbr.frag", &[("TEXTURE_LOD", "")]),
+            Material::Pbr(_) if self.derivatives => ("pbr.frag", &[("STANDARD_DERIVATIVES", "")]),
+            Material::Pbr(_) => ("pbr.frag", &[]),
+            Material::Glass(_) => ("glass.frag", &[]),
+        }
+    }
+
+    // graph で使われている材質の program を作る。作った物は ShaderCache が持ち続ける
+    fn prepare_techniques(&mut self) -> Result<(), String> {
+        let shadings: Vec<_> = self
+            .graph
+            .renderables()
+            .map(|(_, renderable, _)| self.shading(&renderable.material))
+            .collect();
+
+        for (fragment, defines) in shadings {
+            if self.techniques.contains_key(fragment) {
+                continue;
+            }
+            let program = self.shaders.program("standard.vert", fragment, defines)?;
+            self.techniques.insert(fragment, Technique::new(program));
+        }
 
         Ok(())
     }
 
-    pub fn create_texture(context: &'a WebGlRenderingContext) -> Result<WebGlTexture, JsValue> {
-        let source = std::include_bytes!("check.png");
-
-        let targets = [
-            WebGlRenderingContext::TEXTURE_CUBE_MAP_POSITIVE_X,
-            WebGlRenderingContext::TEXTURE_CUBE_MAP_POSITIVE_Y,
-            WebGlRenderingContext::TEXTURE_CUBE_MAP_POSITIVE_Z,
-            WebGlRenderingContext::TEXTURE_CUBE_MAP_NEGATIVE_X,
-            WebGlRenderingContext::TEXTURE_CUBE_MAP_NEGATIVE_Y,
-            WebGlRenderingContext::TEXTURE_CUBE_MAP_NEGATIVE_Z
-        ];
-
-        let tex = context
-            .create_texture()
-            .ok_or("failed create texture")?;
-
-        context
-            .bind_texture(WebGlRenderingContext::TEXTURE_CUBE_MAP, Some(&tex));
-       
-        for target in targets.iter() {
-            let img = image::load_from_memory_with_format(
-                source,
-                image::ImageFormat::Png
-            ).map_err(|e| e.to_string())?;
-            
-            context.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
-                    *target, 
-                    0, 
-                    WebGlRenderingContext::RGBA as i32, 
-                    256,
-                    256,
-                    0,
-                    WebGlRenderingContext::RGBA,
-                    WebGlRenderingContext::UNSIGNED_BYTE,
-                    Some(&img.into_rgba8().into_vec())
-                )?;
+    // モデル座標の外接球を world 座標に移す。拡大は一番大きい軸に合わせる
+    fn world_bounds(
+        (center, radius): &(nalgebra_glm::Vec3, f32),
+        model: &nalgebra_glm::Mat4,
+    ) -> (nalgebra_glm::Vec3, f32) {
+        let scale = (0..3)
+            .map(|i| nalgebra_glm::vec3(model[(0, i)], model[(1, i)], model[(2, i)]).norm())
+            .fold(0.0, f32::max);
+
+        ((model * center.push(1.0)).xyz(), radius * scale)
+    }
+
+    // 二つの球を含む球
+    fn merge_bounds(a: (nalgebra_glm::Vec3, f32), b: (nalgebra_glm::Vec3, f32)) -> (nalgebra_glm::Vec3, f32) {
+        let (small, large) = if a.1 < b.1 { (a, b) } else { (b, a) };
+        let offset = small.0 - large.0;
+        let distance = offset.norm();
+        if distance + small.1 <= large.1 {
+            return large;
         }
 
-        context
-            .generate_mipmap(WebGlRenderingContext::TEXTURE_CUBE_MAP);
-
-        context
-            .tex_parameteri(
-                WebGlRenderingContext::TEXTURE_CUBE_MAP,
-                WebGlRenderingContext::TEXTURE_MIN_FILTER,
-                WebGlRenderingContext::LINEAR as i32,
-            );
-        
-        context
-            .tex_parameteri(
-                WebGlRenderingContext::TEXTURE_CUBE_MAP,
-                WebGlRenderingContext::TEXTURE_MAG_FILTER,
-                WebGlRenderingContext::LINEAR as i32,
-            );
-        
-        context
-            .tex_parameteri(
-                WebGlRenderingContext::TEXTURE_CUBE_MAP,
-                WebGlRenderingContext::TEXTURE_WRAP_S,
-                WebGlRenderingContext::CLAMP_TO_EDGE as i32,
-            );
-        
-        context
-            .tex_parameteri(
-                WebGlRenderingContext::TEXTURE_CUBE_MAP,
-                WebGlRenderingContext::TEXTURE_WRAP_T,
-                WebGlRenderingContext::CLAMP_TO_EDGE as i32,
-            );
-
-        context
-            .bind_texture(WebGlRenderingContext::TEXTURE_CUBE_MAP, None);
-       
-        Ok(tex)
+        let radius = (distance + small.1 + large.1) * 0.5;
+        let center = large.0 + offset * ((radius - large.1) / distance);
+
+        (center, radius)
+    }
+
+    fn weld(name: &str, geometry: &Geometry) -> Geometry {
+        let welded = weld::weld(geometry);
+        log::log(&format!("{}: {} -> {} vertices", name, welded.before, welded.after));
+
+        welded.geometry
     }
 }
@@ -1,102 +1,538 @@
-use wasm_bindgen::prelude::*;
-use web_sys::{WebGlProgram, WebGlRenderingContext, WebGlShader};
-
-pub fn vertex_shader(context: &WebGlRenderingContext) -> Result<WebGlShader, JsValue> {
-    let vert_shader = compile_shader(
-        &context,
-        WebGlRenderingContext::VERTEX_SHADER,
-        r#"
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use web_sys::WebGlRenderingContext;
+
+use crate::backend::Backend;
+use crate::diagnostic::ShaderError;
+use crate::light;
+use crate::preprocessor::Preprocessor;
+use crate::program::Program;
+use crate::shadow;
+
+// drop で delete_shader する shader (program に attach 済みなら削除は link 解除まで遅延される)
+pub struct Shader<C: Backend = WebGlRenderingContext> {
+    context: C,
+    shader: C::Shader,
+}
+
+impl<C: Backend> Deref for Shader<C> {
+    type Target = C::Shader;
+
+    fn deref(&self) -> &C::Shader {
+        &self.shader
+    }
+}
+
+impl<C: Backend> Drop for Shader<C> {
+    fn drop(&mut self) {
+        self.context.delete_shader(Some(&self.shader));
+    }
+}
+
+// #include で使う部品。MAX_LIGHTS と MAX_PCF_RADIUS は compile が #define する
+// 光源の uniform と、光源への向きと減衰。light::LightUniforms が値を設定する
+const LIGHTS: &str = r#"
+        uniform int  uLightCount;
+        uniform vec4 uLightPosition[MAX_LIGHTS];
+        uniform vec3 uLightDirection[MAX_LIGHTS];
+        uniform vec3 uLightColor[MAX_LIGHTS];
+        uniform vec3 uLightAttenuation[MAX_LIGHTS];
+        uniform vec2 uLightCone[MAX_LIGHTS];
+
+        // 光源への単位ベクトルを light に入れ、距離と spot の絞りによる減衰を返す
+        // uniform 配列はループの添字でだけ引けば良いように、uLight*[i] を値で受け取る
+        float lightAttenuation(vec4 lightPosition, vec3 lightDirection, vec3 k, vec2 cone, vec3 position, out vec3 light) {
+            light = normalize(-lightDirection);
+            if (lightPosition.w == 0.0) {
+                return 1.0;
+            }
+
+            vec3  toLight  = lightPosition.xyz - position;
+            float distance = length(toLight);
+            light = toLight / distance;
+
+            float attenuation = 1.0 / (k.x + k.y * distance + k.z * distance * distance);
+            return attenuation * smoothstep(cone.y, cone.x, dot(-light, normalize(lightDirection)));
+        }
+"#;
+
+// 影を受ける fragment shader が include する。shadow::ShadowUniforms が値を設定する
+const SHADOW: &str = r#"
+        uniform sampler2D uShadowMap;
+        uniform int       uShadowLight;
+        uniform bool      uShadowPacked;
+        uniform float     uShadowBias;
+        uniform vec2      uShadowTexelSize;
+        uniform int       uShadowRadius;
+
+        varying vec4      vShadowCoord;
+
+        float shadowDepth(vec2 uv) {
+            vec4 texel = texture2D(uShadowMap, uv);
+            return uShadowPacked ? dot(texel, vec4(1.0, 1.0 / 255.0, 1.0 / 65025.0, 1.0 / 16581375.0)) : texel.r;
+        }
+
+        // light 番目の光源が届く割合 (PCF)。影を作っていない光源は 1.0
+        float shadow(int light, float nDotL) {
+            if (light != uShadowLight) {
+                return 1.0;
+            }
+
+            vec3 coord = vShadowCoord.xyz / vShadowCoord.w;
+            if (coord.x < 0.0 || coord.x > 1.0 || coord.y < 0.0 || coord.y > 1.0) {
+                return 1.0;
+            }
+            // far より奥は shadow map に何か描かれていれば影
+            coord.z = min(coord.z, 1.0);
+
+            // 光に対して傾いた面ほど bias を大きくする
+            float bias  = uShadowBias * (2.0 - nDotL);
+            float lit   = 0.0;
+            float count = 0.0;
+            for (int x = -MAX_PCF_RADIUS; x <= MAX_PCF_RADIUS; x++) {
+                for (int y = -MAX_PCF_RADIUS; y <= MAX_PCF_RADIUS; y++) {
+                    if (abs(float(x)) > float(uShadowRadius) || abs(float(y)) > float(uShadowRadius)) {
+                        continue;
+                    }
+
+                    vec2 uv = coord.xy + vec2(float(x), float(y)) * uShadowTexelSize;
+                    lit   += coord.z - bias > shadowDepth(uv) ? 0.0 : 1.0;
+                    count += 1.0;
+                }
+            }
+
+            return lit / count;
+        }
+"#;
+
+// 材質の shader で共通の頂点 shader
+const STANDARD_VERT: &str = r#"
         attribute vec3 aPosition;
         attribute vec3 aNormal;
         attribute vec4 aColor;
         uniform   mat4 uModelMatrix;
         uniform   mat4 uMVPMatrix;
+        uniform   mat4 uShadowMatrix;
         varying   vec3 vPosition;
         varying   vec3 vNormal;
         varying   vec4 vColor;
-        
+        varying   vec4 vShadowCoord;
+
         void main(void){
-            vPosition   = (uModelMatrix * vec4(aPosition, 1.0)).xyz;
-            vNormal     = (uModelMatrix * vec4(aNormal, 0.0)).xyz;
-            vColor      = aColor;
-            gl_Position = uMVPMatrix * vec4(aPosition, 1.0);
+            vPosition    = (uModelMatrix * vec4(aPosition, 1.0)).xyz;
+            vNormal      = (uModelMatrix * vec4(aNormal, 0.0)).xyz;
+            vColor       = aColor;
+            vShadowCoord = uShadowMatrix * vec4(vPosition, 1.0);
+            gl_Position  = uMVPMatrix * vec4(aPosition, 1.0);
         }
-        "#,
-    )?;
+"#;
 
-    Ok(vert_shader)
-}
+const BLINN_PHONG_FRAG: &str = r#"
+        precision mediump float;
+
+        #include "lights.glsl"
+        #include "shadow.glsl"
 
-pub fn fragment_shader(context: &WebGlRenderingContext) -> Result<WebGlShader, JsValue> {
-    let frag_shader = compile_shader(
-        &context,
-        WebGlRenderingContext::FRAGMENT_SHADER,
-        r#"
+        uniform vec3        eyePosition;
+        uniform samplerCube cubeTexture;
+
+        uniform vec3        uAmbient;
+        uniform vec3        uDiffuse;
+        uniform vec3        uSpecular;
+        uniform float       uShininess;
+        uniform float       uReflectivity;
+
+        varying vec3        vPosition;
+        varying vec3        vNormal;
+        varying vec4        vColor;
+
+        void main(void){
+            vec3 normal    = normalize(vNormal);
+            vec3 view      = normalize(eyePosition - vPosition);
+
+            vec3 diffuse   = uAmbient;
+            vec3 specular  = vec3(0.0);
+            for (int i = 0; i < MAX_LIGHTS; i++) {
+                if (i >= uLightCount) {
+                    break;
+                }
+
+                vec3  light;
+                float attenuation = lightAttenuation(
+                    uLightPosition[i], uLightDirection[i], uLightAttenuation[i], uLightCone[i], vPosition, light);
+
+                float lambert  = max(dot(normal, light), 0.0);
+                attenuation *= shadow(i, lambert);
+                vec3  halfway  = normalize(light + view);
+                float highlight = lambert > 0.0 ? pow(max(dot(normal, halfway), 0.0), uShininess) : 0.0;
+
+                diffuse  += uLightColor[i] * attenuation * lambert * uDiffuse;
+                specular += uLightColor[i] * attenuation * highlight * uSpecular;
+            }
+
+            vec3 ref       = reflect(-view, normal);
+            vec3 envColor  = textureCube(cubeTexture, ref).rgb;
+            vec3 destColor = mix(vColor.rgb * diffuse, vColor.rgb * envColor, uReflectivity) + specular;
+            gl_FragColor   = vec4(destColor, vColor.a);
+        }
+"#;
+
+const GLASS_FRAG: &str = r#"
         precision mediump float;
 
         uniform vec3        eyePosition;
         uniform samplerCube cubeTexture;
+
+        uniform float       uIor;
+        uniform float       uDispersion;
+        uniform vec3        uTint;
+
         varying vec3        vPosition;
         varying vec3        vNormal;
         varying vec4        vColor;
-        
+
+        // 全反射で refract が 0 を返す時は反射方向を使う
+        vec3 refraction(vec3 incident, vec3 normal, float ior) {
+            vec3 direction = refract(incident, normal, 1.0 / ior);
+            return dot(direction, direction) > 0.0 ? direction : reflect(incident, normal);
+        }
+
         void main(void){
-            vec3 direction = vPosition - eyePosition;
-            vec3 ref       = reflect(direction, vNormal);
-            vec4 envColor  = textureCube(cubeTexture, ref);
-            vec4 destColor = vColor * envColor;
-            gl_FragColor   = destColor;
+            vec3 normal   = normalize(vNormal);
+            vec3 incident = normalize(vPosition - eyePosition);
+
+            vec3 reflected = textureCube(cubeTexture, reflect(incident, normal)).rgb;
+            vec3 refracted = vec3(
+                textureCube(cubeTexture, refraction(incident, normal, uIor - uDispersion)).r,
+                textureCube(cubeTexture, refraction(incident, normal, uIor)).g,
+                textureCube(cubeTexture, refraction(incident, normal, uIor + uDispersion)).b
+            );
+
+            // Schlick 近似
+            float f0      = pow((uIor - 1.0) / (uIor + 1.0), 2.0);
+            float fresnel = f0 + (1.0 - f0) * pow(1.0 - max(dot(-incident, normal), 0.0), 5.0);
+
+            vec3 destColor = mix(refracted * uTint, reflected, fresnel) * vColor.rgb;
+            gl_FragColor   = vec4(destColor, vColor.a);
+        }
+"#;
+
+// TEXTURE_LOD: EXT_shader_texture_lod が使える時は mip level を直接指定する
+// STANDARD_DERIVATIVES: 無ければ OES_standard_derivatives で暗黙の level を見積もり、bias で打ち消す
+const PBR_FRAG: &str = r#"
+        #ifdef TEXTURE_LOD
+        #extension GL_EXT_shader_texture_lod : enable
+        #elif defined(STANDARD_DERIVATIVES)
+        #extension GL_OES_standard_derivatives : enable
+        #endif
+
+        precision mediump float;
+
+        #define PI 3.14159265
+
+        #include "lights.glsl"
+        #include "shadow.glsl"
+
+        uniform vec3        eyePosition;
+
+        uniform samplerCube uSpecularMap;
+        uniform float       uSpecularMaxLod;
+        uniform float       uSpecularSize;
+        uniform vec3        uIrradiance[9];
+        uniform sampler2D   uBrdfLut;
+
+        uniform vec3        uBaseColor;
+        uniform float       uMetallic;
+        uniform float       uRoughness;
+        uniform float       uAmbientOcclusion;
+
+        varying vec3        vPosition;
+        varying vec3        vNormal;
+        varying vec4        vColor;
+
+        // ibl::sh_basis と同じ順の球面調和関数
+        vec3 irradiance(vec3 n) {
+            return uIrradiance[0] * 0.282095
+                 + uIrradiance[1] * 0.488603 * n.y
+                 + uIrradiance[2] * 0.488603 * n.z
+                 + uIrradiance[3] * 0.488603 * n.x
+                 + uIrradiance[4] * 1.092548 * n.x * n.y
+                 + uIrradiance[5] * 1.092548 * n.y * n.z
+                 + uIrradiance[6] * 0.315392 * (3.0 * n.z * n.z - 1.0)
+                 + uIrradiance[7] * 1.092548 * n.x * n.z
+                 + uIrradiance[8] * 0.546274 * (n.x * n.x - n.y * n.y);
+        }
+
+        vec3 prefiltered(vec3 direction, float roughness) {
+            float lod = roughness * uSpecularMaxLod;
+        #ifdef TEXTURE_LOD
+            vec3 color = textureCubeLodEXT(uSpecularMap, direction, lod).rgb;
+        #elif defined(STANDARD_DERIVATIVES)
+            // 面の上の座標 ([-1, 1]) が 1 画素で動く texel 数の log2 が暗黙の level
+            vec3 face = direction / max(max(abs(direction.x), abs(direction.y)), abs(direction.z));
+            float texels = max(length(dFdx(face)), length(dFdy(face))) * 0.5 * uSpecularSize;
+            vec3 color = textureCube(uSpecularMap, direction, lod - log2(max(texels, 1e-4))).rgb;
+        #else
+            // bias しか渡せないので、拡大か等倍で引く所 (暗黙の level が 0) でだけ lod に一致する
+            // 縮小の強い所では lod より粗い level になる
+            vec3 color = textureCube(uSpecularMap, direction, lod).rgb;
+        #endif
+            return pow(color, vec3(2.2));
+        }
+
+        float distributionGGX(float nDotH, float roughness) {
+            float a2 = roughness * roughness * roughness * roughness;
+            float d  = nDotH * nDotH * (a2 - 1.0) + 1.0;
+            return a2 / (PI * d * d);
         }
-        "#,
-    )?;
 
-    Ok(frag_shader)
+        // 直接光では k = (roughness + 1)^2 / 8
+        float geometrySmith(float nDotV, float nDotL, float roughness) {
+            float r = roughness + 1.0;
+            float k = r * r / 8.0;
+            return nDotV / (nDotV * (1.0 - k) + k) * nDotL / (nDotL * (1.0 - k) + k);
+        }
+
+        vec3 fresnelSchlick(float cosTheta, vec3 f0, float roughness) {
+            return f0 + (max(vec3(1.0 - roughness), f0) - f0) * pow(1.0 - cosTheta, 5.0);
+        }
+
+        void main(void){
+            vec3  albedo = uBaseColor * vColor.rgb;
+            vec3  normal = normalize(vNormal);
+            vec3  view   = normalize(eyePosition - vPosition);
+            float nDotV  = max(dot(normal, view), 1e-4);
+            vec3  f0     = mix(vec3(0.04), albedo, uMetallic);
+
+            vec3 direct = vec3(0.0);
+            for (int i = 0; i < MAX_LIGHTS; i++) {
+                if (i >= uLightCount) {
+                    break;
+                }
+
+                vec3  light;
+                float attenuation = lightAttenuation(
+                    uLightPosition[i], uLightDirection[i], uLightAttenuation[i], uLightCone[i], vPosition, light);
+
+                float nDotL   = max(dot(normal, light), 0.0);
+                attenuation *= shadow(i, nDotL);
+                vec3  halfway = normalize(light + view);
+                vec3  f       = fresnelSchlick(max(dot(halfway, view), 0.0), f0, 0.0);
+                vec3  spec    = f * distributionGGX(max(dot(normal, halfway), 0.0), uRoughness)
+                              * geometrySmith(nDotV, nDotL, uRoughness) / (4.0 * nDotV * nDotL + 1e-4);
+                vec3  kd      = (1.0 - f) * (1.0 - uMetallic);
+
+                direct += (kd * albedo / PI + spec) * uLightColor[i] * attenuation * nDotL;
+            }
+
+            // split-sum 近似
+            vec3 f        = fresnelSchlick(nDotV, f0, uRoughness);
+            vec3 kd       = (1.0 - f) * (1.0 - uMetallic);
+            vec2 brdf     = texture2D(uBrdfLut, vec2(nDotV, uRoughness)).rg;
+            vec3 diffuse  = irradiance(normal) * albedo;
+            vec3 specular = prefiltered(reflect(-view, normal), uRoughness) * (f * brdf.x + brdf.y);
+
+            vec3 color = (kd * diffuse + specular) * uAmbientOcclusion + direct;
+
+            // Reinhard でトーンマップしてから gamma 補正する
+            color        = pow(color / (color + 1.0), vec3(1.0 / 2.2));
+            gl_FragColor = vec4(color, vColor.a);
+        }
+"#;
+
+const DEPTH_VERT: &str = r#"
+        attribute vec3 aPosition;
+        uniform   mat4 uMVPMatrix;
+
+        void main(void){
+            gl_Position = uMVPMatrix * vec4(aPosition, 1.0);
+        }
+"#;
+
+// WEBGL_depth_texture が無い時は深度を RGBA に詰めて書く
+const DEPTH_FRAG: &str = r#"
+        precision mediump float;
+
+        uniform bool uPackDepth;
+
+        vec4 packDepth(float depth) {
+            vec4 enc = fract(depth * vec4(1.0, 255.0, 65025.0, 16581375.0));
+            return enc - enc.yzww * vec4(1.0 / 255.0, 1.0 / 255.0, 1.0 / 255.0, 0.0);
+        }
+
+        void main(void){
+            gl_FragColor = uPackDepth ? packDepth(gl_FragCoord.z) : vec4(1.0);
+        }
+"#;
+
+const SKYBOX_VERT: &str = r#"
+        attribute vec3 aPosition;
+        uniform   mat4 uViewProjectionMatrix;
+        varying   vec3 vDirection;
+
+        void main(void){
+            vDirection  = aPosition;
+            vec4 position = uViewProjectionMatrix * vec4(aPosition, 1.0);
+            gl_Position = position.xyww;
+        }
+"#;
+
+const SKYBOX_FRAG: &str = r#"
+        precision mediump float;
+
+        uniform samplerCube cubeTexture;
+        varying vec3        vDirection;
+
+        void main(void){
+            gl_FragColor = textureCube(cubeTexture, vDirection);
+        }
+"#;
+
+// 名前で引ける shader と部品。".vert" は頂点 shader、".frag" は fragment shader として compile できる
+pub fn library() -> Preprocessor {
+    let mut library = Preprocessor::new();
+    for (name, source) in [
+        ("lights.glsl", LIGHTS),
+        ("shadow.glsl", SHADOW),
+        ("standard.vert", STANDARD_VERT),
+        ("blinn_phong.frag", BLINN_PHONG_FRAG),
+        ("glass.frag", GLASS_FRAG),
+        ("pbr.frag", PBR_FRAG),
+        ("depth.vert", DEPTH_VERT),
+        ("depth.frag", DEPTH_FRAG),
+        ("skybox.vert", SKYBOX_VERT),
+        ("skybox.frag", SKYBOX_FRAG),
+    ] {
+        library.add(name, source);
+    }
+
+    library
 }
 
-pub fn create_program(
-    context: &WebGlRenderingContext,
-    vert_shader: &WebGlShader,
-    frag_shader: &WebGlShader,
-) -> Result<WebGlProgram, String> {
-    let program = context
-        .create_program()
-        .ok_or_else(|| String::from("create program error"))?;
-
-    context.attach_shader(&program, vert_shader);
-    context.attach_shader(&program, frag_shader);
-    context.link_program(&program);
-
-    let check = context
-        .get_program_parameter(&program, WebGlRenderingContext::LINK_STATUS)
-        .as_bool()
-        .unwrap_or(false);
-
-    if check {
-        Ok(program)
+// library の name を展開して compile する。Rust 側の定数に合わせた MAX_LIGHTS と MAX_PCF_RADIUS も #define する
+// 失敗したらエラーの行を元の chunk の位置と前後の行付きで console に出す
+pub fn compile<C: Backend>(context: &C, name: &str, defines: &[(&str, &str)]) -> Result<Shader<C>, ShaderError> {
+    let shader_type = if name.ends_with(".vert") {
+        WebGlRenderingContext::VERTEX_SHADER
+    } else if name.ends_with(".frag") {
+        WebGlRenderingContext::FRAGMENT_SHADER
     } else {
-        Err(context
-            .get_program_info_log(&program)
-            .unwrap_or_else(|| String::from("create program error")))
+        return Err(ShaderError::preprocess(name, format!("{} is neither .vert nor .frag", name)));
+    };
+
+    let max_lights = light::MAX_LIGHTS.to_string();
+    let max_pcf_radius = shadow::MAX_PCF_RADIUS.to_string();
+    let mut all = vec![("MAX_LIGHTS", max_lights.as_str()), ("MAX_PCF_RADIUS", max_pcf_radius.as_str())];
+    all.extend_from_slice(defines);
+
+    let result = library()
+        .process(name, &all)
+        .map_err(|e| ShaderError::preprocess(name, e))
+        .and_then(|source| {
+            compile_shader(context, shader_type, &source.source)
+                .map_err(|log| ShaderError::compile(name, &log, &source))
+        })
+        .inspect(|shader| context.label_shader(shader, name));
+    if let Err(error) = &result {
+        error.report();
     }
+
+    result
 }
 
-pub fn compile_shader(
-    context: &WebGlRenderingContext,
+pub fn build_program<C: Backend>(
+    context: &C,
+    vertex: &str,
+    fragment: &str,
+    defines: &[(&str, &str)],
+) -> Result<Program<C>, ShaderError> {
+    let vert_shader = compile(context, vertex, defines)?;
+    let frag_shader = compile(context, fragment, defines)?;
+
+    create_program(context, &vert_shader, &frag_shader).map_err(|log| {
+        let error = ShaderError::link(&format!("{} + {}", vertex, fragment), &log);
+        error.report();
+        error
+    })
+}
+
+// 頂点 shader、fragment shader と並べ替えた #define の組
+type Variant = (String, String, Vec<(String, String)>);
+
+// variant ごとに link した program を使い回す
+pub struct ShaderCache<C: Backend = WebGlRenderingContext> {
+    context: C,
+    programs: HashMap<Variant, Rc<Program<C>>>,
+}
+
+impl<C: Backend> ShaderCache<C> {
+    pub fn new(context: &C) -> ShaderCache<C> {
+        ShaderCache {
+            context: context.clone(),
+            programs: HashMap::new(),
+        }
+    }
+
+    // 無ければ作る。defines の順番は variant の区別に関係しない
+    pub fn program(&mut self, vertex: &str, fragment: &str, defines: &[(&str, &str)]) -> Result<Rc<Program<C>>, ShaderError> {
+        let mut flags: Vec<(String, String)> = defines
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        flags.sort();
+        flags.dedup();
+        let key = (vertex.to_string(), fragment.to_string(), flags);
+
+        if let Some(program) = self.programs.get(&key) {
+            return Ok(program.clone());
+        }
+
+        let program = Rc::new(build_program(&self.context, vertex, fragment, defines)?);
+        self.programs.insert(key, program.clone());
+
+        Ok(program)
+    }
+
+    pub fn len(&self) -> usize {
+        self.programs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.programs.is_empty()
+    }
+
+    // 使っている所が Rc を持っている間は program は消えない
+    pub fn clear(&mut self) {
+        self.programs.clear();
+    }
+}
+
+pub fn create_program<C: Backend>(
+    context: &C,
+    vert_shader: &Shader<C>,
+    frag_shader: &Shader<C>,
+) -> Result<Program<C>, String> {
+    Program::link(context, vert_shader, frag_shader)
+}
+
+pub fn compile_shader<C: Backend>(
+    context: &C,
     shader_type: u32,
     source: &str,
-) -> Result<WebGlShader, String> {
+) -> Result<Shader<C>, String> {
     let shader = context
         .create_shader(shader_type)
         .ok_or_else(|| String::from("create shader error"))?;
+    let shader = Shader {
+        context: context.clone(),
+        shader,
+    };
     context.shader_source(&shader, source);
     context.compile_shader(&shader);
 
-    let check = context
-        .get_shader_parameter(&shader, WebGlRenderingContext::COMPILE_STATUS)
-        .as_bool()
-        .unwrap_or(false);
-
-    if check {
+    if context.shader_compile_status(&shader) {
         Ok(shader)
     } else {
         Err(context
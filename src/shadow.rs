@@ -0,0 +1,282 @@
+use nalgebra_glm::{Mat4, Vec3};
+use web_sys::WebGlRenderingContext;
+
+use crate::backend::Backend;
+use crate::framebuffer::{Framebuffer, Renderbuffer};
+use crate::light::{Light, LightKind};
+use crate::mesh::Mesh;
+use crate::program::Program;
+use crate::shader;
+use crate::texture::Texture;
+
+// shader::SHADOW のループの範囲。shader::compile が GLSL の MAX_PCF_RADIUS として #define する
+pub const MAX_PCF_RADIUS: i32 = 2;
+
+#[derive(Clone, Debug)]
+pub struct ShadowOptions {
+    // shadow map の一辺の画素数
+    pub size: i32,
+    // 深度の比較で足す値 (0..1 の深度)。大きいと影が浮き、小さいと縞模様が出る
+    pub bias: f32,
+    // PCF の半径 (texel)。0 で 1 点、1 で 3x3、2 で 5x5
+    pub pcf_radius: i32,
+}
+
+impl Default for ShadowOptions {
+    fn default() -> Self {
+        ShadowOptions {
+            size: 1024,
+            bias: 0.002,
+            pcf_radius: 1,
+        }
+    }
+}
+
+// 一つの光源から見た深度を描く先
+pub struct ShadowMap<C: Backend = WebGlRenderingContext> {
+    context: C,
+    options: ShadowOptions,
+
+    framebuffer: Framebuffer<C>,
+    texture: Texture<C>,
+    _renderbuffer: Renderbuffer<C>,
+    // true なら texture は RGBA に詰めた深度
+    packed: bool,
+
+    program: Program<C>,
+
+    // 直前の begin で使った光源の番号と、world 座標から shadow map の (u, v, depth) への行列
+    light: Option<usize>,
+    matrix: Mat4,
+}
+
+impl<C: Backend> ShadowMap<C> {
+    pub fn new(context: &C, options: ShadowOptions) -> Result<ShadowMap<C>, String> {
+        let program = shader::build_program(context, "depth.vert", "depth.frag", &[])?;
+
+        let size = options.size.max(1);
+        let packed = !context.enable_extension("WEBGL_depth_texture");
+
+        let framebuffer = Framebuffer::new(context)?;
+        let texture = Texture::new(context)?;
+        context.bind_framebuffer(WebGlRenderingContext::FRAMEBUFFER, Some(&framebuffer));
+        context.bind_texture(WebGlRenderingContext::TEXTURE_2D, Some(&texture));
+
+        // 深度テクスチャは LINEAR で引けない実装があるので NEAREST にする
+        let (format, kind) = if packed {
+            (WebGlRenderingContext::RGBA, WebGlRenderingContext::UNSIGNED_BYTE)
+        } else {
+            (WebGlRenderingContext::DEPTH_COMPONENT, WebGlRenderingContext::UNSIGNED_INT)
+        };
+        context.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+            WebGlRenderingContext::TEXTURE_2D,
+            0,
+            format as i32,
+            size,
+            size,
+            0,
+            format,
+            kind,
+            None,
+        )?;
+        for (name, value) in [
+            (WebGlRenderingContext::TEXTURE_MIN_FILTER, WebGlRenderingContext::NEAREST),
+            (WebGlRenderingContext::TEXTURE_MAG_FILTER, WebGlRenderingContext::NEAREST),
+            (WebGlRenderingContext::TEXTURE_WRAP_S, WebGlRenderingContext::CLAMP_TO_EDGE),
+            (WebGlRenderingContext::TEXTURE_WRAP_T, WebGlRenderingContext::CLAMP_TO_EDGE),
+        ] {
+            context.tex_parameteri(WebGlRenderingContext::TEXTURE_2D, name, value as i32);
+        }
+
+        // 深度テクスチャだけの framebuffer は complete にならない実装があるので色も付ける
+        let renderbuffer = if packed {
+            context.framebuffer_texture_2d(
+                WebGlRenderingContext::FRAMEBUFFER,
+                WebGlRenderingContext::COLOR_ATTACHMENT0,
+                WebGlRenderingContext::TEXTURE_2D,
+                Some(&texture),
+                0,
+            );
+            let depth = Renderbuffer::new(context, WebGlRenderingContext::DEPTH_COMPONENT16, size, size)?;
+            context.framebuffer_renderbuffer(
+                WebGlRenderingContext::FRAMEBUFFER,
+                WebGlRenderingContext::DEPTH_ATTACHMENT,
+                WebGlRenderingContext::RENDERBUFFER,
+                Some(&depth),
+            );
+            depth
+        } else {
+            context.framebuffer_texture_2d(
+                WebGlRenderingContext::FRAMEBUFFER,
+                WebGlRenderingContext::DEPTH_ATTACHMENT,
+                WebGlRenderingContext::TEXTURE_2D,
+                Some(&texture),
+                0,
+            );
+            let color = Renderbuffer::new(context, WebGlRenderingContext::RGBA4, size, size)?;
+            context.framebuffer_renderbuffer(
+                WebGlRenderingContext::FRAMEBUFFER,
+                WebGlRenderingContext::COLOR_ATTACHMENT0,
+                WebGlRenderingContext::RENDERBUFFER,
+                Some(&color),
+            );
+            color
+        };
+
+        let status = framebuffer.check_status();
+        context.bind_texture(WebGlRenderingContext::TEXTURE_2D, None);
+        context.bind_framebuffer(WebGlRenderingContext::FRAMEBUFFER, None);
+        status?;
+
+        Ok(ShadowMap {
+            context: context.clone(),
+            options: ShadowOptions { size, ..options },
+
+            framebuffer,
+            texture,
+            _renderbuffer: renderbuffer,
+            packed,
+
+            program,
+
+            light: None,
+            matrix: Mat4::identity(),
+        })
+    }
+
+    pub fn options(&self) -> &ShadowOptions {
+        &self.options
+    }
+
+    // 大きさが変わらなければ作り直さずに済む
+    pub fn set_options(&mut self, options: ShadowOptions) {
+        self.options = ShadowOptions {
+            size: self.options.size,
+            ..options
+        };
+    }
+
+    // 中心 center、半径 radius の球が収まるように光源から見た view-projection 行列を作る
+    // 点光源は全方向の shadow map が要るので対象外
+    pub fn light_matrix(light: &Light, center: &Vec3, radius: f32) -> Option<Mat4> {
+        let radius = radius.max(f32::EPSILON);
+        let up = |direction: &Vec3| if direction.y.abs() > 0.99 { Vec3::x() } else { Vec3::y() };
+
+        match light.kind {
+            LightKind::Directional => {
+                let eye = center - light.direction * radius * 2.0;
+                let view = nalgebra_glm::look_at(&eye, center, &up(&light.direction));
+                let projection = nalgebra_glm::ortho(-radius, radius, -radius, radius, radius * 0.5, radius * 3.5);
+
+                Some(projection * view)
+            }
+            LightKind::Spot => {
+                let distance = (center - light.position).norm();
+                let near = (distance - radius).max(radius * 0.01);
+                let far = distance + radius;
+                let fov = (light.outer_angle * 2.0).clamp(0.01, std::f32::consts::PI - 0.01);
+
+                let target = light.position + light.direction;
+                let view = nalgebra_glm::look_at(&light.position, &target, &up(&light.direction));
+                let projection = nalgebra_glm::perspective(1.0, fov, near, far);
+
+                Some(projection * view)
+            }
+            LightKind::Point => None,
+        }
+    }
+
+    // light 番目の光源の matrix (light_matrix の戻り値) で描き始める。end まで framebuffer が切り替わる
+    pub fn begin(&mut self, light: usize, matrix: Mat4) -> Result<(), String> {
+        // クリップ座標 -1..1 を 0..1 に
+        let bias = nalgebra_glm::translate(&Mat4::identity(), &Vec3::new(0.5, 0.5, 0.5))
+            * nalgebra_glm::scale(&Mat4::identity(), &Vec3::new(0.5, 0.5, 0.5));
+        self.light = Some(light);
+        self.matrix = bias * matrix;
+
+        self.context
+            .bind_framebuffer(WebGlRenderingContext::FRAMEBUFFER, Some(&self.framebuffer));
+        self.context
+            .viewport(0, 0, self.options.size, self.options.size);
+        // 詰めた深度は白が一番奥
+        self.context.clear_color(1.0, 1.0, 1.0, 1.0);
+        self.context
+            .clear(WebGlRenderingContext::COLOR_BUFFER_BIT | WebGlRenderingContext::DEPTH_BUFFER_BIT);
+
+        self.program.bind();
+        self.program.set_bool("uPackDepth", self.packed)?;
+
+        Ok(())
+    }
+
+    // mvp は light_matrix * model
+    pub fn draw(&self, mvp: &Mat4, mesh: &Mesh<C>) -> Result<(), String> {
+        let _binding = mesh.bind(&self.program);
+        self.program.set_mat4("uMVPMatrix", mvp.as_slice())?;
+
+        mesh.draw();
+
+        Ok(())
+    }
+
+    // 画面に戻す。viewport は呼び出し側で戻す
+    pub fn end(&self) {
+        self.context
+            .bind_framebuffer(WebGlRenderingContext::FRAMEBUFFER, None);
+    }
+
+    // 今のフレームで影を作らない
+    pub fn clear(&mut self) {
+        self.light = None;
+    }
+}
+
+pub struct ShadowUniforms<C: Backend = WebGlRenderingContext> {
+    matrix: Option<C::UniformLocation>,
+    map: Option<C::UniformLocation>,
+    light: Option<C::UniformLocation>,
+    packed: Option<C::UniformLocation>,
+    bias: Option<C::UniformLocation>,
+    texel_size: Option<C::UniformLocation>,
+    radius: Option<C::UniformLocation>,
+}
+
+impl<C: Backend> ShadowUniforms<C> {
+    pub fn new(program: &Program<C>) -> ShadowUniforms<C> {
+        ShadowUniforms {
+            matrix: program.uniform_location("uShadowMatrix"),
+            map: program.uniform_location("uShadowMap"),
+            light: program.uniform_location("uShadowLight"),
+            packed: program.uniform_location("uShadowPacked"),
+            bias: program.uniform_location("uShadowBias"),
+            texel_size: program.uniform_location("uShadowTexelSize"),
+            radius: program.uniform_location("uShadowRadius"),
+        }
+    }
+
+    // TEXTURE3 に shadow map を割り当てる。影が無い時は uShadowLight = -1
+    pub fn apply(&self, context: &C, shadow: Option<&ShadowMap<C>>) {
+        let shadow = shadow.and_then(|s| s.light.map(|light| (s, light)));
+
+        context.active_texture(WebGlRenderingContext::TEXTURE3);
+        context.bind_texture(WebGlRenderingContext::TEXTURE_2D, shadow.map(|(s, _)| &*s.texture));
+        context.active_texture(WebGlRenderingContext::TEXTURE0);
+        context.uniform1i(self.map.as_ref(), 3);
+
+        match shadow {
+            Some((shadow, light)) => {
+                let options = &shadow.options;
+                context.uniform_matrix4fv_with_f32_array(self.matrix.as_ref(), false, shadow.matrix.as_slice());
+                context.uniform1i(self.light.as_ref(), light as i32);
+                context.uniform1i(self.packed.as_ref(), shadow.packed as i32);
+                context.uniform1f(self.bias.as_ref(), options.bias);
+                context.uniform2f(self.texel_size.as_ref(), 1.0 / options.size as f32, 1.0 / options.size as f32);
+                context.uniform1i(self.radius.as_ref(), options.pcf_radius.clamp(0, MAX_PCF_RADIUS));
+            }
+            None => {
+                context.uniform_matrix4fv_with_f32_array(self.matrix.as_ref(), false, Mat4::identity().as_slice());
+                context.uniform1i(self.light.as_ref(), -1);
+            }
+        }
+    }
+}
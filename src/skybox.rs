@@ -0,0 +1,58 @@
+use nalgebra_glm::Mat4;
+use web_sys::WebGlRenderingContext;
+
+use crate::backend::Backend;
+use crate::cube;
+use crate::mesh::{Attribute, Mesh};
+use crate::program::Program;
+use crate::shader;
+
+// 環境マップを視線方向で引いて背景に描く
+pub struct Skybox<C: Backend = WebGlRenderingContext> {
+    context: C,
+    program: Program<C>,
+
+    mesh: Mesh<C>,
+}
+
+impl<C: Backend> Skybox<C> {
+    pub fn new(context: &C) -> Result<Skybox<C>, String> {
+        let program = shader::build_program(context, "skybox.vert", "skybox.frag", &[])?;
+
+        let mut mesh = Mesh::new(context, WebGlRenderingContext::TRIANGLES);
+        mesh.add_vertex_buffer(cube::VERTEX, vec![Attribute::float("aPosition", 3)])?;
+        mesh.set_index(cube::INDEX)?;
+
+        Ok(Skybox {
+            context: context.clone(),
+            program,
+            mesh,
+        })
+    }
+
+    // 深度は常に 1.0 になるので LEQUAL で何も描かれていない所だけ埋まる
+    pub fn render(&self, view: &Mat4, projection: &Mat4, texture: Option<&C::Texture>) -> Result<(), String> {
+        // 平行移動を除いた view 行列
+        let mut view = *view;
+        view[(0, 3)] = 0.0;
+        view[(1, 3)] = 0.0;
+        view[(2, 3)] = 0.0;
+        let view_projection = projection * view;
+
+        self.program.bind();
+
+        let _binding = self.mesh.bind(&self.program);
+
+        self.program.set_mat4("uViewProjectionMatrix", view_projection.as_slice())?;
+
+        self.context
+            .active_texture(WebGlRenderingContext::TEXTURE0);
+        self.context
+            .bind_texture(WebGlRenderingContext::TEXTURE_CUBE_MAP, texture);
+        self.program.set_sampler("cubeTexture", 0)?;
+
+        self.mesh.draw();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1633 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::path::Path;
+use std::rc::Rc;
+
+use image::{ImageFormat, RgbaImage};
+use nalgebra_glm::{Mat4, Vec2, Vec3, Vec4};
+use web_sys::WebGlRenderingContext;
+
+use crate::backend::{ActiveInfo, Backend};
+use crate::ibl;
+use crate::light::MAX_LIGHTS;
+use crate::recording::{self, Handle, Location};
+use crate::shadow::MAX_PCF_RADIUS;
+
+// 頂点 shader から fragment shader に渡す float の最大数 (standard.vert の varying の合計)
+const MAX_VARYINGS: usize = 14;
+const MAX_ATTRIBUTES: usize = 16;
+const TEXTURE_UNITS: usize = 8;
+// 窓座標の 1 画素あたりの刻み
+const SUBPIXEL: i64 = 256;
+// clip で切る x と y の範囲 (NDC)。画面の外の三角形の窓座標を i64 の辺の式で扱える大きさに抑える
+const GUARD_BAND: f32 = 16.0;
+
+// GPU もブラウザも無い所で Scene を描く Backend。GL の状態を CPU 側に持ち、三角形を走査して画素を埋める
+// GLSL は実行できないので、shader::library の program は Backend::label_shader で付いた名前で見分けて同じ計算を Rust で行う
+// 画素は GL と同じく下の行から並べ、image で取り出す時に上下を反転する
+// 対応するのは TRIANGLES、RGBA / UNSIGNED_BYTE のテクスチャ、CLAMP_TO_EDGE のみ。blend と culling は無い
+#[derive(Clone)]
+pub struct Software {
+    state: Rc<RefCell<State>>,
+    next: Rc<Cell<u32>>,
+}
+
+// shader::library のどの組み合わせか
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kind {
+    Depth,
+    Skybox,
+    BlinnPhong,
+    Glass,
+    Pbr,
+}
+
+impl Kind {
+    // Backend::label_shader で付いた名前の組から決める。名前の無い shader や知らない組なら None
+    fn from_labels(labels: &[&str]) -> Option<Kind> {
+        let vertex = labels.iter().find(|l| l.ends_with(".vert"))?;
+        let fragment = labels.iter().find(|l| l.ends_with(".frag"))?;
+
+        match (*vertex, *fragment) {
+            ("depth.vert", "depth.frag") => Some(Kind::Depth),
+            ("skybox.vert", "skybox.frag") => Some(Kind::Skybox),
+            ("standard.vert", "blinn_phong.frag") => Some(Kind::BlinnPhong),
+            ("standard.vert", "glass.frag") => Some(Kind::Glass),
+            ("standard.vert", "pbr.frag") => Some(Kind::Pbr),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct Level {
+    width: usize,
+    height: usize,
+    pixels: Vec<[u8; 4]>,
+}
+
+struct Texture {
+    // TEXTURE_2D は faces[0] だけ使う。面ごとの mip level
+    faces: Vec<Vec<Level>>,
+    min_filter: u32,
+    mag_filter: u32,
+}
+
+impl Default for Texture {
+    fn default() -> Self {
+        Texture {
+            faces: vec![Vec::new(); 6],
+            min_filter: WebGlRenderingContext::NEAREST_MIPMAP_LINEAR,
+            mag_filter: WebGlRenderingContext::LINEAR,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Renderbuffer {
+    width: usize,
+    height: usize,
+    color: Vec<[u8; 4]>,
+    depth: Vec<f32>,
+}
+
+#[derive(Clone, Copy)]
+enum Attachment {
+    Texture { texture: Handle, face: usize, level: usize },
+    Renderbuffer(Handle),
+}
+
+#[derive(Default)]
+struct Framebuffer {
+    color: Option<Attachment>,
+    depth: Option<Handle>,
+}
+
+#[derive(Default)]
+struct Program {
+    shaders: Vec<Handle>,
+    kind: Option<Kind>,
+    uniforms: Vec<ActiveInfo>,
+    attributes: Vec<ActiveInfo>,
+    // uniform の名前 (配列は "[0]" を除く) ごとの値。int と bool も float で持つ
+    values: HashMap<String, Vec<f32>>,
+}
+
+#[derive(Clone, Copy)]
+struct AttribArray {
+    enabled: bool,
+    buffer: Option<Handle>,
+    size: i32,
+    kind: u32,
+    normalized: bool,
+    stride: i32,
+    offset: i32,
+    // 無効な時に使う vertex_attrib4f の値
+    value: [f32; 4],
+}
+
+impl Default for AttribArray {
+    fn default() -> Self {
+        AttribArray {
+            enabled: false,
+            buffer: None,
+            size: 4,
+            kind: WebGlRenderingContext::FLOAT,
+            normalized: false,
+            stride: 0,
+            offset: 0,
+            value: [0.0, 0.0, 0.0, 1.0],
+        }
+    }
+}
+
+// 描く先の色と深度。描いている間だけ State から取り出す
+struct Target {
+    width: usize,
+    height: usize,
+    color: Vec<[u8; 4]>,
+    depth: Option<Vec<f32>>,
+}
+
+struct State {
+    width: usize,
+    height: usize,
+    color: Vec<[u8; 4]>,
+    depth: Vec<f32>,
+
+    viewport: [i32; 4],
+    clear_color: [f32; 4],
+    clear_depth: f32,
+    depth_test: bool,
+    depth_func: u32,
+
+    buffers: HashMap<Handle, Vec<u8>>,
+    array_buffer: Option<Handle>,
+    element_buffer: Option<Handle>,
+
+    textures: HashMap<Handle, Texture>,
+    // unit ごとの (TEXTURE_2D, TEXTURE_CUBE_MAP)
+    units: [(Option<Handle>, Option<Handle>); TEXTURE_UNITS],
+    active_unit: usize,
+
+    framebuffers: HashMap<Handle, Framebuffer>,
+    framebuffer: Option<Handle>,
+    renderbuffers: HashMap<Handle, Renderbuffer>,
+    renderbuffer: Option<Handle>,
+
+    shaders: HashMap<Handle, String>,
+    labels: HashMap<Handle, String>,
+    programs: HashMap<Handle, Program>,
+    program: Option<Handle>,
+    attribs: [AttribArray; MAX_ATTRIBUTES],
+}
+
+// clip 座標と varying
+#[derive(Clone, Copy)]
+struct Vertex {
+    position: Vec4,
+    varyings: [f32; MAX_VARYINGS],
+}
+
+impl Vertex {
+    fn lerp(&self, other: &Vertex, t: f32) -> Vertex {
+        let mut varyings = [0.0; MAX_VARYINGS];
+        for (i, v) in varyings.iter_mut().enumerate() {
+            *v = self.varyings[i] + (other.varyings[i] - self.varyings[i]) * t;
+        }
+
+        Vertex {
+            position: self.position + (other.position - self.position) * t,
+            varyings,
+        }
+    }
+}
+
+impl Software {
+    // width x height の既定の framebuffer を持つ
+    pub fn new(width: u32, height: u32) -> Software {
+        let (width, height) = (width.max(1) as usize, height.max(1) as usize);
+
+        Software {
+            state: Rc::new(RefCell::new(State {
+                width,
+                height,
+                color: vec![[0, 0, 0, 0]; width * height],
+                depth: vec![1.0; width * height],
+
+                viewport: [0, 0, width as i32, height as i32],
+                clear_color: [0.0; 4],
+                clear_depth: 1.0,
+                depth_test: false,
+                depth_func: WebGlRenderingContext::LESS,
+
+                buffers: HashMap::new(),
+                array_buffer: None,
+                element_buffer: None,
+
+                textures: HashMap::new(),
+                units: [(None, None); TEXTURE_UNITS],
+                active_unit: 0,
+
+                framebuffers: HashMap::new(),
+                framebuffer: None,
+                renderbuffers: HashMap::new(),
+                renderbuffer: None,
+
+                shaders: HashMap::new(),
+                labels: HashMap::new(),
+                programs: HashMap::new(),
+                program: None,
+                attribs: [AttribArray::default(); MAX_ATTRIBUTES],
+            })),
+            next: Rc::new(Cell::new(0)),
+        }
+    }
+
+    pub fn size(&self) -> (u32, u32) {
+        let state = self.state.borrow();
+        (state.width as u32, state.height as u32)
+    }
+
+    // 既定の framebuffer の内容。上の行から並べ直す
+    pub fn image(&self) -> RgbaImage {
+        let state = self.state.borrow();
+        let (width, height) = (state.width, state.height);
+
+        RgbaImage::from_fn(width as u32, height as u32, |x, y| {
+            image::Rgba(state.color[(height - 1 - y as usize) * width + x as usize])
+        })
+    }
+
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let path = path.as_ref();
+        self.image()
+            .save_with_format(path, ImageFormat::Png)
+            .map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    fn handle(&self) -> Handle {
+        let id = self.next.get() + 1;
+        self.next.set(id);
+        Handle(id)
+    }
+
+    fn uniform(&self, location: Option<&Location>, value: &[f32]) {
+        let location = match location {
+            Some(location) => location,
+            None => return,
+        };
+        if let Some(program) = self.state.borrow_mut().programs.get_mut(&location.program) {
+            program.values.insert(location.name.clone(), value.to_vec());
+        }
+    }
+}
+
+impl State {
+    fn bound_texture(&self, target: u32) -> Option<Handle> {
+        let (texture_2d, cube) = self.units[self.active_unit];
+        if target == WebGlRenderingContext::TEXTURE_2D {
+            texture_2d
+        } else {
+            cube
+        }
+    }
+
+    fn take_target(&mut self) -> Target {
+        let framebuffer = match self.framebuffer.and_then(|f| self.framebuffers.get(&f)) {
+            Some(framebuffer) => (framebuffer.color, framebuffer.depth),
+            None => {
+                return Target {
+                    width: self.width,
+                    height: self.height,
+                    color: std::mem::take(&mut self.color),
+                    depth: Some(std::mem::take(&mut self.depth)),
+                }
+            }
+        };
+
+        let (mut width, mut height, mut color) = (0, 0, Vec::new());
+        match framebuffer.0 {
+            Some(Attachment::Texture { texture, face, level }) => {
+                if let Some(level) = self
+                    .textures
+                    .get_mut(&texture)
+                    .and_then(|t| t.faces[face].get_mut(level))
+                {
+                    width = level.width;
+                    height = level.height;
+                    color = std::mem::take(&mut level.pixels);
+                }
+            }
+            Some(Attachment::Renderbuffer(renderbuffer)) => {
+                if let Some(renderbuffer) = self.renderbuffers.get_mut(&renderbuffer) {
+                    width = renderbuffer.width;
+                    height = renderbuffer.height;
+                    color = std::mem::take(&mut renderbuffer.color);
+                }
+            }
+            None => {}
+        }
+
+        let depth = framebuffer.1.and_then(|d| self.renderbuffers.get_mut(&d)).map(|d| {
+            if color.is_empty() {
+                width = d.width;
+                height = d.height;
+            }
+            std::mem::take(&mut d.depth)
+        });
+
+        Target {
+            width,
+            height,
+            color,
+            depth,
+        }
+    }
+
+    fn put_target(&mut self, target: Target) {
+        let framebuffer = match self.framebuffer.and_then(|f| self.framebuffers.get(&f)) {
+            Some(framebuffer) => (framebuffer.color, framebuffer.depth),
+            None => {
+                self.color = target.color;
+                self.depth = target.depth.unwrap_or_default();
+                return;
+            }
+        };
+
+        match framebuffer.0 {
+            Some(Attachment::Texture { texture, face, level }) => {
+                if let Some(level) = self
+                    .textures
+                    .get_mut(&texture)
+                    .and_then(|t| t.faces[face].get_mut(level))
+                {
+                    level.pixels = target.color;
+                }
+            }
+            Some(Attachment::Renderbuffer(renderbuffer)) => {
+                if let Some(renderbuffer) = self.renderbuffers.get_mut(&renderbuffer) {
+                    renderbuffer.color = target.color;
+                }
+            }
+            None => {}
+        }
+        if let (Some(depth), Some(renderbuffer)) = (target.depth, framebuffer.1.and_then(|d| self.renderbuffers.get_mut(&d))) {
+            renderbuffer.depth = depth;
+        }
+    }
+
+    // index 番目の頂点の attribute。無効なら vertex_attrib4f の値
+    fn fetch(&self, location: Option<usize>, index: usize) -> Vec4 {
+        let attrib = match location.and_then(|l| self.attribs.get(l)) {
+            Some(attrib) => attrib,
+            None => return Vec4::new(0.0, 0.0, 0.0, 1.0),
+        };
+        let data = match attrib.buffer.and_then(|b| self.buffers.get(&b)) {
+            Some(data) if attrib.enabled => data,
+            _ => return Vec4::from(attrib.value),
+        };
+
+        let component = match attrib.kind {
+            WebGlRenderingContext::FLOAT => 4,
+            WebGlRenderingContext::SHORT | WebGlRenderingContext::UNSIGNED_SHORT => 2,
+            _ => 1,
+        };
+        let stride = if attrib.stride > 0 {
+            attrib.stride as usize
+        } else {
+            component * attrib.size as usize
+        };
+
+        let mut value = [0.0, 0.0, 0.0, 1.0];
+        for (i, v) in value.iter_mut().enumerate().take(attrib.size as usize) {
+            let at = attrib.offset as usize + stride * index + component * i;
+            let bytes = match data.get(at..at + component) {
+                Some(bytes) => bytes,
+                None => break,
+            };
+            *v = match attrib.kind {
+                WebGlRenderingContext::FLOAT => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+                WebGlRenderingContext::UNSIGNED_BYTE if attrib.normalized => bytes[0] as f32 / 255.0,
+                WebGlRenderingContext::UNSIGNED_BYTE => bytes[0] as f32,
+                WebGlRenderingContext::BYTE if attrib.normalized => (bytes[0] as i8 as f32 / 127.0).max(-1.0),
+                WebGlRenderingContext::BYTE => bytes[0] as i8 as f32,
+                WebGlRenderingContext::UNSIGNED_SHORT if attrib.normalized => {
+                    u16::from_le_bytes([bytes[0], bytes[1]]) as f32 / 65535.0
+                }
+                WebGlRenderingContext::UNSIGNED_SHORT => u16::from_le_bytes([bytes[0], bytes[1]]) as f32,
+                WebGlRenderingContext::SHORT if attrib.normalized => {
+                    (i16::from_le_bytes([bytes[0], bytes[1]]) as f32 / 32767.0).max(-1.0)
+                }
+                _ => i16::from_le_bytes([bytes[0], bytes[1]]) as f32,
+            };
+        }
+
+        Vec4::from(value)
+    }
+
+    fn clear(&mut self, mask: u32) {
+        let mut target = self.take_target();
+
+        if mask & WebGlRenderingContext::COLOR_BUFFER_BIT != 0 {
+            let [r, g, b, a] = self.clear_color;
+            let color = to_unorm(&Vec4::new(r, g, b, a));
+            target.color.iter_mut().for_each(|c| *c = color);
+        }
+        if mask & WebGlRenderingContext::DEPTH_BUFFER_BIT != 0 {
+            let depth = self.clear_depth.clamp(0.0, 1.0);
+            if let Some(d) = target.depth.as_mut() {
+                d.iter_mut().for_each(|d| *d = depth);
+            }
+        }
+
+        self.put_target(target);
+    }
+
+    fn draw(&mut self, mode: u32, indices: &[usize]) {
+        if mode != WebGlRenderingContext::TRIANGLES || indices.is_empty() {
+            return;
+        }
+
+        // 描く先を取り出しておけば、テクスチャと uniform は self から借りたまま読める
+        let mut target = self.take_target();
+        self.render(indices, &mut target);
+        self.put_target(target);
+    }
+
+    fn render(&self, indices: &[usize], target: &mut Target) {
+        let program = match self.program.and_then(|p| self.programs.get(&p)) {
+            Some(program) => program,
+            None => return,
+        };
+        let kind = match program.kind {
+            Some(kind) => kind,
+            None => return,
+        };
+
+        let location = |name: &str| program.attributes.iter().position(|a| a.name == name);
+        let (position, normal, color) = (location("aPosition"), location("aNormal"), location("aColor"));
+        let shading = Shading {
+            state: self,
+            values: &program.values,
+        };
+
+        let count = indices.iter().max().map(|m| m + 1).unwrap_or(0);
+        let vertices: Vec<Vertex> = (0..count)
+            .map(|i| {
+                let input = Input {
+                    position: self.fetch(position, i),
+                    normal: self.fetch(normal, i),
+                    color: self.fetch(color, i),
+                };
+                vertex_shader(kind, &shading, &input)
+            })
+            .collect();
+
+        for triangle in indices.chunks_exact(3) {
+            let polygon = clip([vertices[triangle[0]], vertices[triangle[1]], vertices[triangle[2]]]);
+            for i in 1..polygon.len().saturating_sub(1) {
+                rasterize(&[polygon[0], polygon[i], polygon[i + 1]], self.viewport, target, |target, i, z, varyings| {
+                    // 深度を先に比べて、隠れる画素の shader は実行しない
+                    if let Some(depth) = target.depth.as_mut() {
+                        if self.depth_test && !compare(self.depth_func, z, depth[i]) {
+                            return;
+                        }
+                        depth[i] = z;
+                    }
+                    let color = fragment_shader(kind, &shading, varyings, z);
+                    if let Some(pixel) = target.color.get_mut(i) {
+                        *pixel = to_unorm(&color);
+                    }
+                });
+            }
+        }
+    }
+}
+
+// 頂点 shader の attribute
+struct Input {
+    position: Vec4,
+    normal: Vec4,
+    color: Vec4,
+}
+
+// fragment shader から見える uniform とテクスチャ。無い uniform は GL と同じく 0
+struct Shading<'a> {
+    state: &'a State,
+    values: &'a HashMap<String, Vec<f32>>,
+}
+
+impl<'a> Shading<'a> {
+    fn floats(&self, name: &str, index: usize, count: usize) -> Vec<f32> {
+        let mut value = vec![0.0; count];
+        if let Some(stored) = self.values.get(name) {
+            for (i, v) in value.iter_mut().enumerate() {
+                *v = stored.get(index * count + i).copied().unwrap_or(0.0);
+            }
+        }
+
+        value
+    }
+
+    fn float(&self, name: &str) -> f32 {
+        self.floats(name, 0, 1)[0]
+    }
+
+    fn int(&self, name: &str) -> i32 {
+        self.float(name) as i32
+    }
+
+    fn vec2_at(&self, name: &str, index: usize) -> Vec2 {
+        Vec2::from_column_slice(&self.floats(name, index, 2))
+    }
+
+    fn vec2(&self, name: &str) -> Vec2 {
+        self.vec2_at(name, 0)
+    }
+
+    fn vec3_at(&self, name: &str, index: usize) -> Vec3 {
+        Vec3::from_column_slice(&self.floats(name, index, 3))
+    }
+
+    fn vec3(&self, name: &str) -> Vec3 {
+        self.vec3_at(name, 0)
+    }
+
+    fn vec4_at(&self, name: &str, index: usize) -> Vec4 {
+        Vec4::from_column_slice(&self.floats(name, index, 4))
+    }
+
+    fn mat4(&self, name: &str) -> Mat4 {
+        Mat4::from_column_slice(&self.floats(name, 0, 16))
+    }
+
+    // sampler の uniform が指す unit に bind されたテクスチャ
+    fn texture(&self, sampler: &str, cube: bool) -> Option<&Texture> {
+        let unit = self.state.units.get(self.int(sampler).max(0) as usize)?;
+        let texture = if cube { unit.1 } else { unit.0 };
+        self.state.textures.get(&texture?)
+    }
+
+    fn texture_2d(&self, sampler: &str, uv: &Vec2) -> Vec4 {
+        match self.texture(sampler, false) {
+            Some(texture) => texture.sample(0, uv.x, uv.y, 0.0),
+            None => Vec4::new(0.0, 0.0, 0.0, 1.0),
+        }
+    }
+
+    // 画面上の微分が無いので、lod (textureCube の bias や textureCubeLodEXT の lod) をそのまま mip level にする
+    fn texture_cube(&self, sampler: &str, direction: &Vec3, lod: f32) -> Vec4 {
+        let texture = match self.texture(sampler, true) {
+            Some(texture) => texture,
+            None => return Vec4::new(0.0, 0.0, 0.0, 1.0),
+        };
+        let (face, s, t) = ibl::direction_to_face(direction);
+
+        texture.sample(face, (s + 1.0) * 0.5, (t + 1.0) * 0.5, lod)
+    }
+}
+
+impl Texture {
+    fn sample(&self, face: usize, u: f32, v: f32, lod: f32) -> Vec4 {
+        let levels = &self.faces[face];
+        let mipmap = matches!(
+            self.min_filter,
+            WebGlRenderingContext::NEAREST_MIPMAP_NEAREST
+                | WebGlRenderingContext::LINEAR_MIPMAP_NEAREST
+                | WebGlRenderingContext::NEAREST_MIPMAP_LINEAR
+                | WebGlRenderingContext::LINEAR_MIPMAP_LINEAR
+        );
+
+        if lod <= 0.0 || !mipmap || levels.len() < 2 {
+            let filter = if lod <= 0.0 { self.mag_filter } else { self.min_filter };
+            let linear = matches!(
+                filter,
+                WebGlRenderingContext::LINEAR
+                    | WebGlRenderingContext::LINEAR_MIPMAP_NEAREST
+                    | WebGlRenderingContext::LINEAR_MIPMAP_LINEAR
+            );
+            return sample_level(levels.first(), u, v, linear);
+        }
+
+        let linear = matches!(
+            self.min_filter,
+            WebGlRenderingContext::LINEAR_MIPMAP_NEAREST | WebGlRenderingContext::LINEAR_MIPMAP_LINEAR
+        );
+        let lod = lod.min((levels.len() - 1) as f32);
+        match self.min_filter {
+            WebGlRenderingContext::NEAREST_MIPMAP_NEAREST | WebGlRenderingContext::LINEAR_MIPMAP_NEAREST => {
+                sample_level(levels.get(lod.round() as usize), u, v, linear)
+            }
+            _ => {
+                let base = lod.floor() as usize;
+                let a = sample_level(levels.get(base), u, v, linear);
+                let b = sample_level(levels.get((base + 1).min(levels.len() - 1)), u, v, linear);
+                a + (b - a) * lod.fract()
+            }
+        }
+    }
+
+    // level 0 から 1x1 まで box filter で縮める
+    fn generate_mipmap(&mut self) {
+        for levels in self.faces.iter_mut() {
+            levels.truncate(1);
+            while let Some(last) = levels.last() {
+                if last.pixels.is_empty() || (last.width == 1 && last.height == 1) {
+                    break;
+                }
+
+                let (width, height) = ((last.width / 2).max(1), (last.height / 2).max(1));
+                let mut pixels = Vec::with_capacity(width * height);
+                for y in 0..height {
+                    for x in 0..width {
+                        let mut sum = [0u32; 4];
+                        for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                            let sx = (x * 2 + dx).min(last.width - 1);
+                            let sy = (y * 2 + dy).min(last.height - 1);
+                            let p = last.pixels[sy * last.width + sx];
+                            for c in 0..4 {
+                                sum[c] += p[c] as u32;
+                            }
+                        }
+                        pixels.push([
+                            ((sum[0] + 2) / 4) as u8,
+                            ((sum[1] + 2) / 4) as u8,
+                            ((sum[2] + 2) / 4) as u8,
+                            ((sum[3] + 2) / 4) as u8,
+                        ]);
+                    }
+                }
+
+                levels.push(Level { width, height, pixels });
+            }
+        }
+    }
+}
+
+// CLAMP_TO_EDGE。中身の無い level は GL の不完全なテクスチャと同じく黒
+fn sample_level(level: Option<&Level>, u: f32, v: f32, linear: bool) -> Vec4 {
+    let level = match level {
+        Some(level) if !level.pixels.is_empty() => level,
+        _ => return Vec4::new(0.0, 0.0, 0.0, 1.0),
+    };
+    let texel = |x: i64, y: i64| {
+        let x = x.clamp(0, level.width as i64 - 1) as usize;
+        let y = y.clamp(0, level.height as i64 - 1) as usize;
+        let p = level.pixels[y * level.width + x];
+        Vec4::new(p[0] as f32, p[1] as f32, p[2] as f32, p[3] as f32) / 255.0
+    };
+
+    let x = u * level.width as f32;
+    let y = v * level.height as f32;
+    if !linear {
+        return texel(x.floor() as i64, y.floor() as i64);
+    }
+
+    let (x, y) = (x - 0.5, y - 0.5);
+    let (x0, y0) = (x.floor(), y.floor());
+    let (fx, fy) = (x - x0, y - y0);
+    let (x0, y0) = (x0 as i64, y0 as i64);
+
+    let top = texel(x0, y0) * (1.0 - fx) + texel(x0 + 1, y0) * fx;
+    let bottom = texel(x0, y0 + 1) * (1.0 - fx) + texel(x0 + 1, y0 + 1) * fx;
+    top * (1.0 - fy) + bottom * fy
+}
+
+// standard.vert, depth.vert, skybox.vert
+fn vertex_shader(kind: Kind, shading: &Shading, input: &Input) -> Vertex {
+    let position = Vec4::new(input.position.x, input.position.y, input.position.z, 1.0);
+    let mut varyings = [0.0; MAX_VARYINGS];
+
+    let position = match kind {
+        Kind::Depth => shading.mat4("uMVPMatrix") * position,
+        Kind::Skybox => {
+            varyings[..3].copy_from_slice(&[position.x, position.y, position.z]);
+            let clip = shading.mat4("uViewProjectionMatrix") * position;
+            Vec4::new(clip.x, clip.y, clip.w, clip.w)
+        }
+        Kind::BlinnPhong | Kind::Glass | Kind::Pbr => {
+            let model = shading.mat4("uModelMatrix");
+            let world = model * position;
+            let normal = model * Vec4::new(input.normal.x, input.normal.y, input.normal.z, 0.0);
+            let shadow = shading.mat4("uShadowMatrix") * Vec4::new(world.x, world.y, world.z, 1.0);
+
+            varyings[..3].copy_from_slice(&[world.x, world.y, world.z]);
+            varyings[3..6].copy_from_slice(&[normal.x, normal.y, normal.z]);
+            varyings[6..10].copy_from_slice(input.color.as_slice());
+            varyings[10..14].copy_from_slice(shadow.as_slice());
+            shading.mat4("uMVPMatrix") * position
+        }
+    };
+
+    Vertex { position, varyings }
+}
+
+// depth は gl_FragCoord.z
+fn fragment_shader(kind: Kind, shading: &Shading, varyings: &[f32; MAX_VARYINGS], depth: f32) -> Vec4 {
+    match kind {
+        Kind::Depth => {
+            if shading.int("uPackDepth") != 0 {
+                pack_depth(depth)
+            } else {
+                Vec4::new(1.0, 1.0, 1.0, 1.0)
+            }
+        }
+        Kind::Skybox => shading.texture_cube("cubeTexture", &Vec3::new(varyings[0], varyings[1], varyings[2]), 0.0),
+        Kind::BlinnPhong => blinn_phong(shading, &Surface::new(varyings)),
+        Kind::Glass => glass(shading, &Surface::new(varyings)),
+        Kind::Pbr => pbr(shading, &Surface::new(varyings)),
+    }
+}
+
+// standard.vert の varying
+struct Surface {
+    position: Vec3,
+    normal: Vec3,
+    color: Vec4,
+    shadow_coord: Vec4,
+}
+
+impl Surface {
+    fn new(varyings: &[f32; MAX_VARYINGS]) -> Surface {
+        Surface {
+            position: Vec3::from_column_slice(&varyings[0..3]),
+            normal: Vec3::from_column_slice(&varyings[3..6]),
+            color: Vec4::from_column_slice(&varyings[6..10]),
+            shadow_coord: Vec4::from_column_slice(&varyings[10..14]),
+        }
+    }
+}
+
+// 長さ 0 のベクトルは 0 のまま
+fn normalize(v: &Vec3) -> Vec3 {
+    let length = v.norm();
+    if length > 0.0 {
+        v / length
+    } else {
+        *v
+    }
+}
+
+fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+fn reflect(incident: &Vec3, normal: &Vec3) -> Vec3 {
+    incident - normal * (2.0 * normal.dot(incident))
+}
+
+fn refract(incident: &Vec3, normal: &Vec3, eta: f32) -> Vec3 {
+    let cos = normal.dot(incident);
+    let k = 1.0 - eta * eta * (1.0 - cos * cos);
+    if k < 0.0 {
+        Vec3::zeros()
+    } else {
+        incident * eta - normal * (eta * cos + k.sqrt())
+    }
+}
+
+fn powf(color: &Vec3, exponent: f32) -> Vec3 {
+    color.map(|c| c.max(0.0).powf(exponent))
+}
+
+// shader::DEPTH_FRAG の packDepth
+fn pack_depth(depth: f32) -> Vec4 {
+    let fract = |x: f32| x - x.floor();
+    let enc = Vec4::new(fract(depth), fract(depth * 255.0), fract(depth * 65025.0), fract(depth * 16_581_375.0));
+
+    enc - Vec4::new(enc.y, enc.z, enc.w, enc.w).component_mul(&Vec4::new(1.0 / 255.0, 1.0 / 255.0, 1.0 / 255.0, 0.0))
+}
+
+// shader::LIGHTS の lightAttenuation。(減衰, 光源への単位ベクトル)
+fn light_attenuation(shading: &Shading, i: usize, position: &Vec3) -> (f32, Vec3) {
+    let light_position = shading.vec4_at("uLightPosition", i);
+    let direction = shading.vec3_at("uLightDirection", i);
+    let k = shading.vec3_at("uLightAttenuation", i);
+    let cone = shading.vec2_at("uLightCone", i);
+
+    if light_position.w == 0.0 {
+        return (1.0, normalize(&-direction));
+    }
+
+    let to_light = light_position.xyz() - position;
+    let distance = to_light.norm();
+    let light = to_light / distance;
+    let attenuation = 1.0 / (k.x + k.y * distance + k.z * distance * distance);
+
+    (attenuation * smoothstep(cone.y, cone.x, (-light).dot(&normalize(&direction))), light)
+}
+
+// shader::SHADOW の shadow
+fn shadow(shading: &Shading, light: usize, n_dot_l: f32, shadow_coord: &Vec4) -> f32 {
+    if light as i32 != shading.int("uShadowLight") {
+        return 1.0;
+    }
+
+    let mut coord = shadow_coord.xyz() / shadow_coord.w;
+    if coord.x < 0.0 || coord.x > 1.0 || coord.y < 0.0 || coord.y > 1.0 {
+        return 1.0;
+    }
+    coord.z = coord.z.min(1.0);
+
+    let packed = shading.int("uShadowPacked") != 0;
+    let texel_size = shading.vec2("uShadowTexelSize");
+    let radius = shading.int("uShadowRadius");
+    let bias = shading.float("uShadowBias") * (2.0 - n_dot_l);
+
+    let (mut lit, mut count) = (0.0, 0.0);
+    for x in -MAX_PCF_RADIUS..=MAX_PCF_RADIUS {
+        for y in -MAX_PCF_RADIUS..=MAX_PCF_RADIUS {
+            if x.abs() > radius || y.abs() > radius {
+                continue;
+            }
+            let uv = coord.xy() + Vec2::new(x as f32, y as f32).component_mul(&texel_size);
+            let texel = shading.texture_2d("uShadowMap", &uv);
+            let depth = if packed {
+                texel.dot(&Vec4::new(1.0, 1.0 / 255.0, 1.0 / 65025.0, 1.0 / 16_581_375.0))
+            } else {
+                texel.x
+            };
+
+            lit += if coord.z - bias > depth { 0.0 } else { 1.0 };
+            count += 1.0;
+        }
+    }
+
+    lit / count
+}
+
+fn light_count(shading: &Shading) -> usize {
+    shading.int("uLightCount").clamp(0, MAX_LIGHTS as i32) as usize
+}
+
+// shader::BLINN_PHONG_FRAG
+fn blinn_phong(shading: &Shading, surface: &Surface) -> Vec4 {
+    let normal = normalize(&surface.normal);
+    let view = normalize(&(shading.vec3("eyePosition") - surface.position));
+    let mut diffuse = shading.vec3("uAmbient");
+    let mut specular = Vec3::zeros();
+
+    for i in 0..light_count(shading) {
+        let (attenuation, light) = light_attenuation(shading, i, &surface.position);
+        let lambert = normal.dot(&light).max(0.0);
+        let attenuation = attenuation * shadow(shading, i, lambert, &surface.shadow_coord);
+        let halfway = normalize(&(light + view));
+        let highlight = if lambert > 0.0 {
+            normal.dot(&halfway).max(0.0).powf(shading.float("uShininess"))
+        } else {
+            0.0
+        };
+
+        let color = shading.vec3_at("uLightColor", i);
+        diffuse += color.component_mul(&shading.vec3("uDiffuse")) * attenuation * lambert;
+        specular += color.component_mul(&shading.vec3("uSpecular")) * attenuation * highlight;
+    }
+
+    let reflected = reflect(&-view, &normal);
+    let environment = shading.texture_cube("cubeTexture", &reflected, 0.0).xyz();
+    let color = surface.color.xyz();
+    let reflectivity = shading.float("uReflectivity");
+    let destination =
+        color.component_mul(&diffuse) * (1.0 - reflectivity) + color.component_mul(&environment) * reflectivity + specular;
+
+    Vec4::new(destination.x, destination.y, destination.z, surface.color.w)
+}
+
+// shader::GLASS_FRAG
+fn glass(shading: &Shading, surface: &Surface) -> Vec4 {
+    let normal = normalize(&surface.normal);
+    let incident = normalize(&(surface.position - shading.vec3("eyePosition")));
+    let ior = shading.float("uIor");
+    let dispersion = shading.float("uDispersion");
+
+    let refraction = |ior: f32| {
+        let direction = refract(&incident, &normal, 1.0 / ior);
+        if direction.dot(&direction) > 0.0 {
+            direction
+        } else {
+            reflect(&incident, &normal)
+        }
+    };
+
+    let reflected = shading.texture_cube("cubeTexture", &reflect(&incident, &normal), 0.0).xyz();
+    let refracted = Vec3::new(
+        shading.texture_cube("cubeTexture", &refraction(ior - dispersion), 0.0).x,
+        shading.texture_cube("cubeTexture", &refraction(ior), 0.0).y,
+        shading.texture_cube("cubeTexture", &refraction(ior + dispersion), 0.0).z,
+    );
+
+    let f0 = ((ior - 1.0) / (ior + 1.0)).powi(2);
+    let fresnel = f0 + (1.0 - f0) * (1.0 - (-incident).dot(&normal).max(0.0)).powi(5);
+    let tinted = refracted.component_mul(&shading.vec3("uTint"));
+    let destination = (tinted * (1.0 - fresnel) + reflected * fresnel).component_mul(&surface.color.xyz());
+
+    Vec4::new(destination.x, destination.y, destination.z, surface.color.w)
+}
+
+fn distribution_ggx(n_dot_h: f32, roughness: f32) -> f32 {
+    let a2 = roughness.powi(4);
+    let d = n_dot_h * n_dot_h * (a2 - 1.0) + 1.0;
+    a2 / (std::f32::consts::PI * d * d)
+}
+
+fn geometry_smith(n_dot_v: f32, n_dot_l: f32, roughness: f32) -> f32 {
+    let r = roughness + 1.0;
+    let k = r * r / 8.0;
+    n_dot_v / (n_dot_v * (1.0 - k) + k) * n_dot_l / (n_dot_l * (1.0 - k) + k)
+}
+
+fn fresnel_schlick(cos_theta: f32, f0: &Vec3, roughness: f32) -> Vec3 {
+    let max = f0.map(|f| f.max(1.0 - roughness));
+    f0 + (max - f0) * (1.0 - cos_theta).powi(5)
+}
+
+// shader::PBR_FRAG
+fn pbr(shading: &Shading, surface: &Surface) -> Vec4 {
+    let pi = std::f32::consts::PI;
+    let albedo = shading.vec3("uBaseColor").component_mul(&surface.color.xyz());
+    let metallic = shading.float("uMetallic");
+    let roughness = shading.float("uRoughness");
+    let normal = normalize(&surface.normal);
+    let view = normalize(&(shading.vec3("eyePosition") - surface.position));
+    let n_dot_v = normal.dot(&view).max(1e-4);
+    let f0 = Vec3::new(0.04, 0.04, 0.04) * (1.0 - metallic) + albedo * metallic;
+
+    let mut direct = Vec3::zeros();
+    for i in 0..light_count(shading) {
+        let (attenuation, light) = light_attenuation(shading, i, &surface.position);
+        let n_dot_l = normal.dot(&light).max(0.0);
+        let attenuation = attenuation * shadow(shading, i, n_dot_l, &surface.shadow_coord);
+        let halfway = normalize(&(light + view));
+        let f = fresnel_schlick(halfway.dot(&view).max(0.0), &f0, 0.0);
+        let specular = f * distribution_ggx(normal.dot(&halfway).max(0.0), roughness)
+            * geometry_smith(n_dot_v, n_dot_l, roughness)
+            / (4.0 * n_dot_v * n_dot_l + 1e-4);
+        let kd = (Vec3::new(1.0, 1.0, 1.0) - f) * (1.0 - metallic);
+
+        direct += (kd.component_mul(&albedo) / pi + specular).component_mul(&shading.vec3_at("uLightColor", i))
+            * attenuation
+            * n_dot_l;
+    }
+
+    // ibl::sh_basis と同じ順
+    let n = normal;
+    let basis = [
+        0.282_095,
+        0.488_603 * n.y,
+        0.488_603 * n.z,
+        0.488_603 * n.x,
+        1.092_548 * n.x * n.y,
+        1.092_548 * n.y * n.z,
+        0.315_392 * (3.0 * n.z * n.z - 1.0),
+        1.092_548 * n.x * n.z,
+        0.546_274 * (n.x * n.x - n.y * n.y),
+    ];
+    let irradiance = basis
+        .iter()
+        .enumerate()
+        .fold(Vec3::zeros(), |sum, (i, b)| sum + shading.vec3_at("uIrradiance", i) * *b);
+
+    let lod = roughness * shading.float("uSpecularMaxLod");
+    let prefiltered = powf(&shading.texture_cube("uSpecularMap", &reflect(&-view, &normal), lod).xyz(), 2.2);
+
+    let f = fresnel_schlick(n_dot_v, &f0, roughness);
+    let kd = (Vec3::new(1.0, 1.0, 1.0) - f) * (1.0 - metallic);
+    let brdf = shading.texture_2d("uBrdfLut", &Vec2::new(n_dot_v, roughness));
+    let diffuse = irradiance.component_mul(&albedo);
+    let specular = prefiltered.component_mul(&(f * brdf.x + Vec3::new(brdf.y, brdf.y, brdf.y)));
+
+    let color = (kd.component_mul(&diffuse) + specular) * shading.float("uAmbientOcclusion") + direct;
+    let color = powf(&color.component_div(&(color + Vec3::new(1.0, 1.0, 1.0))), 1.0 / 2.2);
+
+    Vec4::new(color.x, color.y, color.z, surface.color.w)
+}
+
+// near (z >= -w) と far (z <= w)、x と y は画面の GUARD_BAND 倍の所で切る
+// x と y の画面の外は rasterize で飛ばすが、w が 0 に近い頂点の窓座標が桁あふれしないよう遠くで切っておく
+fn clip(triangle: [Vertex; 3]) -> Vec<Vertex> {
+    let mut polygon = triangle.to_vec();
+
+    // (成分, w に掛ける数, 符号) ごとに scale * w + sign * v >= 0 の側を残す
+    let planes = [
+        (2, 1.0, 1.0),
+        (2, 1.0, -1.0),
+        (0, GUARD_BAND, 1.0),
+        (0, GUARD_BAND, -1.0),
+        (1, GUARD_BAND, 1.0),
+        (1, GUARD_BAND, -1.0),
+    ];
+
+    for &(axis, scale, sign) in planes.iter() {
+        let distance = |v: &Vertex| scale * v.position.w + sign * v.position[axis];
+        let mut clipped = Vec::with_capacity(polygon.len() + 1);
+
+        for (i, current) in polygon.iter().enumerate() {
+            let next = &polygon[(i + 1) % polygon.len()];
+            let (d0, d1) = (distance(current), distance(next));
+            if d0 >= 0.0 {
+                clipped.push(*current);
+            }
+            if (d0 >= 0.0) != (d1 >= 0.0) {
+                clipped.push(current.lerp(next, d0 / (d0 - d1)));
+            }
+        }
+
+        polygon = clipped;
+        if polygon.len() < 3 {
+            return Vec::new();
+        }
+    }
+
+    polygon
+}
+
+// 画素の中心が三角形に入る所で fragment(target, 画素の番号, 窓座標の深度, varying) を呼ぶ
+// varying は透視補正して補間する
+fn rasterize<F>(triangle: &[Vertex; 3], viewport: [i32; 4], target: &mut Target, mut fragment: F)
+where
+    F: FnMut(&mut Target, usize, f32, &[f32; MAX_VARYINGS]),
+{
+    let [vx, vy, vw, vh] = viewport;
+    let screen: Vec<(f32, f32, f32, f32)> = triangle
+        .iter()
+        .map(|v| {
+            let w = v.position.w;
+            let ndc = v.position.xyz() / w;
+            (
+                vx as f32 + (ndc.x + 1.0) * 0.5 * vw as f32,
+                vy as f32 + (ndc.y + 1.0) * 0.5 * vh as f32,
+                (ndc.z + 1.0) * 0.5,
+                1.0 / w,
+            )
+        })
+        .collect();
+    // 頂点を 1/256 画素に丸めて整数で辺の式を求める。共有する辺の画素が両方から漏れたり重なったりしないよう
+    // 辺の上に乗った画素は左か上の辺の時だけ描く
+    if screen.iter().any(|s| !s.0.is_finite() || !s.1.is_finite()) {
+        return;
+    }
+    let fixed = |v: f32| (v * SUBPIXEL as f32).round() as i64;
+    let mut order = [0, 1, 2];
+    let points: Vec<(i64, i64)> = screen.iter().map(|s| (fixed(s.0), fixed(s.1))).collect();
+    let edge = |a: (i64, i64), b: (i64, i64), p: (i64, i64)| (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0);
+
+    let mut area = edge(points[0], points[1], points[2]);
+    if area == 0 {
+        return;
+    }
+    // 裏向きも描くので、反時計回りに揃える
+    if area < 0 {
+        order = [0, 2, 1];
+        area = -area;
+    }
+    let (p0, p1, p2) = (points[order[0]], points[order[1]], points[order[2]]);
+    // 左か上の辺は 0 を含め、それ以外は含めない
+    let bias = |a: (i64, i64), b: (i64, i64)| if (a.1 == b.1 && b.0 < a.0) || b.1 < a.1 { 0 } else { -1 };
+    let (bias0, bias1, bias2) = (bias(p1, p2), bias(p2, p0), bias(p0, p1));
+
+    let extent = |f: fn(i64, i64) -> i64, a: i64, b: i64, c: i64| f(f(a, b), c);
+    let left = (vx.max(0) as i64).max(extent(i64::min, p0.0, p1.0, p2.0).div_euclid(SUBPIXEL));
+    let right = ((vx + vw) as i64).min(target.width as i64).min(extent(i64::max, p0.0, p1.0, p2.0).div_euclid(SUBPIXEL) + 1);
+    let bottom = (vy.max(0) as i64).max(extent(i64::min, p0.1, p1.1, p2.1).div_euclid(SUBPIXEL));
+    let top = ((vy + vh) as i64).min(target.height as i64).min(extent(i64::max, p0.1, p1.1, p2.1).div_euclid(SUBPIXEL) + 1);
+
+    for y in bottom..top {
+        for x in left..right {
+            let p = (x * SUBPIXEL + SUBPIXEL / 2, y * SUBPIXEL + SUBPIXEL / 2);
+            let (e0, e1, e2) = (edge(p1, p2, p), edge(p2, p0, p), edge(p0, p1, p));
+            if e0 + bias0 < 0 || e1 + bias1 < 0 || e2 + bias2 < 0 {
+                continue;
+            }
+
+            // order で並べ替えた重みを元の頂点の順に戻す
+            let mut weights = [0.0; 3];
+            weights[order[0]] = e0 as f32 / area as f32;
+            weights[order[1]] = e1 as f32 / area as f32;
+            weights[order[2]] = e2 as f32 / area as f32;
+            let [b0, b1, b2] = weights;
+            let (x, y) = (x as usize, y as usize);
+
+            let z = (b0 * screen[0].2 + b1 * screen[1].2 + b2 * screen[2].2).clamp(0.0, 1.0);
+            let (w0, w1, w2) = (b0 * screen[0].3, b1 * screen[1].3, b2 * screen[2].3);
+            let sum = w0 + w1 + w2;
+
+            let mut varyings = [0.0; MAX_VARYINGS];
+            for (i, v) in varyings.iter_mut().enumerate() {
+                *v = (w0 * triangle[0].varyings[i] + w1 * triangle[1].varyings[i] + w2 * triangle[2].varyings[i]) / sum;
+            }
+
+            fragment(target, y * target.width + x, z, &varyings);
+        }
+    }
+}
+
+fn compare(func: u32, depth: f32, stored: f32) -> bool {
+    match func {
+        WebGlRenderingContext::NEVER => false,
+        WebGlRenderingContext::LESS => depth < stored,
+        WebGlRenderingContext::EQUAL => depth == stored,
+        WebGlRenderingContext::LEQUAL => depth <= stored,
+        WebGlRenderingContext::GREATER => depth > stored,
+        WebGlRenderingContext::NOTEQUAL => depth != stored,
+        WebGlRenderingContext::GEQUAL => depth >= stored,
+        _ => true,
+    }
+}
+
+fn to_unorm(color: &Vec4) -> [u8; 4] {
+    let c = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    [c(color.x), c(color.y), c(color.z), c(color.w)]
+}
+
+fn cube_face(target: u32) -> usize {
+    match target {
+        WebGlRenderingContext::TEXTURE_CUBE_MAP_POSITIVE_X..=WebGlRenderingContext::TEXTURE_CUBE_MAP_NEGATIVE_Z => {
+            (target - WebGlRenderingContext::TEXTURE_CUBE_MAP_POSITIVE_X) as usize
+        }
+        _ => 0,
+    }
+}
+
+impl Backend for Software {
+    type Buffer = Handle;
+    type Texture = Handle;
+    type Framebuffer = Handle;
+    type Renderbuffer = Handle;
+    type Shader = Handle;
+    type Program = Handle;
+    type UniformLocation = Location;
+
+    // textureCube の bias を lod として扱うので EXT_shader_texture_lod と同じ結果になる
+    // 深度テクスチャは持たないので shadow map は RGBA に詰める
+    fn enable_extension(&self, name: &str) -> bool {
+        name == "EXT_shader_texture_lod"
+    }
+
+    fn viewport(&self, x: i32, y: i32, width: i32, height: i32) {
+        self.state.borrow_mut().viewport = [x, y, width, height];
+    }
+
+    fn clear_color(&self, red: f32, green: f32, blue: f32, alpha: f32) {
+        self.state.borrow_mut().clear_color = [red, green, blue, alpha];
+    }
+
+    fn clear_depth(&self, depth: f32) {
+        self.state.borrow_mut().clear_depth = depth;
+    }
+
+    fn clear(&self, mask: u32) {
+        self.state.borrow_mut().clear(mask);
+    }
+
+    fn enable(&self, cap: u32) {
+        if cap == WebGlRenderingContext::DEPTH_TEST {
+            self.state.borrow_mut().depth_test = true;
+        }
+    }
+
+    fn depth_func(&self, func: u32) {
+        self.state.borrow_mut().depth_func = func;
+    }
+
+    fn flush(&self) {}
+
+    fn create_buffer(&self) -> Option<Handle> {
+        let buffer = self.handle();
+        self.state.borrow_mut().buffers.insert(buffer, Vec::new());
+        Some(buffer)
+    }
+
+    fn delete_buffer(&self, buffer: Option<&Handle>) {
+        if let Some(buffer) = buffer {
+            self.state.borrow_mut().buffers.remove(buffer);
+        }
+    }
+
+    fn bind_buffer(&self, target: u32, buffer: Option<&Handle>) {
+        let mut state = self.state.borrow_mut();
+        if target == WebGlRenderingContext::ELEMENT_ARRAY_BUFFER {
+            state.element_buffer = buffer.copied();
+        } else {
+            state.array_buffer = buffer.copied();
+        }
+    }
+
+    fn buffer_data_with_f32_array(&self, target: u32, data: &[f32], usage: u32) {
+        let bytes: Vec<u8> = data.iter().flat_map(|v| v.to_le_bytes()).collect();
+        self.buffer_data_with_u8_array(target, &bytes, usage);
+    }
+
+    fn buffer_data_with_u16_array(&self, target: u32, data: &[u16], usage: u32) {
+        let bytes: Vec<u8> = data.iter().flat_map(|v| v.to_le_bytes()).collect();
+        self.buffer_data_with_u8_array(target, &bytes, usage);
+    }
+
+    fn buffer_data_with_u8_array(&self, target: u32, data: &[u8], _usage: u32) {
+        let mut state = self.state.borrow_mut();
+        let buffer = if target == WebGlRenderingContext::ELEMENT_ARRAY_BUFFER {
+            state.element_buffer
+        } else {
+            state.array_buffer
+        };
+        if let Some(stored) = buffer.and_then(|b| state.buffers.get_mut(&b)) {
+            *stored = data.to_vec();
+        }
+    }
+
+    fn create_texture(&self) -> Option<Handle> {
+        let texture = self.handle();
+        self.state.borrow_mut().textures.insert(texture, Texture::default());
+        Some(texture)
+    }
+
+    fn delete_texture(&self, texture: Option<&Handle>) {
+        if let Some(texture) = texture {
+            self.state.borrow_mut().textures.remove(texture);
+        }
+    }
+
+    fn bind_texture(&self, target: u32, texture: Option<&Handle>) {
+        let mut state = self.state.borrow_mut();
+        let unit = state.active_unit;
+        if target == WebGlRenderingContext::TEXTURE_2D {
+            state.units[unit].0 = texture.copied();
+        } else {
+            state.units[unit].1 = texture.copied();
+        }
+    }
+
+    fn active_texture(&self, texture: u32) {
+        let unit = texture.saturating_sub(WebGlRenderingContext::TEXTURE0) as usize;
+        self.state.borrow_mut().active_unit = unit.min(TEXTURE_UNITS - 1);
+    }
+
+    // RGBA / UNSIGNED_BYTE 以外の format も RGBA として領域だけ確保する
+    fn tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+        &self,
+        target: u32,
+        level: i32,
+        _internal_format: i32,
+        width: i32,
+        height: i32,
+        _border: i32,
+        _format: u32,
+        _kind: u32,
+        pixels: Option<&[u8]>,
+    ) -> Result<(), String> {
+        let mut state = self.state.borrow_mut();
+        let bound = if target == WebGlRenderingContext::TEXTURE_2D {
+            state.bound_texture(WebGlRenderingContext::TEXTURE_2D)
+        } else {
+            state.bound_texture(WebGlRenderingContext::TEXTURE_CUBE_MAP)
+        };
+        let texture = match bound.and_then(|t| state.textures.get_mut(&t)) {
+            Some(texture) => texture,
+            None => return Ok(()),
+        };
+
+        let (width, height) = (width.max(0) as usize, height.max(0) as usize);
+        let pixels = match pixels {
+            Some(pixels) if pixels.len() == width * height * 4 => {
+                pixels.chunks_exact(4).map(|p| [p[0], p[1], p[2], p[3]]).collect()
+            }
+            _ => vec![[0, 0, 0, 0]; width * height],
+        };
+
+        let levels = &mut texture.faces[cube_face(target)];
+        let level = level.max(0) as usize;
+        if levels.len() <= level {
+            levels.resize(level + 1, Level::default());
+        }
+        levels[level] = Level { width, height, pixels };
+
+        Ok(())
+    }
+
+    fn tex_parameteri(&self, target: u32, pname: u32, param: i32) {
+        let mut state = self.state.borrow_mut();
+        if let Some(texture) = state.bound_texture(target).and_then(|t| state.textures.get_mut(&t)) {
+            match pname {
+                WebGlRenderingContext::TEXTURE_MIN_FILTER => texture.min_filter = param as u32,
+                WebGlRenderingContext::TEXTURE_MAG_FILTER => texture.mag_filter = param as u32,
+                _ => {}
+            }
+        }
+    }
+
+    fn generate_mipmap(&self, target: u32) {
+        let mut state = self.state.borrow_mut();
+        if let Some(texture) = state.bound_texture(target).and_then(|t| state.textures.get_mut(&t)) {
+            texture.generate_mipmap();
+        }
+    }
+
+    fn create_framebuffer(&self) -> Option<Handle> {
+        let framebuffer = self.handle();
+        self.state.borrow_mut().framebuffers.insert(framebuffer, Framebuffer::default());
+        Some(framebuffer)
+    }
+
+    fn delete_framebuffer(&self, framebuffer: Option<&Handle>) {
+        if let Some(framebuffer) = framebuffer {
+            let mut state = self.state.borrow_mut();
+            state.framebuffers.remove(framebuffer);
+            if state.framebuffer == Some(*framebuffer) {
+                state.framebuffer = None;
+            }
+        }
+    }
+
+    fn bind_framebuffer(&self, _target: u32, framebuffer: Option<&Handle>) {
+        self.state.borrow_mut().framebuffer = framebuffer.copied();
+    }
+
+    fn check_framebuffer_status(&self, _target: u32) -> u32 {
+        let state = self.state.borrow();
+        match state.framebuffer.and_then(|f| state.framebuffers.get(&f)) {
+            Some(framebuffer) if framebuffer.color.is_none() && framebuffer.depth.is_none() => {
+                WebGlRenderingContext::FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT
+            }
+            _ => WebGlRenderingContext::FRAMEBUFFER_COMPLETE,
+        }
+    }
+
+    fn framebuffer_texture_2d(&self, _target: u32, attachment: u32, textarget: u32, texture: Option<&Handle>, level: i32) {
+        let mut state = self.state.borrow_mut();
+        let framebuffer = match state.framebuffer.and_then(|f| state.framebuffers.get_mut(&f)) {
+            Some(framebuffer) => framebuffer,
+            None => return,
+        };
+        if attachment == WebGlRenderingContext::COLOR_ATTACHMENT0 {
+            framebuffer.color = texture.map(|&texture| Attachment::Texture {
+                texture,
+                face: cube_face(textarget),
+                level: level.max(0) as usize,
+            });
+        }
+    }
+
+    fn create_renderbuffer(&self) -> Option<Handle> {
+        let renderbuffer = self.handle();
+        self.state.borrow_mut().renderbuffers.insert(renderbuffer, Renderbuffer::default());
+        Some(renderbuffer)
+    }
+
+    fn delete_renderbuffer(&self, renderbuffer: Option<&Handle>) {
+        if let Some(renderbuffer) = renderbuffer {
+            self.state.borrow_mut().renderbuffers.remove(renderbuffer);
+        }
+    }
+
+    fn bind_renderbuffer(&self, _target: u32, renderbuffer: Option<&Handle>) {
+        self.state.borrow_mut().renderbuffer = renderbuffer.copied();
+    }
+
+    fn renderbuffer_storage(&self, _target: u32, _format: u32, width: i32, height: i32) {
+        let mut state = self.state.borrow_mut();
+        if let Some(renderbuffer) = state.renderbuffer.and_then(|r| state.renderbuffers.get_mut(&r)) {
+            let (width, height) = (width.max(0) as usize, height.max(0) as usize);
+            *renderbuffer = Renderbuffer {
+                width,
+                height,
+                color: vec![[0, 0, 0, 0]; width * height],
+                depth: vec![1.0; width * height],
+            };
+        }
+    }
+
+    fn framebuffer_renderbuffer(&self, _target: u32, attachment: u32, _renderbuffer_target: u32, renderbuffer: Option<&Handle>) {
+        let mut state = self.state.borrow_mut();
+        let framebuffer = match state.framebuffer.and_then(|f| state.framebuffers.get_mut(&f)) {
+            Some(framebuffer) => framebuffer,
+            None => return,
+        };
+        if attachment == WebGlRenderingContext::DEPTH_ATTACHMENT {
+            framebuffer.depth = renderbuffer.copied();
+        } else if attachment == WebGlRenderingContext::COLOR_ATTACHMENT0 {
+            framebuffer.color = renderbuffer.map(|&r| Attachment::Renderbuffer(r));
+        }
+    }
+
+    fn create_shader(&self, _kind: u32) -> Option<Handle> {
+        let shader = self.handle();
+        self.state.borrow_mut().shaders.insert(shader, String::new());
+        Some(shader)
+    }
+
+    fn delete_shader(&self, _shader: Option<&Handle>) {
+        // attach 済みの shader は program が source を読むまで残す
+    }
+
+    fn shader_source(&self, shader: &Handle, source: &str) {
+        self.state.borrow_mut().shaders.insert(*shader, source.to_string());
+    }
+
+    fn compile_shader(&self, _shader: &Handle) {}
+
+    fn shader_compile_status(&self, _shader: &Handle) -> bool {
+        true
+    }
+
+    fn get_shader_info_log(&self, _shader: &Handle) -> Option<String> {
+        Some(String::new())
+    }
+
+    fn label_shader(&self, shader: &Handle, name: &str) {
+        self.state.borrow_mut().labels.insert(*shader, name.to_string());
+    }
+
+    fn create_program(&self) -> Option<Handle> {
+        let program = self.handle();
+        self.state.borrow_mut().programs.insert(program, Program::default());
+        Some(program)
+    }
+
+    fn delete_program(&self, program: Option<&Handle>) {
+        if let Some(program) = program {
+            self.state.borrow_mut().programs.remove(program);
+        }
+    }
+
+    fn attach_shader(&self, program: &Handle, shader: &Handle) {
+        if let Some(program) = self.state.borrow_mut().programs.get_mut(program) {
+            program.shaders.push(*shader);
+        }
+    }
+
+    fn link_program(&self, program: &Handle) {
+        let mut state = self.state.borrow_mut();
+        let (sources, labels): (Vec<String>, Vec<String>) = match state.programs.get(program) {
+            Some(p) => (
+                p.shaders.iter().filter_map(|s| state.shaders.get(s).cloned()).collect(),
+                p.shaders.iter().filter_map(|s| state.labels.get(s).cloned()).collect(),
+            ),
+            None => return,
+        };
+        let (uniforms, attributes) = recording::declarations(&sources);
+        let labels: Vec<&str> = labels.iter().map(|l| l.as_str()).collect();
+
+        if let Some(program) = state.programs.get_mut(program) {
+            program.kind = Kind::from_labels(&labels);
+            program.uniforms = uniforms;
+            program.attributes = attributes;
+        }
+    }
+
+    fn program_link_status(&self, program: &Handle) -> bool {
+        let state = self.state.borrow();
+        state.programs.get(program).map(|p| p.kind.is_some()).unwrap_or(false)
+    }
+
+    fn get_program_info_log(&self, program: &Handle) -> Option<String> {
+        if self.program_link_status(program) {
+            Some(String::new())
+        } else {
+            Some(String::from("ERROR: software renderer has no implementation of this program"))
+        }
+    }
+
+    fn use_program(&self, program: Option<&Handle>) {
+        self.state.borrow_mut().program = program.copied();
+    }
+
+    fn active_uniform_count(&self, program: &Handle) -> u32 {
+        let state = self.state.borrow();
+        state.programs.get(program).map(|p| p.uniforms.len() as u32).unwrap_or(0)
+    }
+
+    fn active_attribute_count(&self, program: &Handle) -> u32 {
+        let state = self.state.borrow();
+        state.programs.get(program).map(|p| p.attributes.len() as u32).unwrap_or(0)
+    }
+
+    fn active_uniform(&self, program: &Handle, index: u32) -> Option<ActiveInfo> {
+        let state = self.state.borrow();
+        state.programs.get(program)?.uniforms.get(index as usize).cloned()
+    }
+
+    fn active_attribute(&self, program: &Handle, index: u32) -> Option<ActiveInfo> {
+        let state = self.state.borrow();
+        state.programs.get(program)?.attributes.get(index as usize).cloned()
+    }
+
+    fn get_uniform_location(&self, program: &Handle, name: &str) -> Option<Location> {
+        let state = self.state.borrow();
+        let base = name.strip_suffix("[0]").unwrap_or(name);
+        let found = state
+            .programs
+            .get(program)?
+            .uniforms
+            .iter()
+            .any(|u| u.name.strip_suffix("[0]").unwrap_or(&u.name) == base);
+
+        if found {
+            Some(Location {
+                program: *program,
+                name: base.to_string(),
+            })
+        } else {
+            None
+        }
+    }
+
+    // attribute の番号は宣言の順
+    fn get_attrib_location(&self, program: &Handle, name: &str) -> i32 {
+        let state = self.state.borrow();
+        state
+            .programs
+            .get(program)
+            .and_then(|p| p.attributes.iter().position(|a| a.name == name))
+            .map(|i| i as i32)
+            .unwrap_or(-1)
+    }
+
+    fn uniform1i(&self, location: Option<&Location>, x: i32) {
+        self.uniform(location, &[x as f32]);
+    }
+
+    fn uniform1f(&self, location: Option<&Location>, x: f32) {
+        self.uniform(location, &[x]);
+    }
+
+    fn uniform2f(&self, location: Option<&Location>, x: f32, y: f32) {
+        self.uniform(location, &[x, y]);
+    }
+
+    fn uniform2fv_with_f32_array(&self, location: Option<&Location>, data: &[f32]) {
+        self.uniform(location, data);
+    }
+
+    fn uniform3fv_with_f32_array(&self, location: Option<&Location>, data: &[f32]) {
+        self.uniform(location, data);
+    }
+
+    fn uniform4fv_with_f32_array(&self, location: Option<&Location>, data: &[f32]) {
+        self.uniform(location, data);
+    }
+
+    fn uniform_matrix4fv_with_f32_array(&self, location: Option<&Location>, _transpose: bool, data: &[f32]) {
+        self.uniform(location, data);
+    }
+
+    fn enable_vertex_attrib_array(&self, index: u32) {
+        if let Some(attrib) = self.state.borrow_mut().attribs.get_mut(index as usize) {
+            attrib.enabled = true;
+        }
+    }
+
+    fn disable_vertex_attrib_array(&self, index: u32) {
+        if let Some(attrib) = self.state.borrow_mut().attribs.get_mut(index as usize) {
+            attrib.enabled = false;
+        }
+    }
+
+    // 今 ARRAY_BUFFER に bind されているバッファから読む
+    fn vertex_attrib_pointer_with_i32(&self, index: u32, size: i32, kind: u32, normalized: bool, stride: i32, offset: i32) {
+        let mut state = self.state.borrow_mut();
+        let buffer = state.array_buffer;
+        if let Some(attrib) = state.attribs.get_mut(index as usize) {
+            *attrib = AttribArray {
+                buffer,
+                size: size.clamp(1, 4),
+                kind,
+                normalized,
+                stride,
+                offset,
+                ..*attrib
+            };
+        }
+    }
+
+    fn vertex_attrib4f(&self, index: u32, x: f32, y: f32, z: f32, w: f32) {
+        if let Some(attrib) = self.state.borrow_mut().attribs.get_mut(index as usize) {
+            attrib.value = [x, y, z, w];
+        }
+    }
+
+    fn draw_elements_with_i32(&self, mode: u32, count: i32, kind: u32, offset: i32) {
+        let mut state = self.state.borrow_mut();
+        let indices: Vec<usize> = match state.element_buffer.and_then(|b| state.buffers.get(&b)) {
+            Some(data) => {
+                let data = data.get(offset.max(0) as usize..).unwrap_or(&[]);
+                if kind == WebGlRenderingContext::UNSIGNED_BYTE {
+                    data.iter().take(count.max(0) as usize).map(|i| *i as usize).collect()
+                } else {
+                    data.chunks_exact(2)
+                        .take(count.max(0) as usize)
+                        .map(|i| u16::from_le_bytes([i[0], i[1]]) as usize)
+                        .collect()
+                }
+            }
+            None => return,
+        };
+
+        state.draw(mode, &indices);
+    }
+
+    fn draw_arrays(&self, mode: u32, first: i32, count: i32) {
+        let indices: Vec<usize> = (first.max(0) as usize..(first + count).max(0) as usize).collect();
+        self.state.borrow_mut().draw(mode, &indices);
+    }
+}
@@ -1,6952 +0,0 @@
-pub static VERTEX:  &'static [f32] = &[
-    5.929688,4.125000,0.000000,
-    5.387188,4.125000,2.747500,
-    5.297100,4.494141,2.709170,
-    5.297100,4.494141,2.709170,
-    5.832031,4.494141,0.000000,
-    5.929688,4.125000,0.000000,
-    5.832031,4.494141,0.000000,
-    5.297100,4.494141,2.709170,
-    5.401602,4.617188,2.753633,
-    5.401602,4.617188,2.753633,
-    5.945313,4.617188,0.000000,
-    5.832031,4.494141,0.000000,
-    5.945313,4.617188,0.000000,
-    5.401602,4.617188,2.753633,
-    5.614209,4.494141,2.844092,
-    5.614209,4.494141,2.844092,
-    6.175781,4.494141,0.000000,
-    5.945313,4.617188,0.000000,
-    6.175781,4.494141,0.000000,
-    5.614209,4.494141,2.844092,
-    5.848437,4.125000,2.943750,
-    5.848437,4.125000,2.943750,
-    6.429688,4.125000,0.000000,
-    6.175781,4.494141,0.000000,
-    5.387188,4.125000,2.747500,
-    3.899688,4.125000,4.970000,
-    3.830352,4.494141,4.900664,
-    3.830352,4.494141,4.900664,
-    5.297100,4.494141,2.709170,
-    5.387188,4.125000,2.747500,
-    5.297100,4.494141,2.709170,
-    3.830352,4.494141,4.900664,
-    3.910782,4.617188,4.981094,
-    3.910782,4.617188,4.981094,
-    5.401602,4.617188,2.753633,
-    5.297100,4.494141,2.709170,
-    5.401602,4.617188,2.753633,
-    3.910782,4.617188,4.981094,
-    4.074414,4.494141,5.144727,
-    4.074414,4.494141,5.144727,
-    5.614209,4.494141,2.844092,
-    5.401602,4.617188,2.753633,
-    5.614209,4.494141,2.844092,
-    4.074414,4.494141,5.144727,
-    4.254687,4.125000,5.325000,
-    4.254687,4.125000,5.325000,
-    5.848437,4.125000,2.943750,
-    5.614209,4.494141,2.844092,
-    3.899688,4.125000,4.970000,
-    1.677188,4.125000,6.457500,
-    1.638858,4.494141,6.367412,
-    1.638858,4.494141,6.367412,
-    3.830352,4.494141,4.900664,
-    3.899688,4.125000,4.970000,
-    3.830352,4.494141,4.900664,
-    1.638858,4.494141,6.367412,
-    1.683320,4.617188,6.471914,
-    1.683320,4.617188,6.471914,
-    3.910782,4.617188,4.981094,
-    3.830352,4.494141,4.900664,
-    3.910782,4.617188,4.981094,
-    1.683320,4.617188,6.471914,
-    1.773780,4.494141,6.684522,
-    1.773780,4.494141,6.684522,
-    4.074414,4.494141,5.144727,
-    3.910782,4.617188,4.981094,
-    4.074414,4.494141,5.144727,
-    1.773780,4.494141,6.684522,
-    1.873438,4.125000,6.918750,
-    1.873438,4.125000,6.918750,
-    4.254687,4.125000,5.325000,
-    4.074414,4.494141,5.144727,
-    1.677188,4.125000,6.457500,
-    -1.070312,4.125000,7.000000,
-    -1.070312,4.494141,6.902344,
-    -1.070312,4.494141,6.902344,
-    1.638858,4.494141,6.367412,
-    1.677188,4.125000,6.457500,
-    1.638858,4.494141,6.367412,
-    -1.070312,4.494141,6.902344,
-    -1.070312,4.617188,7.015625,
-    -1.070312,4.617188,7.015625,
-    1.683320,4.617188,6.471914,
-    1.638858,4.494141,6.367412,
-    1.683320,4.617188,6.471914,
-    -1.070312,4.617188,7.015625,
-    -1.070312,4.494141,7.246094,
-    -1.070312,4.494141,7.246094,
-    1.773780,4.494141,6.684522,
-    1.683320,4.617188,6.471914,
-    1.773780,4.494141,6.684522,
-    -1.070312,4.494141,7.246094,
-    -1.070312,4.125000,7.500000,
-    -1.070312,4.125000,7.500000,
-    1.873438,4.125000,6.918750,
-    1.773780,4.494141,6.684522,
-    -1.070312,4.125000,7.000000,
-    -4.007656,4.125000,6.457500,
-    -3.859572,4.494141,6.367412,
-    -3.859572,4.494141,6.367412,
-    -1.070312,4.494141,6.902344,
-    -1.070312,4.125000,7.000000,
-    -1.070312,4.494141,6.902344,
-    -3.859572,4.494141,6.367412,
-    -3.847676,4.617188,6.471914,
-    -3.847676,4.617188,6.471914,
-    -1.070312,4.617188,7.015625,
-    -1.070312,4.494141,6.902344,
-    -1.070312,4.617188,7.015625,
-    -3.847676,4.617188,6.471914,
-    -3.917371,4.494141,6.684522,
-    -3.917371,4.494141,6.684522,
-    -1.070312,4.494141,7.246094,
-    -1.070312,4.617188,7.015625,
-    -1.070312,4.494141,7.246094,
-    -3.917371,4.494141,6.684522,
-    -4.014062,4.125000,6.918750,
-    -4.014062,4.125000,6.918750,
-    -1.070312,4.125000,7.500000,
-    -1.070312,4.494141,7.246094,
-    -4.007656,4.125000,6.457500,
-    -6.209063,4.125000,4.970000,
-    -6.042168,4.494141,4.900664,
-    -6.042168,4.494141,4.900664,
-    -3.859572,4.494141,6.367412,
-    -4.007656,4.125000,6.457500,
-    -3.859572,4.494141,6.367412,
-    -6.042168,4.494141,4.900664,
-    -6.072500,4.617188,4.981094,
-    -6.072500,4.617188,4.981094,
-    -3.847676,4.617188,6.471914,
-    -3.859572,4.494141,6.367412,
-    -3.847676,4.617188,6.471914,
-    -6.072500,4.617188,4.981094,
-    -6.217675,4.494141,5.144727,
-    -6.217675,4.494141,5.144727,
-    -3.917371,4.494141,6.684522,
-    -3.847676,4.617188,6.471914,
-    -3.917371,4.494141,6.684522,
-    -6.217675,4.494141,5.144727,
-    -6.395312,4.125000,5.325000,
-    -6.395312,4.125000,5.325000,
-    -4.014062,4.125000,6.918750,
-    -3.917371,4.494141,6.684522,
-    -6.209063,4.125000,4.970000,
-    -7.591093,4.125000,2.747500,
-    -7.464421,4.494141,2.709170,
-    -7.464421,4.494141,2.709170,
-    -6.042168,4.494141,4.900664,
-    -6.209063,4.125000,4.970000,
-    -6.042168,4.494141,4.900664,
-    -7.464421,4.494141,2.709170,
-    -7.550137,4.617188,2.753633,
-    -7.550137,4.617188,2.753633,
-    -6.072500,4.617188,4.981094,
-    -6.042168,4.494141,4.900664,
-    -6.072500,4.617188,4.981094,
-    -7.550137,4.617188,2.753633,
-    -7.755822,4.494141,2.844092,
-    -7.755822,4.494141,2.844092,
-    -6.217675,4.494141,5.144727,
-    -6.072500,4.617188,4.981094,
-    -6.217675,4.494141,5.144727,
-    -7.755822,4.494141,2.844092,
-    -7.989062,4.125000,2.943750,
-    -7.989062,4.125000,2.943750,
-    -6.395312,4.125000,5.325000,
-    -6.217675,4.494141,5.144727,
-    -7.591093,4.125000,2.747500,
-    -8.070313,4.125000,0.000000,
-    -7.972656,4.494141,0.000000,
-    -7.972656,4.494141,0.000000,
-    -7.464421,4.494141,2.709170,
-    -7.591093,4.125000,2.747500,
-    -7.464421,4.494141,2.709170,
-    -7.972656,4.494141,0.000000,
-    -8.085938,4.617188,0.000000,
-    -8.085938,4.617188,0.000000,
-    -7.550137,4.617188,2.753633,
-    -7.464421,4.494141,2.709170,
-    -7.550137,4.617188,2.753633,
-    -8.085938,4.617188,0.000000,
-    -8.316406,4.494141,0.000000,
-    -8.316406,4.494141,0.000000,
-    -7.755822,4.494141,2.844092,
-    -7.550137,4.617188,2.753633,
-    -7.755822,4.494141,2.844092,
-    -8.316406,4.494141,0.000000,
-    -8.570313,4.125000,0.000000,
-    -8.570313,4.125000,0.000000,
-    -7.989062,4.125000,2.943750,
-    -7.755822,4.494141,2.844092,
-    -8.070313,4.125000,0.000000,
-    -7.527812,4.125000,-2.747500,
-    -7.437724,4.494141,-2.709170,
-    -7.437724,4.494141,-2.709170,
-    -7.972656,4.494141,0.000000,
-    -8.070313,4.125000,0.000000,
-    -7.972656,4.494141,0.000000,
-    -7.437724,4.494141,-2.709170,
-    -7.542227,4.617188,-2.753633,
-    -7.542227,4.617188,-2.753633,
-    -8.085938,4.617188,0.000000,
-    -7.972656,4.494141,0.000000,
-    -8.085938,4.617188,0.000000,
-    -7.542227,4.617188,-2.753633,
-    -7.754834,4.494141,-2.844092,
-    -7.754834,4.494141,-2.844092,
-    -8.316406,4.494141,0.000000,
-    -8.085938,4.617188,0.000000,
-    -8.316406,4.494141,0.000000,
-    -7.754834,4.494141,-2.844092,
-    -7.989062,4.125000,-2.943750,
-    -7.989062,4.125000,-2.943750,
-    -8.570313,4.125000,0.000000,
-    -8.316406,4.494141,0.000000,
-    -7.527812,4.125000,-2.747500,
-    -6.040312,4.125000,-4.970000,
-    -5.970977,4.494141,-4.900664,
-    -5.970977,4.494141,-4.900664,
-    -7.437724,4.494141,-2.709170,
-    -7.527812,4.125000,-2.747500,
-    -7.437724,4.494141,-2.709170,
-    -5.970977,4.494141,-4.900664,
-    -6.051406,4.617188,-4.981094,
-    -6.051406,4.617188,-4.981094,
-    -7.542227,4.617188,-2.753633,
-    -7.437724,4.494141,-2.709170,
-    -7.542227,4.617188,-2.753633,
-    -6.051406,4.617188,-4.981094,
-    -6.215039,4.494141,-5.144727,
-    -6.215039,4.494141,-5.144727,
-    -7.754834,4.494141,-2.844092,
-    -7.542227,4.617188,-2.753633,
-    -7.754834,4.494141,-2.844092,
-    -6.215039,4.494141,-5.144727,
-    -6.395312,4.125000,-5.325000,
-    -6.395312,4.125000,-5.325000,
-    -7.989062,4.125000,-2.943750,
-    -7.754834,4.494141,-2.844092,
-    -6.040312,4.125000,-4.970000,
-    -3.817812,4.125000,-6.457500,
-    -3.779482,4.494141,-6.367412,
-    -3.779482,4.494141,-6.367412,
-    -5.970977,4.494141,-4.900664,
-    -6.040312,4.125000,-4.970000,
-    -5.970977,4.494141,-4.900664,
-    -3.779482,4.494141,-6.367412,
-    -3.823945,4.617188,-6.471914,
-    -3.823945,4.617188,-6.471914,
-    -6.051406,4.617188,-4.981094,
-    -5.970977,4.494141,-4.900664,
-    -6.051406,4.617188,-4.981094,
-    -3.823945,4.617188,-6.471914,
-    -3.914404,4.494141,-6.684522,
-    -3.914404,4.494141,-6.684522,
-    -6.215039,4.494141,-5.144727,
-    -6.051406,4.617188,-4.981094,
-    -6.215039,4.494141,-5.144727,
-    -3.914404,4.494141,-6.684522,
-    -4.014062,4.125000,-6.918750,
-    -4.014062,4.125000,-6.918750,
-    -6.395312,4.125000,-5.325000,
-    -6.215039,4.494141,-5.144727,
-    -3.817812,4.125000,-6.457500,
-    -1.070312,4.125000,-7.000000,
-    -1.070312,4.494141,-6.902344,
-    -1.070312,4.494141,-6.902344,
-    -3.779482,4.494141,-6.367412,
-    -3.817812,4.125000,-6.457500,
-    -3.779482,4.494141,-6.367412,
-    -1.070312,4.494141,-6.902344,
-    -1.070312,4.617188,-7.015625,
-    -1.070312,4.617188,-7.015625,
-    -3.823945,4.617188,-6.471914,
-    -3.779482,4.494141,-6.367412,
-    -3.823945,4.617188,-6.471914,
-    -1.070312,4.617188,-7.015625,
-    -1.070312,4.494141,-7.246094,
-    -1.070312,4.494141,-7.246094,
-    -3.914404,4.494141,-6.684522,
-    -3.823945,4.617188,-6.471914,
-    -3.914404,4.494141,-6.684522,
-    -1.070312,4.494141,-7.246094,
-    -1.070312,4.125000,-7.500000,
-    -1.070312,4.125000,-7.500000,
-    -4.014062,4.125000,-6.918750,
-    -3.914404,4.494141,-6.684522,
-    -1.070312,4.125000,-7.000000,
-    1.677188,4.125000,-6.457500,
-    1.638858,4.494141,-6.367412,
-    1.638858,4.494141,-6.367412,
-    -1.070312,4.494141,-6.902344,
-    -1.070312,4.125000,-7.000000,
-    -1.070312,4.494141,-6.902344,
-    1.638858,4.494141,-6.367412,
-    1.683320,4.617188,-6.471914,
-    1.683320,4.617188,-6.471914,
-    -1.070312,4.617188,-7.015625,
-    -1.070312,4.494141,-6.902344,
-    -1.070312,4.617188,-7.015625,
-    1.683320,4.617188,-6.471914,
-    1.773780,4.494141,-6.684522,
-    1.773780,4.494141,-6.684522,
-    -1.070312,4.494141,-7.246094,
-    -1.070312,4.617188,-7.015625,
-    -1.070312,4.494141,-7.246094,
-    1.773780,4.494141,-6.684522,
-    1.873438,4.125000,-6.918750,
-    1.873438,4.125000,-6.918750,
-    -1.070312,4.125000,-7.500000,
-    -1.070312,4.494141,-7.246094,
-    1.677188,4.125000,-6.457500,
-    3.899688,4.125000,-4.970000,
-    3.830352,4.494141,-4.900664,
-    3.830352,4.494141,-4.900664,
-    1.638858,4.494141,-6.367412,
-    1.677188,4.125000,-6.457500,
-    1.638858,4.494141,-6.367412,
-    3.830352,4.494141,-4.900664,
-    3.910782,4.617188,-4.981094,
-    3.910782,4.617188,-4.981094,
-    1.683320,4.617188,-6.471914,
-    1.638858,4.494141,-6.367412,
-    1.683320,4.617188,-6.471914,
-    3.910782,4.617188,-4.981094,
-    4.074414,4.494141,-5.144727,
-    4.074414,4.494141,-5.144727,
-    1.773780,4.494141,-6.684522,
-    1.683320,4.617188,-6.471914,
-    1.773780,4.494141,-6.684522,
-    4.074414,4.494141,-5.144727,
-    4.254687,4.125000,-5.325000,
-    4.254687,4.125000,-5.325000,
-    1.873438,4.125000,-6.918750,
-    1.773780,4.494141,-6.684522,
-    3.899688,4.125000,-4.970000,
-    5.387188,4.125000,-2.747500,
-    5.297100,4.494141,-2.709170,
-    5.297100,4.494141,-2.709170,
-    3.830352,4.494141,-4.900664,
-    3.899688,4.125000,-4.970000,
-    3.830352,4.494141,-4.900664,
-    5.297100,4.494141,-2.709170,
-    5.401602,4.617188,-2.753633,
-    5.401602,4.617188,-2.753633,
-    3.910782,4.617188,-4.981094,
-    3.830352,4.494141,-4.900664,
-    3.910782,4.617188,-4.981094,
-    5.401602,4.617188,-2.753633,
-    5.614209,4.494141,-2.844092,
-    5.614209,4.494141,-2.844092,
-    4.074414,4.494141,-5.144727,
-    3.910782,4.617188,-4.981094,
-    4.074414,4.494141,-5.144727,
-    5.614209,4.494141,-2.844092,
-    5.848437,4.125000,-2.943750,
-    5.848437,4.125000,-2.943750,
-    4.254687,4.125000,-5.325000,
-    4.074414,4.494141,-5.144727,
-    5.387188,4.125000,-2.747500,
-    5.929688,4.125000,0.000000,
-    5.832031,4.494141,0.000000,
-    5.832031,4.494141,0.000000,
-    5.297100,4.494141,-2.709170,
-    5.387188,4.125000,-2.747500,
-    5.297100,4.494141,-2.709170,
-    5.832031,4.494141,0.000000,
-    5.945313,4.617188,0.000000,
-    5.945313,4.617188,0.000000,
-    5.401602,4.617188,-2.753633,
-    5.297100,4.494141,-2.709170,
-    5.401602,4.617188,-2.753633,
-    5.945313,4.617188,0.000000,
-    6.175781,4.494141,0.000000,
-    6.175781,4.494141,0.000000,
-    5.614209,4.494141,-2.844092,
-    5.401602,4.617188,-2.753633,
-    5.614209,4.494141,-2.844092,
-    6.175781,4.494141,0.000000,
-    6.429688,4.125000,0.000000,
-    6.429688,4.125000,0.000000,
-    5.848437,4.125000,-2.943750,
-    5.614209,4.494141,-2.844092,
-    6.429688,4.125000,0.000000,
-    5.848437,4.125000,2.943750,
-    6.695264,2.162109,3.304053,
-    6.695264,2.162109,3.304053,
-    7.347656,2.162109,0.000000,
-    6.429688,4.125000,0.000000,
-    7.347656,2.162109,0.000000,
-    6.695264,2.162109,3.304053,
-    7.433985,0.234375,3.618360,
-    7.433985,0.234375,3.618360,
-    8.148438,0.234375,0.000000,
-    7.347656,2.162109,0.000000,
-    8.148438,0.234375,0.000000,
-    7.433985,0.234375,3.618360,
-    7.956494,-1.623047,3.840674,
-    7.956494,-1.623047,3.840674,
-    8.714844,-1.623047,0.000000,
-    8.148438,0.234375,0.000000,
-    8.714844,-1.623047,0.000000,
-    7.956494,-1.623047,3.840674,
-    8.154688,-3.375000,3.925000,
-    8.154688,-3.375000,3.925000,
-    8.929688,-3.375000,0.000000,
-    8.714844,-1.623047,0.000000,
-    5.848437,4.125000,2.943750,
-    4.254687,4.125000,5.325000,
-    4.906446,2.162109,5.976758,
-    4.906446,2.162109,5.976758,
-    6.695264,2.162109,3.304053,
-    5.848437,4.125000,2.943750,
-    6.695264,2.162109,3.304053,
-    4.906446,2.162109,5.976758,
-    5.475000,0.234375,6.545312,
-    5.475000,0.234375,6.545312,
-    7.433985,0.234375,3.618360,
-    6.695264,2.162109,3.304053,
-    7.433985,0.234375,3.618360,
-    5.475000,0.234375,6.545312,
-    5.877149,-1.623047,6.947461,
-    5.877149,-1.623047,6.947461,
-    7.956494,-1.623047,3.840674,
-    7.433985,0.234375,3.618360,
-    7.956494,-1.623047,3.840674,
-    5.877149,-1.623047,6.947461,
-    6.029688,-3.375000,7.100000,
-    6.029688,-3.375000,7.100000,
-    8.154688,-3.375000,3.925000,
-    7.956494,-1.623047,3.840674,
-    4.254687,4.125000,5.325000,
-    1.873438,4.125000,6.918750,
-    2.233740,2.162109,7.765576,
-    2.233740,2.162109,7.765576,
-    4.906446,2.162109,5.976758,
-    4.254687,4.125000,5.325000,
-    4.906446,2.162109,5.976758,
-    2.233740,2.162109,7.765576,
-    2.548047,0.234375,8.504297,
-    2.548047,0.234375,8.504297,
-    5.475000,0.234375,6.545312,
-    4.906446,2.162109,5.976758,
-    5.475000,0.234375,6.545312,
-    2.548047,0.234375,8.504297,
-    2.770362,-1.623047,9.026807,
-    2.770362,-1.623047,9.026807,
-    5.877149,-1.623047,6.947461,
-    5.475000,0.234375,6.545312,
-    5.877149,-1.623047,6.947461,
-    2.770362,-1.623047,9.026807,
-    2.854688,-3.375000,9.225000,
-    2.854688,-3.375000,9.225000,
-    6.029688,-3.375000,7.100000,
-    5.877149,-1.623047,6.947461,
-    1.873438,4.125000,6.918750,
-    -1.070312,4.125000,7.500000,
-    -1.070312,2.162109,8.417969,
-    -1.070312,2.162109,8.417969,
-    2.233740,2.162109,7.765576,
-    1.873438,4.125000,6.918750,
-    2.233740,2.162109,7.765576,
-    -1.070312,2.162109,8.417969,
-    -1.070312,0.234375,9.218750,
-    -1.070312,0.234375,9.218750,
-    2.548047,0.234375,8.504297,
-    2.233740,2.162109,7.765576,
-    2.548047,0.234375,8.504297,
-    -1.070312,0.234375,9.218750,
-    -1.070312,-1.623047,9.785156,
-    -1.070312,-1.623047,9.785156,
-    2.770362,-1.623047,9.026807,
-    2.548047,0.234375,8.504297,
-    2.770362,-1.623047,9.026807,
-    -1.070312,-1.623047,9.785156,
-    -1.070312,-3.375000,10.000000,
-    -1.070312,-3.375000,10.000000,
-    2.854688,-3.375000,9.225000,
-    2.770362,-1.623047,9.026807,
-    -1.070312,4.125000,7.500000,
-    -4.014062,4.125000,6.918750,
-    -4.374365,2.162109,7.765576,
-    -4.374365,2.162109,7.765576,
-    -1.070312,2.162109,8.417969,
-    -1.070312,4.125000,7.500000,
-    -1.070312,2.162109,8.417969,
-    -4.374365,2.162109,7.765576,
-    -4.688672,0.234375,8.504297,
-    -4.688672,0.234375,8.504297,
-    -1.070312,0.234375,9.218750,
-    -1.070312,2.162109,8.417969,
-    -1.070312,0.234375,9.218750,
-    -4.688672,0.234375,8.504297,
-    -4.910986,-1.623047,9.026807,
-    -4.910986,-1.623047,9.026807,
-    -1.070312,-1.623047,9.785156,
-    -1.070312,0.234375,9.218750,
-    -1.070312,-1.623047,9.785156,
-    -4.910986,-1.623047,9.026807,
-    -4.995313,-3.375000,9.225000,
-    -4.995313,-3.375000,9.225000,
-    -1.070312,-3.375000,10.000000,
-    -1.070312,-1.623047,9.785156,
-    -4.014062,4.125000,6.918750,
-    -6.395312,4.125000,5.325000,
-    -7.047071,2.162109,5.976758,
-    -7.047071,2.162109,5.976758,
-    -4.374365,2.162109,7.765576,
-    -4.014062,4.125000,6.918750,
-    -4.374365,2.162109,7.765576,
-    -7.047071,2.162109,5.976758,
-    -7.615624,0.234375,6.545312,
-    -7.615624,0.234375,6.545312,
-    -4.688672,0.234375,8.504297,
-    -4.374365,2.162109,7.765576,
-    -4.688672,0.234375,8.504297,
-    -7.615624,0.234375,6.545312,
-    -8.017773,-1.623047,6.947461,
-    -8.017773,-1.623047,6.947461,
-    -4.910986,-1.623047,9.026807,
-    -4.688672,0.234375,8.504297,
-    -4.910986,-1.623047,9.026807,
-    -8.017773,-1.623047,6.947461,
-    -8.170312,-3.375000,7.100000,
-    -8.170312,-3.375000,7.100000,
-    -4.995313,-3.375000,9.225000,
-    -4.910986,-1.623047,9.026807,
-    -6.395312,4.125000,5.325000,
-    -7.989062,4.125000,2.943750,
-    -8.835889,2.162109,3.304053,
-    -8.835889,2.162109,3.304053,
-    -7.047071,2.162109,5.976758,
-    -6.395312,4.125000,5.325000,
-    -7.047071,2.162109,5.976758,
-    -8.835889,2.162109,3.304053,
-    -9.574610,0.234375,3.618360,
-    -9.574610,0.234375,3.618360,
-    -7.615624,0.234375,6.545312,
-    -7.047071,2.162109,5.976758,
-    -7.615624,0.234375,6.545312,
-    -9.574610,0.234375,3.618360,
-    -10.097119,-1.623047,3.840674,
-    -10.097119,-1.623047,3.840674,
-    -8.017773,-1.623047,6.947461,
-    -7.615624,0.234375,6.545312,
-    -8.017773,-1.623047,6.947461,
-    -10.097119,-1.623047,3.840674,
-    -10.295313,-3.375000,3.925000,
-    -10.295313,-3.375000,3.925000,
-    -8.170312,-3.375000,7.100000,
-    -8.017773,-1.623047,6.947461,
-    -7.989062,4.125000,2.943750,
-    -8.570313,4.125000,0.000000,
-    -9.488281,2.162109,0.000000,
-    -9.488281,2.162109,0.000000,
-    -8.835889,2.162109,3.304053,
-    -7.989062,4.125000,2.943750,
-    -8.835889,2.162109,3.304053,
-    -9.488281,2.162109,0.000000,
-    -10.289063,0.234375,0.000000,
-    -10.289063,0.234375,0.000000,
-    -9.574610,0.234375,3.618360,
-    -8.835889,2.162109,3.304053,
-    -9.574610,0.234375,3.618360,
-    -10.289063,0.234375,0.000000,
-    -10.855469,-1.623047,0.000000,
-    -10.855469,-1.623047,0.000000,
-    -10.097119,-1.623047,3.840674,
-    -9.574610,0.234375,3.618360,
-    -10.097119,-1.623047,3.840674,
-    -10.855469,-1.623047,0.000000,
-    -11.070313,-3.375000,0.000000,
-    -11.070313,-3.375000,0.000000,
-    -10.295313,-3.375000,3.925000,
-    -10.097119,-1.623047,3.840674,
-    -8.570313,4.125000,0.000000,
-    -7.989062,4.125000,-2.943750,
-    -8.835889,2.162109,-3.304053,
-    -8.835889,2.162109,-3.304053,
-    -9.488281,2.162109,0.000000,
-    -8.570313,4.125000,0.000000,
-    -9.488281,2.162109,0.000000,
-    -8.835889,2.162109,-3.304053,
-    -9.574610,0.234375,-3.618360,
-    -9.574610,0.234375,-3.618360,
-    -10.289063,0.234375,0.000000,
-    -9.488281,2.162109,0.000000,
-    -10.289063,0.234375,0.000000,
-    -9.574610,0.234375,-3.618360,
-    -10.097119,-1.623047,-3.840674,
-    -10.097119,-1.623047,-3.840674,
-    -10.855469,-1.623047,0.000000,
-    -10.289063,0.234375,0.000000,
-    -10.855469,-1.623047,0.000000,
-    -10.097119,-1.623047,-3.840674,
-    -10.295313,-3.375000,-3.925000,
-    -10.295313,-3.375000,-3.925000,
-    -11.070313,-3.375000,0.000000,
-    -10.855469,-1.623047,0.000000,
-    -7.989062,4.125000,-2.943750,
-    -6.395312,4.125000,-5.325000,
-    -7.047071,2.162109,-5.976758,
-    -7.047071,2.162109,-5.976758,
-    -8.835889,2.162109,-3.304053,
-    -7.989062,4.125000,-2.943750,
-    -8.835889,2.162109,-3.304053,
-    -7.047071,2.162109,-5.976758,
-    -7.615624,0.234375,-6.545312,
-    -7.615624,0.234375,-6.545312,
-    -9.574610,0.234375,-3.618360,
-    -8.835889,2.162109,-3.304053,
-    -9.574610,0.234375,-3.618360,
-    -7.615624,0.234375,-6.545312,
-    -8.017773,-1.623047,-6.947461,
-    -8.017773,-1.623047,-6.947461,
-    -10.097119,-1.623047,-3.840674,
-    -9.574610,0.234375,-3.618360,
-    -10.097119,-1.623047,-3.840674,
-    -8.017773,-1.623047,-6.947461,
-    -8.170312,-3.375000,-7.100000,
-    -8.170312,-3.375000,-7.100000,
-    -10.295313,-3.375000,-3.925000,
-    -10.097119,-1.623047,-3.840674,
-    -6.395312,4.125000,-5.325000,
-    -4.014062,4.125000,-6.918750,
-    -4.374365,2.162109,-7.765576,
-    -4.374365,2.162109,-7.765576,
-    -7.047071,2.162109,-5.976758,
-    -6.395312,4.125000,-5.325000,
-    -7.047071,2.162109,-5.976758,
-    -4.374365,2.162109,-7.765576,
-    -4.688672,0.234375,-8.504297,
-    -4.688672,0.234375,-8.504297,
-    -7.615624,0.234375,-6.545312,
-    -7.047071,2.162109,-5.976758,
-    -7.615624,0.234375,-6.545312,
-    -4.688672,0.234375,-8.504297,
-    -4.910986,-1.623047,-9.026807,
-    -4.910986,-1.623047,-9.026807,
-    -8.017773,-1.623047,-6.947461,
-    -7.615624,0.234375,-6.545312,
-    -8.017773,-1.623047,-6.947461,
-    -4.910986,-1.623047,-9.026807,
-    -4.995313,-3.375000,-9.225000,
-    -4.995313,-3.375000,-9.225000,
-    -8.170312,-3.375000,-7.100000,
-    -8.017773,-1.623047,-6.947461,
-    -4.014062,4.125000,-6.918750,
-    -1.070312,4.125000,-7.500000,
-    -1.070312,2.162109,-8.417969,
-    -1.070312,2.162109,-8.417969,
-    -4.374365,2.162109,-7.765576,
-    -4.014062,4.125000,-6.918750,
-    -4.374365,2.162109,-7.765576,
-    -1.070312,2.162109,-8.417969,
-    -1.070312,0.234375,-9.218750,
-    -1.070312,0.234375,-9.218750,
-    -4.688672,0.234375,-8.504297,
-    -4.374365,2.162109,-7.765576,
-    -4.688672,0.234375,-8.504297,
-    -1.070312,0.234375,-9.218750,
-    -1.070312,-1.623047,-9.785156,
-    -1.070312,-1.623047,-9.785156,
-    -4.910986,-1.623047,-9.026807,
-    -4.688672,0.234375,-8.504297,
-    -4.910986,-1.623047,-9.026807,
-    -1.070312,-1.623047,-9.785156,
-    -1.070312,-3.375000,-10.000000,
-    -1.070312,-3.375000,-10.000000,
-    -4.995313,-3.375000,-9.225000,
-    -4.910986,-1.623047,-9.026807,
-    -1.070312,4.125000,-7.500000,
-    1.873438,4.125000,-6.918750,
-    2.233740,2.162109,-7.765576,
-    2.233740,2.162109,-7.765576,
-    -1.070312,2.162109,-8.417969,
-    -1.070312,4.125000,-7.500000,
-    -1.070312,2.162109,-8.417969,
-    2.233740,2.162109,-7.765576,
-    2.548047,0.234375,-8.504297,
-    2.548047,0.234375,-8.504297,
-    -1.070312,0.234375,-9.218750,
-    -1.070312,2.162109,-8.417969,
-    -1.070312,0.234375,-9.218750,
-    2.548047,0.234375,-8.504297,
-    2.770362,-1.623047,-9.026807,
-    2.770362,-1.623047,-9.026807,
-    -1.070312,-1.623047,-9.785156,
-    -1.070312,0.234375,-9.218750,
-    -1.070312,-1.623047,-9.785156,
-    2.770362,-1.623047,-9.026807,
-    2.854688,-3.375000,-9.225000,
-    2.854688,-3.375000,-9.225000,
-    -1.070312,-3.375000,-10.000000,
-    -1.070312,-1.623047,-9.785156,
-    1.873438,4.125000,-6.918750,
-    4.254687,4.125000,-5.325000,
-    4.906446,2.162109,-5.976758,
-    4.906446,2.162109,-5.976758,
-    2.233740,2.162109,-7.765576,
-    1.873438,4.125000,-6.918750,
-    2.233740,2.162109,-7.765576,
-    4.906446,2.162109,-5.976758,
-    5.475000,0.234375,-6.545312,
-    5.475000,0.234375,-6.545312,
-    2.548047,0.234375,-8.504297,
-    2.233740,2.162109,-7.765576,
-    2.548047,0.234375,-8.504297,
-    5.475000,0.234375,-6.545312,
-    5.877149,-1.623047,-6.947461,
-    5.877149,-1.623047,-6.947461,
-    2.770362,-1.623047,-9.026807,
-    2.548047,0.234375,-8.504297,
-    2.770362,-1.623047,-9.026807,
-    5.877149,-1.623047,-6.947461,
-    6.029688,-3.375000,-7.100000,
-    6.029688,-3.375000,-7.100000,
-    2.854688,-3.375000,-9.225000,
-    2.770362,-1.623047,-9.026807,
-    4.254687,4.125000,-5.325000,
-    5.848437,4.125000,-2.943750,
-    6.695264,2.162109,-3.304053,
-    6.695264,2.162109,-3.304053,
-    4.906446,2.162109,-5.976758,
-    4.254687,4.125000,-5.325000,
-    4.906446,2.162109,-5.976758,
-    6.695264,2.162109,-3.304053,
-    7.433985,0.234375,-3.618360,
-    7.433985,0.234375,-3.618360,
-    5.475000,0.234375,-6.545312,
-    4.906446,2.162109,-5.976758,
-    5.475000,0.234375,-6.545312,
-    7.433985,0.234375,-3.618360,
-    7.956494,-1.623047,-3.840674,
-    7.956494,-1.623047,-3.840674,
-    5.877149,-1.623047,-6.947461,
-    5.475000,0.234375,-6.545312,
-    5.877149,-1.623047,-6.947461,
-    7.956494,-1.623047,-3.840674,
-    8.154688,-3.375000,-3.925000,
-    8.154688,-3.375000,-3.925000,
-    6.029688,-3.375000,-7.100000,
-    5.877149,-1.623047,-6.947461,
-    5.848437,4.125000,-2.943750,
-    6.429688,4.125000,0.000000,
-    7.347656,2.162109,0.000000,
-    7.347656,2.162109,0.000000,
-    6.695264,2.162109,-3.304053,
-    5.848437,4.125000,-2.943750,
-    6.695264,2.162109,-3.304053,
-    7.347656,2.162109,0.000000,
-    8.148438,0.234375,0.000000,
-    8.148438,0.234375,0.000000,
-    7.433985,0.234375,-3.618360,
-    6.695264,2.162109,-3.304053,
-    7.433985,0.234375,-3.618360,
-    8.148438,0.234375,0.000000,
-    8.714844,-1.623047,0.000000,
-    8.714844,-1.623047,0.000000,
-    7.956494,-1.623047,-3.840674,
-    7.433985,0.234375,-3.618360,
-    7.956494,-1.623047,-3.840674,
-    8.714844,-1.623047,0.000000,
-    8.929688,-3.375000,0.000000,
-    8.929688,-3.375000,0.000000,
-    8.154688,-3.375000,-3.925000,
-    7.956494,-1.623047,-3.840674,
-    8.929688,-3.375000,0.000000,
-    8.154688,-3.375000,3.925000,
-    7.794336,-4.857422,3.771680,
-    7.794336,-4.857422,3.771680,
-    8.539063,-4.857422,0.000000,
-    8.929688,-3.375000,0.000000,
-    8.539063,-4.857422,0.000000,
-    7.794336,-4.857422,3.771680,
-    7.001562,-5.953125,3.434375,
-    7.001562,-5.953125,3.434375,
-    7.679688,-5.953125,0.000000,
-    8.539063,-4.857422,0.000000,
-    7.679688,-5.953125,0.000000,
-    7.001562,-5.953125,3.434375,
-    6.208789,-6.697266,3.097070,
-    6.208789,-6.697266,3.097070,
-    6.820313,-6.697266,0.000000,
-    7.679688,-5.953125,0.000000,
-    6.820313,-6.697266,0.000000,
-    6.208789,-6.697266,3.097070,
-    5.848437,-7.125000,2.943750,
-    5.848437,-7.125000,2.943750,
-    6.429688,-7.125000,0.000000,
-    6.820313,-6.697266,0.000000,
-    8.154688,-3.375000,3.925000,
-    6.029688,-3.375000,7.100000,
-    5.752343,-4.857422,6.822656,
-    5.752343,-4.857422,6.822656,
-    7.794336,-4.857422,3.771680,
-    8.154688,-3.375000,3.925000,
-    7.794336,-4.857422,3.771680,
-    5.752343,-4.857422,6.822656,
-    5.142187,-5.953125,6.212500,
-    5.142187,-5.953125,6.212500,
-    7.001562,-5.953125,3.434375,
-    7.794336,-4.857422,3.771680,
-    7.001562,-5.953125,3.434375,
-    5.142187,-5.953125,6.212500,
-    4.532031,-6.697266,5.602344,
-    4.532031,-6.697266,5.602344,
-    6.208789,-6.697266,3.097070,
-    7.001562,-5.953125,3.434375,
-    6.208789,-6.697266,3.097070,
-    4.532031,-6.697266,5.602344,
-    4.254687,-7.125000,5.325000,
-    4.254687,-7.125000,5.325000,
-    5.848437,-7.125000,2.943750,
-    6.208789,-6.697266,3.097070,
-    6.029688,-3.375000,7.100000,
-    2.854688,-3.375000,9.225000,
-    2.701367,-4.857422,8.864649,
-    2.701367,-4.857422,8.864649,
-    5.752343,-4.857422,6.822656,
-    6.029688,-3.375000,7.100000,
-    5.752343,-4.857422,6.822656,
-    2.701367,-4.857422,8.864649,
-    2.364063,-5.953125,8.071875,
-    2.364063,-5.953125,8.071875,
-    5.142187,-5.953125,6.212500,
-    5.752343,-4.857422,6.822656,
-    5.142187,-5.953125,6.212500,
-    2.364063,-5.953125,8.071875,
-    2.026758,-6.697266,7.279101,
-    2.026758,-6.697266,7.279101,
-    4.532031,-6.697266,5.602344,
-    5.142187,-5.953125,6.212500,
-    4.532031,-6.697266,5.602344,
-    2.026758,-6.697266,7.279101,
-    1.873438,-7.125000,6.918750,
-    1.873438,-7.125000,6.918750,
-    4.254687,-7.125000,5.325000,
-    4.532031,-6.697266,5.602344,
-    2.854688,-3.375000,9.225000,
-    -1.070312,-3.375000,10.000000,
-    -1.070312,-4.857422,9.609375,
-    -1.070312,-4.857422,9.609375,
-    2.701367,-4.857422,8.864649,
-    2.854688,-3.375000,9.225000,
-    2.701367,-4.857422,8.864649,
-    -1.070312,-4.857422,9.609375,
-    -1.070312,-5.953125,8.750000,
-    -1.070312,-5.953125,8.750000,
-    2.364063,-5.953125,8.071875,
-    2.701367,-4.857422,8.864649,
-    2.364063,-5.953125,8.071875,
-    -1.070312,-5.953125,8.750000,
-    -1.070312,-6.697266,7.890625,
-    -1.070312,-6.697266,7.890625,
-    2.026758,-6.697266,7.279101,
-    2.364063,-5.953125,8.071875,
-    2.026758,-6.697266,7.279101,
-    -1.070312,-6.697266,7.890625,
-    -1.070312,-7.125000,7.500000,
-    -1.070312,-7.125000,7.500000,
-    1.873438,-7.125000,6.918750,
-    2.026758,-6.697266,7.279101,
-    -1.070312,-3.375000,10.000000,
-    -4.995313,-3.375000,9.225000,
-    -4.841992,-4.857422,8.864649,
-    -4.841992,-4.857422,8.864649,
-    -1.070312,-4.857422,9.609375,
-    -1.070312,-3.375000,10.000000,
-    -1.070312,-4.857422,9.609375,
-    -4.841992,-4.857422,8.864649,
-    -4.504687,-5.953125,8.071875,
-    -4.504687,-5.953125,8.071875,
-    -1.070312,-5.953125,8.750000,
-    -1.070312,-4.857422,9.609375,
-    -1.070312,-5.953125,8.750000,
-    -4.504687,-5.953125,8.071875,
-    -4.167383,-6.697266,7.279101,
-    -4.167383,-6.697266,7.279101,
-    -1.070312,-6.697266,7.890625,
-    -1.070312,-5.953125,8.750000,
-    -1.070312,-6.697266,7.890625,
-    -4.167383,-6.697266,7.279101,
-    -4.014062,-7.125000,6.918750,
-    -4.014062,-7.125000,6.918750,
-    -1.070312,-7.125000,7.500000,
-    -1.070312,-6.697266,7.890625,
-    -4.995313,-3.375000,9.225000,
-    -8.170312,-3.375000,7.100000,
-    -7.892968,-4.857422,6.822656,
-    -7.892968,-4.857422,6.822656,
-    -4.841992,-4.857422,8.864649,
-    -4.995313,-3.375000,9.225000,
-    -4.841992,-4.857422,8.864649,
-    -7.892968,-4.857422,6.822656,
-    -7.282812,-5.953125,6.212500,
-    -7.282812,-5.953125,6.212500,
-    -4.504687,-5.953125,8.071875,
-    -4.841992,-4.857422,8.864649,
-    -4.504687,-5.953125,8.071875,
-    -7.282812,-5.953125,6.212500,
-    -6.672656,-6.697266,5.602344,
-    -6.672656,-6.697266,5.602344,
-    -4.167383,-6.697266,7.279101,
-    -4.504687,-5.953125,8.071875,
-    -4.167383,-6.697266,7.279101,
-    -6.672656,-6.697266,5.602344,
-    -6.395312,-7.125000,5.325000,
-    -6.395312,-7.125000,5.325000,
-    -4.014062,-7.125000,6.918750,
-    -4.167383,-6.697266,7.279101,
-    -8.170312,-3.375000,7.100000,
-    -10.295313,-3.375000,3.925000,
-    -9.934961,-4.857422,3.771680,
-    -9.934961,-4.857422,3.771680,
-    -7.892968,-4.857422,6.822656,
-    -8.170312,-3.375000,7.100000,
-    -7.892968,-4.857422,6.822656,
-    -9.934961,-4.857422,3.771680,
-    -9.142187,-5.953125,3.434375,
-    -9.142187,-5.953125,3.434375,
-    -7.282812,-5.953125,6.212500,
-    -7.892968,-4.857422,6.822656,
-    -7.282812,-5.953125,6.212500,
-    -9.142187,-5.953125,3.434375,
-    -8.349414,-6.697266,3.097070,
-    -8.349414,-6.697266,3.097070,
-    -6.672656,-6.697266,5.602344,
-    -7.282812,-5.953125,6.212500,
-    -6.672656,-6.697266,5.602344,
-    -8.349414,-6.697266,3.097070,
-    -7.989062,-7.125000,2.943750,
-    -7.989062,-7.125000,2.943750,
-    -6.395312,-7.125000,5.325000,
-    -6.672656,-6.697266,5.602344,
-    -10.295313,-3.375000,3.925000,
-    -11.070313,-3.375000,0.000000,
-    -10.679688,-4.857422,0.000000,
-    -10.679688,-4.857422,0.000000,
-    -9.934961,-4.857422,3.771680,
-    -10.295313,-3.375000,3.925000,
-    -9.934961,-4.857422,3.771680,
-    -10.679688,-4.857422,0.000000,
-    -9.820313,-5.953125,0.000000,
-    -9.820313,-5.953125,0.000000,
-    -9.142187,-5.953125,3.434375,
-    -9.934961,-4.857422,3.771680,
-    -9.142187,-5.953125,3.434375,
-    -9.820313,-5.953125,0.000000,
-    -8.960938,-6.697266,0.000000,
-    -8.960938,-6.697266,0.000000,
-    -8.349414,-6.697266,3.097070,
-    -9.142187,-5.953125,3.434375,
-    -8.349414,-6.697266,3.097070,
-    -8.960938,-6.697266,0.000000,
-    -8.570313,-7.125000,0.000000,
-    -8.570313,-7.125000,0.000000,
-    -7.989062,-7.125000,2.943750,
-    -8.349414,-6.697266,3.097070,
-    -11.070313,-3.375000,0.000000,
-    -10.295313,-3.375000,-3.925000,
-    -9.934961,-4.857422,-3.771680,
-    -9.934961,-4.857422,-3.771680,
-    -10.679688,-4.857422,0.000000,
-    -11.070313,-3.375000,0.000000,
-    -10.679688,-4.857422,0.000000,
-    -9.934961,-4.857422,-3.771680,
-    -9.142187,-5.953125,-3.434375,
-    -9.142187,-5.953125,-3.434375,
-    -9.820313,-5.953125,0.000000,
-    -10.679688,-4.857422,0.000000,
-    -9.820313,-5.953125,0.000000,
-    -9.142187,-5.953125,-3.434375,
-    -8.349414,-6.697266,-3.097070,
-    -8.349414,-6.697266,-3.097070,
-    -8.960938,-6.697266,0.000000,
-    -9.820313,-5.953125,0.000000,
-    -8.960938,-6.697266,0.000000,
-    -8.349414,-6.697266,-3.097070,
-    -7.989062,-7.125000,-2.943750,
-    -7.989062,-7.125000,-2.943750,
-    -8.570313,-7.125000,0.000000,
-    -8.960938,-6.697266,0.000000,
-    -10.295313,-3.375000,-3.925000,
-    -8.170312,-3.375000,-7.100000,
-    -7.892968,-4.857422,-6.822656,
-    -7.892968,-4.857422,-6.822656,
-    -9.934961,-4.857422,-3.771680,
-    -10.295313,-3.375000,-3.925000,
-    -9.934961,-4.857422,-3.771680,
-    -7.892968,-4.857422,-6.822656,
-    -7.282812,-5.953125,-6.212500,
-    -7.282812,-5.953125,-6.212500,
-    -9.142187,-5.953125,-3.434375,
-    -9.934961,-4.857422,-3.771680,
-    -9.142187,-5.953125,-3.434375,
-    -7.282812,-5.953125,-6.212500,
-    -6.672656,-6.697266,-5.602344,
-    -6.672656,-6.697266,-5.602344,
-    -8.349414,-6.697266,-3.097070,
-    -9.142187,-5.953125,-3.434375,
-    -8.349414,-6.697266,-3.097070,
-    -6.672656,-6.697266,-5.602344,
-    -6.395312,-7.125000,-5.325000,
-    -6.395312,-7.125000,-5.325000,
-    -7.989062,-7.125000,-2.943750,
-    -8.349414,-6.697266,-3.097070,
-    -8.170312,-3.375000,-7.100000,
-    -4.995313,-3.375000,-9.225000,
-    -4.841992,-4.857422,-8.864649,
-    -4.841992,-4.857422,-8.864649,
-    -7.892968,-4.857422,-6.822656,
-    -8.170312,-3.375000,-7.100000,
-    -7.892968,-4.857422,-6.822656,
-    -4.841992,-4.857422,-8.864649,
-    -4.504687,-5.953125,-8.071875,
-    -4.504687,-5.953125,-8.071875,
-    -7.282812,-5.953125,-6.212500,
-    -7.892968,-4.857422,-6.822656,
-    -7.282812,-5.953125,-6.212500,
-    -4.504687,-5.953125,-8.071875,
-    -4.167383,-6.697266,-7.279101,
-    -4.167383,-6.697266,-7.279101,
-    -6.672656,-6.697266,-5.602344,
-    -7.282812,-5.953125,-6.212500,
-    -6.672656,-6.697266,-5.602344,
-    -4.167383,-6.697266,-7.279101,
-    -4.014062,-7.125000,-6.918750,
-    -4.014062,-7.125000,-6.918750,
-    -6.395312,-7.125000,-5.325000,
-    -6.672656,-6.697266,-5.602344,
-    -4.995313,-3.375000,-9.225000,
-    -1.070312,-3.375000,-10.000000,
-    -1.070312,-4.857422,-9.609375,
-    -1.070312,-4.857422,-9.609375,
-    -4.841992,-4.857422,-8.864649,
-    -4.995313,-3.375000,-9.225000,
-    -4.841992,-4.857422,-8.864649,
-    -1.070312,-4.857422,-9.609375,
-    -1.070312,-5.953125,-8.750000,
-    -1.070312,-5.953125,-8.750000,
-    -4.504687,-5.953125,-8.071875,
-    -4.841992,-4.857422,-8.864649,
-    -4.504687,-5.953125,-8.071875,
-    -1.070312,-5.953125,-8.750000,
-    -1.070312,-6.697266,-7.890625,
-    -1.070312,-6.697266,-7.890625,
-    -4.167383,-6.697266,-7.279101,
-    -4.504687,-5.953125,-8.071875,
-    -4.167383,-6.697266,-7.279101,
-    -1.070312,-6.697266,-7.890625,
-    -1.070312,-7.125000,-7.500000,
-    -1.070312,-7.125000,-7.500000,
-    -4.014062,-7.125000,-6.918750,
-    -4.167383,-6.697266,-7.279101,
-    -1.070312,-3.375000,-10.000000,
-    2.854688,-3.375000,-9.225000,
-    2.701367,-4.857422,-8.864649,
-    2.701367,-4.857422,-8.864649,
-    -1.070312,-4.857422,-9.609375,
-    -1.070312,-3.375000,-10.000000,
-    -1.070312,-4.857422,-9.609375,
-    2.701367,-4.857422,-8.864649,
-    2.364063,-5.953125,-8.071875,
-    2.364063,-5.953125,-8.071875,
-    -1.070312,-5.953125,-8.750000,
-    -1.070312,-4.857422,-9.609375,
-    -1.070312,-5.953125,-8.750000,
-    2.364063,-5.953125,-8.071875,
-    2.026758,-6.697266,-7.279101,
-    2.026758,-6.697266,-7.279101,
-    -1.070312,-6.697266,-7.890625,
-    -1.070312,-5.953125,-8.750000,
-    -1.070312,-6.697266,-7.890625,
-    2.026758,-6.697266,-7.279101,
-    1.873438,-7.125000,-6.918750,
-    1.873438,-7.125000,-6.918750,
-    -1.070312,-7.125000,-7.500000,
-    -1.070312,-6.697266,-7.890625,
-    2.854688,-3.375000,-9.225000,
-    6.029688,-3.375000,-7.100000,
-    5.752343,-4.857422,-6.822656,
-    5.752343,-4.857422,-6.822656,
-    2.701367,-4.857422,-8.864649,
-    2.854688,-3.375000,-9.225000,
-    2.701367,-4.857422,-8.864649,
-    5.752343,-4.857422,-6.822656,
-    5.142187,-5.953125,-6.212500,
-    5.142187,-5.953125,-6.212500,
-    2.364063,-5.953125,-8.071875,
-    2.701367,-4.857422,-8.864649,
-    2.364063,-5.953125,-8.071875,
-    5.142187,-5.953125,-6.212500,
-    4.532031,-6.697266,-5.602344,
-    4.532031,-6.697266,-5.602344,
-    2.026758,-6.697266,-7.279101,
-    2.364063,-5.953125,-8.071875,
-    2.026758,-6.697266,-7.279101,
-    4.532031,-6.697266,-5.602344,
-    4.254687,-7.125000,-5.325000,
-    4.254687,-7.125000,-5.325000,
-    1.873438,-7.125000,-6.918750,
-    2.026758,-6.697266,-7.279101,
-    6.029688,-3.375000,-7.100000,
-    8.154688,-3.375000,-3.925000,
-    7.794336,-4.857422,-3.771680,
-    7.794336,-4.857422,-3.771680,
-    5.752343,-4.857422,-6.822656,
-    6.029688,-3.375000,-7.100000,
-    5.752343,-4.857422,-6.822656,
-    7.794336,-4.857422,-3.771680,
-    7.001562,-5.953125,-3.434375,
-    7.001562,-5.953125,-3.434375,
-    5.142187,-5.953125,-6.212500,
-    5.752343,-4.857422,-6.822656,
-    5.142187,-5.953125,-6.212500,
-    7.001562,-5.953125,-3.434375,
-    6.208789,-6.697266,-3.097070,
-    6.208789,-6.697266,-3.097070,
-    4.532031,-6.697266,-5.602344,
-    5.142187,-5.953125,-6.212500,
-    4.532031,-6.697266,-5.602344,
-    6.208789,-6.697266,-3.097070,
-    5.848437,-7.125000,-2.943750,
-    5.848437,-7.125000,-2.943750,
-    4.254687,-7.125000,-5.325000,
-    4.532031,-6.697266,-5.602344,
-    8.154688,-3.375000,-3.925000,
-    8.929688,-3.375000,0.000000,
-    8.539063,-4.857422,0.000000,
-    8.539063,-4.857422,0.000000,
-    7.794336,-4.857422,-3.771680,
-    8.154688,-3.375000,-3.925000,
-    7.794336,-4.857422,-3.771680,
-    8.539063,-4.857422,0.000000,
-    7.679688,-5.953125,0.000000,
-    7.679688,-5.953125,0.000000,
-    7.001562,-5.953125,-3.434375,
-    7.794336,-4.857422,-3.771680,
-    7.001562,-5.953125,-3.434375,
-    7.679688,-5.953125,0.000000,
-    6.820313,-6.697266,0.000000,
-    6.820313,-6.697266,0.000000,
-    6.208789,-6.697266,-3.097070,
-    7.001562,-5.953125,-3.434375,
-    6.208789,-6.697266,-3.097070,
-    6.820313,-6.697266,0.000000,
-    6.429688,-7.125000,0.000000,
-    6.429688,-7.125000,0.000000,
-    5.848437,-7.125000,-2.943750,
-    6.208789,-6.697266,-3.097070,
-    6.429688,-7.125000,0.000000,
-    5.848437,-7.125000,2.943750,
-    5.691685,-7.400391,2.877056,
-    5.691685,-7.400391,2.877056,
-    6.259766,-7.400391,0.000000,
-    6.429688,-7.125000,0.000000,
-    6.259766,-7.400391,0.000000,
-    5.691685,-7.400391,2.877056,
-    4.853868,-7.640625,2.520586,
-    4.853868,-7.640625,2.520586,
-    5.351563,-7.640625,0.000000,
-    6.259766,-7.400391,0.000000,
-    5.351563,-7.640625,0.000000,
-    4.853868,-7.640625,2.520586,
-    2.783648,-7.810547,1.639761,
-    2.783648,-7.810547,1.639761,
-    3.107422,-7.810547,0.000000,
-    5.351563,-7.640625,0.000000,
-    3.107422,-7.810547,0.000000,
-    2.783648,-7.810547,1.639761,
-    -1.070312,-7.875000,0.000000,
-    5.848437,-7.125000,2.943750,
-    4.254687,-7.125000,5.325000,
-    4.134043,-7.400391,5.204355,
-    4.134043,-7.400391,5.204355,
-    5.691685,-7.400391,2.877056,
-    5.848437,-7.125000,2.943750,
-    5.691685,-7.400391,2.877056,
-    4.134043,-7.400391,5.204355,
-    3.489219,-7.640625,4.559531,
-    3.489219,-7.640625,4.559531,
-    4.853868,-7.640625,2.520586,
-    5.691685,-7.400391,2.877056,
-    4.853868,-7.640625,2.520586,
-    3.489219,-7.640625,4.559531,
-    1.895879,-7.810547,2.966191,
-    1.895879,-7.810547,2.966191,
-    2.783648,-7.810547,1.639761,
-    4.853868,-7.640625,2.520586,
-    2.783648,-7.810547,1.639761,
-    1.895879,-7.810547,2.966191,
-    -1.070312,-7.875000,0.000000,
-    4.254687,-7.125000,5.325000,
-    1.873438,-7.125000,6.918750,
-    1.806743,-7.400391,6.761997,
-    1.806743,-7.400391,6.761997,
-    4.134043,-7.400391,5.204355,
-    4.254687,-7.125000,5.325000,
-    4.134043,-7.400391,5.204355,
-    1.806743,-7.400391,6.761997,
-    1.450274,-7.640625,5.924180,
-    1.450274,-7.640625,5.924180,
-    3.489219,-7.640625,4.559531,
-    4.134043,-7.400391,5.204355,
-    3.489219,-7.640625,4.559531,
-    1.450274,-7.640625,5.924180,
-    0.569448,-7.810547,3.853960,
-    0.569448,-7.810547,3.853960,
-    1.895879,-7.810547,2.966191,
-    3.489219,-7.640625,4.559531,
-    1.895879,-7.810547,2.966191,
-    0.569448,-7.810547,3.853960,
-    -1.070312,-7.875000,0.000000,
-    1.873438,-7.125000,6.918750,
-    -1.070312,-7.125000,7.500000,
-    -1.070312,-7.400391,7.330078,
-    -1.070312,-7.400391,7.330078,
-    1.806743,-7.400391,6.761997,
-    1.873438,-7.125000,6.918750,
-    1.806743,-7.400391,6.761997,
-    -1.070312,-7.400391,7.330078,
-    -1.070312,-7.640625,6.421875,
-    -1.070312,-7.640625,6.421875,
-    1.450274,-7.640625,5.924180,
-    1.806743,-7.400391,6.761997,
-    1.450274,-7.640625,5.924180,
-    -1.070312,-7.640625,6.421875,
-    -1.070312,-7.810547,4.177734,
-    -1.070312,-7.810547,4.177734,
-    0.569448,-7.810547,3.853960,
-    1.450274,-7.640625,5.924180,
-    0.569448,-7.810547,3.853960,
-    -1.070312,-7.810547,4.177734,
-    -1.070312,-7.875000,0.000000,
-    -1.070312,-7.125000,7.500000,
-    -4.014062,-7.125000,6.918750,
-    -3.947368,-7.400391,6.761997,
-    -3.947368,-7.400391,6.761997,
-    -1.070312,-7.400391,7.330078,
-    -1.070312,-7.125000,7.500000,
-    -1.070312,-7.400391,7.330078,
-    -3.947368,-7.400391,6.761997,
-    -3.590898,-7.640625,5.924180,
-    -3.590898,-7.640625,5.924180,
-    -1.070312,-7.640625,6.421875,
-    -1.070312,-7.400391,7.330078,
-    -1.070312,-7.640625,6.421875,
-    -3.590898,-7.640625,5.924180,
-    -2.710073,-7.810547,3.853960,
-    -2.710073,-7.810547,3.853960,
-    -1.070312,-7.810547,4.177734,
-    -1.070312,-7.640625,6.421875,
-    -1.070312,-7.810547,4.177734,
-    -2.710073,-7.810547,3.853960,
-    -1.070312,-7.875000,0.000000,
-    -4.014062,-7.125000,6.918750,
-    -6.395312,-7.125000,5.325000,
-    -6.274668,-7.400391,5.204355,
-    -6.274668,-7.400391,5.204355,
-    -3.947368,-7.400391,6.761997,
-    -4.014062,-7.125000,6.918750,
-    -3.947368,-7.400391,6.761997,
-    -6.274668,-7.400391,5.204355,
-    -5.629844,-7.640625,4.559531,
-    -5.629844,-7.640625,4.559531,
-    -3.590898,-7.640625,5.924180,
-    -3.947368,-7.400391,6.761997,
-    -3.590898,-7.640625,5.924180,
-    -5.629844,-7.640625,4.559531,
-    -4.036504,-7.810547,2.966191,
-    -4.036504,-7.810547,2.966191,
-    -2.710073,-7.810547,3.853960,
-    -3.590898,-7.640625,5.924180,
-    -2.710073,-7.810547,3.853960,
-    -4.036504,-7.810547,2.966191,
-    -1.070312,-7.875000,0.000000,
-    -6.395312,-7.125000,5.325000,
-    -7.989062,-7.125000,2.943750,
-    -7.832309,-7.400391,2.877056,
-    -7.832309,-7.400391,2.877056,
-    -6.274668,-7.400391,5.204355,
-    -6.395312,-7.125000,5.325000,
-    -6.274668,-7.400391,5.204355,
-    -7.832309,-7.400391,2.877056,
-    -6.994492,-7.640625,2.520586,
-    -6.994492,-7.640625,2.520586,
-    -5.629844,-7.640625,4.559531,
-    -6.274668,-7.400391,5.204355,
-    -5.629844,-7.640625,4.559531,
-    -6.994492,-7.640625,2.520586,
-    -4.924272,-7.810547,1.639761,
-    -4.924272,-7.810547,1.639761,
-    -4.036504,-7.810547,2.966191,
-    -5.629844,-7.640625,4.559531,
-    -4.036504,-7.810547,2.966191,
-    -4.924272,-7.810547,1.639761,
-    -1.070312,-7.875000,0.000000,
-    -7.989062,-7.125000,2.943750,
-    -8.570313,-7.125000,0.000000,
-    -8.400391,-7.400391,0.000000,
-    -8.400391,-7.400391,0.000000,
-    -7.832309,-7.400391,2.877056,
-    -7.989062,-7.125000,2.943750,
-    -7.832309,-7.400391,2.877056,
-    -8.400391,-7.400391,0.000000,
-    -7.492188,-7.640625,0.000000,
-    -7.492188,-7.640625,0.000000,
-    -6.994492,-7.640625,2.520586,
-    -7.832309,-7.400391,2.877056,
-    -6.994492,-7.640625,2.520586,
-    -7.492188,-7.640625,0.000000,
-    -5.248047,-7.810547,0.000000,
-    -5.248047,-7.810547,0.000000,
-    -4.924272,-7.810547,1.639761,
-    -6.994492,-7.640625,2.520586,
-    -4.924272,-7.810547,1.639761,
-    -5.248047,-7.810547,0.000000,
-    -1.070312,-7.875000,0.000000,
-    -8.570313,-7.125000,0.000000,
-    -7.989062,-7.125000,-2.943750,
-    -7.832309,-7.400391,-2.877056,
-    -7.832309,-7.400391,-2.877056,
-    -8.400391,-7.400391,0.000000,
-    -8.570313,-7.125000,0.000000,
-    -8.400391,-7.400391,0.000000,
-    -7.832309,-7.400391,-2.877056,
-    -6.994492,-7.640625,-2.520586,
-    -6.994492,-7.640625,-2.520586,
-    -7.492188,-7.640625,0.000000,
-    -8.400391,-7.400391,0.000000,
-    -7.492188,-7.640625,0.000000,
-    -6.994492,-7.640625,-2.520586,
-    -4.924272,-7.810547,-1.639761,
-    -4.924272,-7.810547,-1.639761,
-    -5.248047,-7.810547,0.000000,
-    -7.492188,-7.640625,0.000000,
-    -5.248047,-7.810547,0.000000,
-    -4.924272,-7.810547,-1.639761,
-    -1.070312,-7.875000,0.000000,
-    -7.989062,-7.125000,-2.943750,
-    -6.395312,-7.125000,-5.325000,
-    -6.274668,-7.400391,-5.204355,
-    -6.274668,-7.400391,-5.204355,
-    -7.832309,-7.400391,-2.877056,
-    -7.989062,-7.125000,-2.943750,
-    -7.832309,-7.400391,-2.877056,
-    -6.274668,-7.400391,-5.204355,
-    -5.629844,-7.640625,-4.559531,
-    -5.629844,-7.640625,-4.559531,
-    -6.994492,-7.640625,-2.520586,
-    -7.832309,-7.400391,-2.877056,
-    -6.994492,-7.640625,-2.520586,
-    -5.629844,-7.640625,-4.559531,
-    -4.036504,-7.810547,-2.966191,
-    -4.036504,-7.810547,-2.966191,
-    -4.924272,-7.810547,-1.639761,
-    -6.994492,-7.640625,-2.520586,
-    -4.924272,-7.810547,-1.639761,
-    -4.036504,-7.810547,-2.966191,
-    -1.070312,-7.875000,0.000000,
-    -6.395312,-7.125000,-5.325000,
-    -4.014062,-7.125000,-6.918750,
-    -3.947368,-7.400391,-6.761997,
-    -3.947368,-7.400391,-6.761997,
-    -6.274668,-7.400391,-5.204355,
-    -6.395312,-7.125000,-5.325000,
-    -6.274668,-7.400391,-5.204355,
-    -3.947368,-7.400391,-6.761997,
-    -3.590898,-7.640625,-5.924180,
-    -3.590898,-7.640625,-5.924180,
-    -5.629844,-7.640625,-4.559531,
-    -6.274668,-7.400391,-5.204355,
-    -5.629844,-7.640625,-4.559531,
-    -3.590898,-7.640625,-5.924180,
-    -2.710073,-7.810547,-3.853960,
-    -2.710073,-7.810547,-3.853960,
-    -4.036504,-7.810547,-2.966191,
-    -5.629844,-7.640625,-4.559531,
-    -4.036504,-7.810547,-2.966191,
-    -2.710073,-7.810547,-3.853960,
-    -1.070312,-7.875000,0.000000,
-    -4.014062,-7.125000,-6.918750,
-    -1.070312,-7.125000,-7.500000,
-    -1.070312,-7.400391,-7.330078,
-    -1.070312,-7.400391,-7.330078,
-    -3.947368,-7.400391,-6.761997,
-    -4.014062,-7.125000,-6.918750,
-    -3.947368,-7.400391,-6.761997,
-    -1.070312,-7.400391,-7.330078,
-    -1.070312,-7.640625,-6.421875,
-    -1.070312,-7.640625,-6.421875,
-    -3.590898,-7.640625,-5.924180,
-    -3.947368,-7.400391,-6.761997,
-    -3.590898,-7.640625,-5.924180,
-    -1.070312,-7.640625,-6.421875,
-    -1.070312,-7.810547,-4.177734,
-    -1.070312,-7.810547,-4.177734,
-    -2.710073,-7.810547,-3.853960,
-    -3.590898,-7.640625,-5.924180,
-    -2.710073,-7.810547,-3.853960,
-    -1.070312,-7.810547,-4.177734,
-    -1.070312,-7.875000,0.000000,
-    -1.070312,-7.125000,-7.500000,
-    1.873438,-7.125000,-6.918750,
-    1.806743,-7.400391,-6.761997,
-    1.806743,-7.400391,-6.761997,
-    -1.070312,-7.400391,-7.330078,
-    -1.070312,-7.125000,-7.500000,
-    -1.070312,-7.400391,-7.330078,
-    1.806743,-7.400391,-6.761997,
-    1.450274,-7.640625,-5.924180,
-    1.450274,-7.640625,-5.924180,
-    -1.070312,-7.640625,-6.421875,
-    -1.070312,-7.400391,-7.330078,
-    -1.070312,-7.640625,-6.421875,
-    1.450274,-7.640625,-5.924180,
-    0.569448,-7.810547,-3.853960,
-    0.569448,-7.810547,-3.853960,
-    -1.070312,-7.810547,-4.177734,
-    -1.070312,-7.640625,-6.421875,
-    -1.070312,-7.810547,-4.177734,
-    0.569448,-7.810547,-3.853960,
-    -1.070312,-7.875000,0.000000,
-    1.873438,-7.125000,-6.918750,
-    4.254687,-7.125000,-5.325000,
-    4.134043,-7.400391,-5.204355,
-    4.134043,-7.400391,-5.204355,
-    1.806743,-7.400391,-6.761997,
-    1.873438,-7.125000,-6.918750,
-    1.806743,-7.400391,-6.761997,
-    4.134043,-7.400391,-5.204355,
-    3.489219,-7.640625,-4.559531,
-    3.489219,-7.640625,-4.559531,
-    1.450274,-7.640625,-5.924180,
-    1.806743,-7.400391,-6.761997,
-    1.450274,-7.640625,-5.924180,
-    3.489219,-7.640625,-4.559531,
-    1.895879,-7.810547,-2.966191,
-    1.895879,-7.810547,-2.966191,
-    0.569448,-7.810547,-3.853960,
-    1.450274,-7.640625,-5.924180,
-    0.569448,-7.810547,-3.853960,
-    1.895879,-7.810547,-2.966191,
-    -1.070312,-7.875000,0.000000,
-    4.254687,-7.125000,-5.325000,
-    5.848437,-7.125000,-2.943750,
-    5.691685,-7.400391,-2.877056,
-    5.691685,-7.400391,-2.877056,
-    4.134043,-7.400391,-5.204355,
-    4.254687,-7.125000,-5.325000,
-    4.134043,-7.400391,-5.204355,
-    5.691685,-7.400391,-2.877056,
-    4.853868,-7.640625,-2.520586,
-    4.853868,-7.640625,-2.520586,
-    3.489219,-7.640625,-4.559531,
-    4.134043,-7.400391,-5.204355,
-    3.489219,-7.640625,-4.559531,
-    4.853868,-7.640625,-2.520586,
-    2.783648,-7.810547,-1.639761,
-    2.783648,-7.810547,-1.639761,
-    1.895879,-7.810547,-2.966191,
-    3.489219,-7.640625,-4.559531,
-    1.895879,-7.810547,-2.966191,
-    2.783648,-7.810547,-1.639761,
-    -1.070312,-7.875000,0.000000,
-    5.848437,-7.125000,-2.943750,
-    6.429688,-7.125000,0.000000,
-    6.259766,-7.400391,0.000000,
-    6.259766,-7.400391,0.000000,
-    5.691685,-7.400391,-2.877056,
-    5.848437,-7.125000,-2.943750,
-    5.691685,-7.400391,-2.877056,
-    6.259766,-7.400391,0.000000,
-    5.351563,-7.640625,0.000000,
-    5.351563,-7.640625,0.000000,
-    4.853868,-7.640625,-2.520586,
-    5.691685,-7.400391,-2.877056,
-    4.853868,-7.640625,-2.520586,
-    5.351563,-7.640625,0.000000,
-    3.107422,-7.810547,0.000000,
-    3.107422,-7.810547,0.000000,
-    2.783648,-7.810547,-1.639761,
-    4.853868,-7.640625,-2.520586,
-    2.783648,-7.810547,-1.639761,
-    3.107422,-7.810547,0.000000,
-    -1.070312,-7.875000,0.000000,
-    -9.070313,2.250000,0.000000,
-    -8.992188,2.425781,0.843750,
-    -11.475830,2.405457,0.843750,
-    -11.475830,2.405457,0.843750,
-    -11.406250,2.232422,0.000000,
-    -9.070313,2.250000,0.000000,
-    -11.406250,2.232422,0.000000,
-    -11.475830,2.405457,0.843750,
-    -13.298828,2.263184,0.843750,
-    -13.298828,2.263184,0.843750,
-    -13.132813,2.109375,0.000000,
-    -11.406250,2.232422,0.000000,
-    -13.132813,2.109375,0.000000,
-    -13.298828,2.263184,0.843750,
-    -14.421631,1.877014,0.843750,
-    -14.421631,1.877014,0.843750,
-    -14.203125,1.775391,0.000000,
-    -13.132813,2.109375,0.000000,
-    -14.203125,1.775391,0.000000,
-    -14.421631,1.877014,0.843750,
-    -14.804688,1.125000,0.843750,
-    -14.804688,1.125000,0.843750,
-    -14.570313,1.125000,0.000000,
-    -14.203125,1.775391,0.000000,
-    -8.992188,2.425781,0.843750,
-    -8.820313,2.812500,1.125000,
-    -11.628906,2.786134,1.125000,
-    -11.628906,2.786134,1.125000,
-    -11.475830,2.405457,0.843750,
-    -8.992188,2.425781,0.843750,
-    -11.475830,2.405457,0.843750,
-    -11.628906,2.786134,1.125000,
-    -13.664063,2.601563,1.125000,
-    -13.664063,2.601563,1.125000,
-    -13.298828,2.263184,0.843750,
-    -11.475830,2.405457,0.843750,
-    -13.298828,2.263184,0.843750,
-    -13.664063,2.601563,1.125000,
-    -14.902344,2.100586,1.125000,
-    -14.902344,2.100586,1.125000,
-    -14.421631,1.877014,0.843750,
-    -13.298828,2.263184,0.843750,
-    -14.421631,1.877014,0.843750,
-    -14.902344,2.100586,1.125000,
-    -15.320313,1.125000,1.125000,
-    -15.320313,1.125000,1.125000,
-    -14.804688,1.125000,0.843750,
-    -14.421631,1.877014,0.843750,
-    -8.820313,2.812500,1.125000,
-    -8.648438,3.199219,0.843750,
-    -11.781982,3.166809,0.843750,
-    -11.781982,3.166809,0.843750,
-    -11.628906,2.786134,1.125000,
-    -8.820313,2.812500,1.125000,
-    -11.628906,2.786134,1.125000,
-    -11.781982,3.166809,0.843750,
-    -14.029297,2.939941,0.843750,
-    -14.029297,2.939941,0.843750,
-    -13.664063,2.601563,1.125000,
-    -11.628906,2.786134,1.125000,
-    -13.664063,2.601563,1.125000,
-    -14.029297,2.939941,0.843750,
-    -15.383057,2.324158,0.843750,
-    -15.383057,2.324158,0.843750,
-    -14.902344,2.100586,1.125000,
-    -13.664063,2.601563,1.125000,
-    -14.902344,2.100586,1.125000,
-    -15.383057,2.324158,0.843750,
-    -15.835938,1.125000,0.843750,
-    -15.835938,1.125000,0.843750,
-    -15.320313,1.125000,1.125000,
-    -14.902344,2.100586,1.125000,
-    -8.648438,3.199219,0.843750,
-    -8.570313,3.375000,0.000000,
-    -11.851563,3.339844,0.000000,
-    -11.851563,3.339844,0.000000,
-    -11.781982,3.166809,0.843750,
-    -8.648438,3.199219,0.843750,
-    -11.781982,3.166809,0.843750,
-    -11.851563,3.339844,0.000000,
-    -14.195313,3.093750,0.000000,
-    -14.195313,3.093750,0.000000,
-    -14.029297,2.939941,0.843750,
-    -11.781982,3.166809,0.843750,
-    -14.029297,2.939941,0.843750,
-    -14.195313,3.093750,0.000000,
-    -15.601563,2.425781,0.000000,
-    -15.601563,2.425781,0.000000,
-    -15.383057,2.324158,0.843750,
-    -14.029297,2.939941,0.843750,
-    -15.383057,2.324158,0.843750,
-    -15.601563,2.425781,0.000000,
-    -16.070313,1.125000,0.000000,
-    -16.070313,1.125000,0.000000,
-    -15.835938,1.125000,0.843750,
-    -15.383057,2.324158,0.843750,
-    -8.570313,3.375000,0.000000,
-    -8.648438,3.199219,-0.843750,
-    -11.781982,3.166809,-0.843750,
-    -11.781982,3.166809,-0.843750,
-    -11.851563,3.339844,0.000000,
-    -8.570313,3.375000,0.000000,
-    -11.851563,3.339844,0.000000,
-    -11.781982,3.166809,-0.843750,
-    -14.029297,2.939941,-0.843750,
-    -14.029297,2.939941,-0.843750,
-    -14.195313,3.093750,0.000000,
-    -11.851563,3.339844,0.000000,
-    -14.195313,3.093750,0.000000,
-    -14.029297,2.939941,-0.843750,
-    -15.383057,2.324158,-0.843750,
-    -15.383057,2.324158,-0.843750,
-    -15.601563,2.425781,0.000000,
-    -14.195313,3.093750,0.000000,
-    -15.601563,2.425781,0.000000,
-    -15.383057,2.324158,-0.843750,
-    -15.835938,1.125000,-0.843750,
-    -15.835938,1.125000,-0.843750,
-    -16.070313,1.125000,0.000000,
-    -15.601563,2.425781,0.000000,
-    -8.648438,3.199219,-0.843750,
-    -8.820313,2.812500,-1.125000,
-    -11.628906,2.786134,-1.125000,
-    -11.628906,2.786134,-1.125000,
-    -11.781982,3.166809,-0.843750,
-    -8.648438,3.199219,-0.843750,
-    -11.781982,3.166809,-0.843750,
-    -11.628906,2.786134,-1.125000,
-    -13.664063,2.601563,-1.125000,
-    -13.664063,2.601563,-1.125000,
-    -14.029297,2.939941,-0.843750,
-    -11.781982,3.166809,-0.843750,
-    -14.029297,2.939941,-0.843750,
-    -13.664063,2.601563,-1.125000,
-    -14.902344,2.100586,-1.125000,
-    -14.902344,2.100586,-1.125000,
-    -15.383057,2.324158,-0.843750,
-    -14.029297,2.939941,-0.843750,
-    -15.383057,2.324158,-0.843750,
-    -14.902344,2.100586,-1.125000,
-    -15.320313,1.125000,-1.125000,
-    -15.320313,1.125000,-1.125000,
-    -15.835938,1.125000,-0.843750,
-    -15.383057,2.324158,-0.843750,
-    -8.820313,2.812500,-1.125000,
-    -8.992188,2.425781,-0.843750,
-    -11.475830,2.405457,-0.843750,
-    -11.475830,2.405457,-0.843750,
-    -11.628906,2.786134,-1.125000,
-    -8.820313,2.812500,-1.125000,
-    -11.628906,2.786134,-1.125000,
-    -11.475830,2.405457,-0.843750,
-    -13.298828,2.263184,-0.843750,
-    -13.298828,2.263184,-0.843750,
-    -13.664063,2.601563,-1.125000,
-    -11.628906,2.786134,-1.125000,
-    -13.664063,2.601563,-1.125000,
-    -13.298828,2.263184,-0.843750,
-    -14.421631,1.877014,-0.843750,
-    -14.421631,1.877014,-0.843750,
-    -14.902344,2.100586,-1.125000,
-    -13.664063,2.601563,-1.125000,
-    -14.902344,2.100586,-1.125000,
-    -14.421631,1.877014,-0.843750,
-    -14.804688,1.125000,-0.843750,
-    -14.804688,1.125000,-0.843750,
-    -15.320313,1.125000,-1.125000,
-    -14.902344,2.100586,-1.125000,
-    -8.992188,2.425781,-0.843750,
-    -9.070313,2.250000,0.000000,
-    -11.406250,2.232422,0.000000,
-    -11.406250,2.232422,0.000000,
-    -11.475830,2.405457,-0.843750,
-    -8.992188,2.425781,-0.843750,
-    -11.475830,2.405457,-0.843750,
-    -11.406250,2.232422,0.000000,
-    -13.132813,2.109375,0.000000,
-    -13.132813,2.109375,0.000000,
-    -13.298828,2.263184,-0.843750,
-    -11.475830,2.405457,-0.843750,
-    -13.298828,2.263184,-0.843750,
-    -13.132813,2.109375,0.000000,
-    -14.203125,1.775391,0.000000,
-    -14.203125,1.775391,0.000000,
-    -14.421631,1.877014,-0.843750,
-    -13.298828,2.263184,-0.843750,
-    -14.421631,1.877014,-0.843750,
-    -14.203125,1.775391,0.000000,
-    -14.570313,1.125000,0.000000,
-    -14.570313,1.125000,0.000000,
-    -14.804688,1.125000,-0.843750,
-    -14.421631,1.877014,-0.843750,
-    -14.570313,1.125000,0.000000,
-    -14.804688,1.125000,0.843750,
-    -14.588013,0.007050,0.843750,
-    -14.588013,0.007050,0.843750,
-    -14.375000,0.105469,0.000000,
-    -14.570313,1.125000,0.000000,
-    -14.375000,0.105469,0.000000,
-    -14.588013,0.007050,0.843750,
-    -13.909180,-1.275146,0.843750,
-    -13.909180,-1.275146,0.843750,
-    -13.757813,-1.125000,0.000000,
-    -14.375000,0.105469,0.000000,
-    -13.757813,-1.125000,0.000000,
-    -13.909180,-1.275146,0.843750,
-    -12.724976,-2.540863,0.843750,
-    -12.724976,-2.540863,0.843750,
-    -12.671875,-2.355469,0.000000,
-    -13.757813,-1.125000,0.000000,
-    -12.671875,-2.355469,0.000000,
-    -12.724976,-2.540863,0.843750,
-    -10.992188,-3.609375,0.843750,
-    -10.992188,-3.609375,0.843750,
-    -11.070313,-3.375000,0.000000,
-    -12.671875,-2.355469,0.000000,
-    -14.804688,1.125000,0.843750,
-    -15.320313,1.125000,1.125000,
-    -15.056641,-0.209473,1.125000,
-    -15.056641,-0.209473,1.125000,
-    -14.588013,0.007050,0.843750,
-    -14.804688,1.125000,0.843750,
-    -14.588013,0.007050,0.843750,
-    -15.056641,-0.209473,1.125000,
-    -14.242188,-1.605469,1.125000,
-    -14.242188,-1.605469,1.125000,
-    -13.909180,-1.275146,0.843750,
-    -14.588013,0.007050,0.843750,
-    -13.909180,-1.275146,0.843750,
-    -14.242188,-1.605469,1.125000,
-    -12.841797,-2.948730,1.125000,
-    -12.841797,-2.948730,1.125000,
-    -12.724976,-2.540863,0.843750,
-    -13.909180,-1.275146,0.843750,
-    -12.724976,-2.540863,0.843750,
-    -12.841797,-2.948730,1.125000,
-    -10.820313,-4.125000,1.125000,
-    -10.820313,-4.125000,1.125000,
-    -10.992188,-3.609375,0.843750,
-    -12.724976,-2.540863,0.843750,
-    -15.320313,1.125000,1.125000,
-    -15.835938,1.125000,0.843750,
-    -15.525269,-0.425995,0.843750,
-    -15.525269,-0.425995,0.843750,
-    -15.056641,-0.209473,1.125000,
-    -15.320313,1.125000,1.125000,
-    -15.056641,-0.209473,1.125000,
-    -15.525269,-0.425995,0.843750,
-    -14.575195,-1.935791,0.843750,
-    -14.575195,-1.935791,0.843750,
-    -14.242188,-1.605469,1.125000,
-    -15.056641,-0.209473,1.125000,
-    -14.242188,-1.605469,1.125000,
-    -14.575195,-1.935791,0.843750,
-    -12.958618,-3.356598,0.843750,
-    -12.958618,-3.356598,0.843750,
-    -12.841797,-2.948730,1.125000,
-    -14.242188,-1.605469,1.125000,
-    -12.841797,-2.948730,1.125000,
-    -12.958618,-3.356598,0.843750,
-    -10.648438,-4.640625,0.843750,
-    -10.648438,-4.640625,0.843750,
-    -10.820313,-4.125000,1.125000,
-    -12.841797,-2.948730,1.125000,
-    -15.835938,1.125000,0.843750,
-    -16.070313,1.125000,0.000000,
-    -15.738281,-0.524414,0.000000,
-    -15.738281,-0.524414,0.000000,
-    -15.525269,-0.425995,0.843750,
-    -15.835938,1.125000,0.843750,
-    -15.525269,-0.425995,0.843750,
-    -15.738281,-0.524414,0.000000,
-    -14.726563,-2.085938,0.000000,
-    -14.726563,-2.085938,0.000000,
-    -14.575195,-1.935791,0.843750,
-    -15.525269,-0.425995,0.843750,
-    -14.575195,-1.935791,0.843750,
-    -14.726563,-2.085938,0.000000,
-    -13.011719,-3.541992,0.000000,
-    -13.011719,-3.541992,0.000000,
-    -12.958618,-3.356598,0.843750,
-    -14.575195,-1.935791,0.843750,
-    -12.958618,-3.356598,0.843750,
-    -13.011719,-3.541992,0.000000,
-    -10.570313,-4.875000,0.000000,
-    -10.570313,-4.875000,0.000000,
-    -10.648438,-4.640625,0.843750,
-    -12.958618,-3.356598,0.843750,
-    -16.070313,1.125000,0.000000,
-    -15.835938,1.125000,-0.843750,
-    -15.525269,-0.425995,-0.843750,
-    -15.525269,-0.425995,-0.843750,
-    -15.738281,-0.524414,0.000000,
-    -16.070313,1.125000,0.000000,
-    -15.738281,-0.524414,0.000000,
-    -15.525269,-0.425995,-0.843750,
-    -14.575195,-1.935791,-0.843750,
-    -14.575195,-1.935791,-0.843750,
-    -14.726563,-2.085938,0.000000,
-    -15.738281,-0.524414,0.000000,
-    -14.726563,-2.085938,0.000000,
-    -14.575195,-1.935791,-0.843750,
-    -12.958618,-3.356598,-0.843750,
-    -12.958618,-3.356598,-0.843750,
-    -13.011719,-3.541992,0.000000,
-    -14.726563,-2.085938,0.000000,
-    -13.011719,-3.541992,0.000000,
-    -12.958618,-3.356598,-0.843750,
-    -10.648438,-4.640625,-0.843750,
-    -10.648438,-4.640625,-0.843750,
-    -10.570313,-4.875000,0.000000,
-    -13.011719,-3.541992,0.000000,
-    -15.835938,1.125000,-0.843750,
-    -15.320313,1.125000,-1.125000,
-    -15.056641,-0.209473,-1.125000,
-    -15.056641,-0.209473,-1.125000,
-    -15.525269,-0.425995,-0.843750,
-    -15.835938,1.125000,-0.843750,
-    -15.525269,-0.425995,-0.843750,
-    -15.056641,-0.209473,-1.125000,
-    -14.242188,-1.605469,-1.125000,
-    -14.242188,-1.605469,-1.125000,
-    -14.575195,-1.935791,-0.843750,
-    -15.525269,-0.425995,-0.843750,
-    -14.575195,-1.935791,-0.843750,
-    -14.242188,-1.605469,-1.125000,
-    -12.841797,-2.948730,-1.125000,
-    -12.841797,-2.948730,-1.125000,
-    -12.958618,-3.356598,-0.843750,
-    -14.575195,-1.935791,-0.843750,
-    -12.958618,-3.356598,-0.843750,
-    -12.841797,-2.948730,-1.125000,
-    -10.820313,-4.125000,-1.125000,
-    -10.820313,-4.125000,-1.125000,
-    -10.648438,-4.640625,-0.843750,
-    -12.958618,-3.356598,-0.843750,
-    -15.320313,1.125000,-1.125000,
-    -14.804688,1.125000,-0.843750,
-    -14.588013,0.007050,-0.843750,
-    -14.588013,0.007050,-0.843750,
-    -15.056641,-0.209473,-1.125000,
-    -15.320313,1.125000,-1.125000,
-    -15.056641,-0.209473,-1.125000,
-    -14.588013,0.007050,-0.843750,
-    -13.909180,-1.275146,-0.843750,
-    -13.909180,-1.275146,-0.843750,
-    -14.242188,-1.605469,-1.125000,
-    -15.056641,-0.209473,-1.125000,
-    -14.242188,-1.605469,-1.125000,
-    -13.909180,-1.275146,-0.843750,
-    -12.724976,-2.540863,-0.843750,
-    -12.724976,-2.540863,-0.843750,
-    -12.841797,-2.948730,-1.125000,
-    -14.242188,-1.605469,-1.125000,
-    -12.841797,-2.948730,-1.125000,
-    -12.724976,-2.540863,-0.843750,
-    -10.992188,-3.609375,-0.843750,
-    -10.992188,-3.609375,-0.843750,
-    -10.820313,-4.125000,-1.125000,
-    -12.841797,-2.948730,-1.125000,
-    -14.804688,1.125000,-0.843750,
-    -14.570313,1.125000,0.000000,
-    -14.375000,0.105469,0.000000,
-    -14.375000,0.105469,0.000000,
-    -14.588013,0.007050,-0.843750,
-    -14.804688,1.125000,-0.843750,
-    -14.588013,0.007050,-0.843750,
-    -14.375000,0.105469,0.000000,
-    -13.757813,-1.125000,0.000000,
-    -13.757813,-1.125000,0.000000,
-    -13.909180,-1.275146,-0.843750,
-    -14.588013,0.007050,-0.843750,
-    -13.909180,-1.275146,-0.843750,
-    -13.757813,-1.125000,0.000000,
-    -12.671875,-2.355469,0.000000,
-    -12.671875,-2.355469,0.000000,
-    -12.724976,-2.540863,-0.843750,
-    -13.909180,-1.275146,-0.843750,
-    -12.724976,-2.540863,-0.843750,
-    -12.671875,-2.355469,0.000000,
-    -11.070313,-3.375000,0.000000,
-    -11.070313,-3.375000,0.000000,
-    -10.992188,-3.609375,-0.843750,
-    -12.724976,-2.540863,-0.843750,
-    7.429688,-0.750000,0.000000,
-    7.429688,-1.394531,1.856250,
-    10.011230,-0.677124,1.676074,
-    10.011230,-0.677124,1.676074,
-    9.828125,-0.199219,0.000000,
-    7.429688,-0.750000,0.000000,
-    9.828125,-0.199219,0.000000,
-    10.011230,-0.677124,1.676074,
-    11.101563,0.846680,1.279688,
-    11.101563,0.846680,1.279688,
-    10.867188,1.125000,0.000000,
-    9.828125,-0.199219,0.000000,
-    10.867188,1.125000,0.000000,
-    11.101563,0.846680,1.279688,
-    11.723145,2.629761,0.883301,
-    11.723145,2.629761,0.883301,
-    11.437500,2.730469,0.000000,
-    10.867188,1.125000,0.000000,
-    11.437500,2.730469,0.000000,
-    11.723145,2.629761,0.883301,
-    12.898438,4.125000,0.703125,
-    12.898438,4.125000,0.703125,
-    12.429688,4.125000,0.000000,
-    11.437500,2.730469,0.000000,
-    7.429688,-1.394531,1.856250,
-    7.429688,-2.812500,2.475000,
-    10.414063,-1.728516,2.234766,
-    10.414063,-1.728516,2.234766,
-    10.011230,-0.677124,1.676074,
-    7.429688,-1.394531,1.856250,
-    10.011230,-0.677124,1.676074,
-    10.414063,-1.728516,2.234766,
-    11.617188,0.234375,1.706250,
-    11.617188,0.234375,1.706250,
-    11.101563,0.846680,1.279688,
-    10.011230,-0.677124,1.676074,
-    11.101563,0.846680,1.279688,
-    11.617188,0.234375,1.706250,
-    12.351563,2.408203,1.177734,
-    12.351563,2.408203,1.177734,
-    11.723145,2.629761,0.883301,
-    11.101563,0.846680,1.279688,
-    11.723145,2.629761,0.883301,
-    12.351563,2.408203,1.177734,
-    13.929688,4.125000,0.937500,
-    13.929688,4.125000,0.937500,
-    12.898438,4.125000,0.703125,
-    11.723145,2.629761,0.883301,
-    7.429688,-2.812500,2.475000,
-    7.429688,-4.230469,1.856250,
-    10.816895,-2.779907,1.676074,
-    10.816895,-2.779907,1.676074,
-    10.414063,-1.728516,2.234766,
-    7.429688,-2.812500,2.475000,
-    10.414063,-1.728516,2.234766,
-    10.816895,-2.779907,1.676074,
-    12.132813,-0.377930,1.279688,
-    12.132813,-0.377930,1.279688,
-    11.617188,0.234375,1.706250,
-    10.414063,-1.728516,2.234766,
-    11.617188,0.234375,1.706250,
-    12.132813,-0.377930,1.279688,
-    12.979980,2.186646,0.883301,
-    12.979980,2.186646,0.883301,
-    12.351563,2.408203,1.177734,
-    11.617188,0.234375,1.706250,
-    12.351563,2.408203,1.177734,
-    12.979980,2.186646,0.883301,
-    14.960938,4.125000,0.703125,
-    14.960938,4.125000,0.703125,
-    13.929688,4.125000,0.937500,
-    12.351563,2.408203,1.177734,
-    7.429688,-4.230469,1.856250,
-    7.429688,-4.875000,0.000000,
-    11.000000,-3.257813,0.000000,
-    11.000000,-3.257813,0.000000,
-    10.816895,-2.779907,1.676074,
-    7.429688,-4.230469,1.856250,
-    10.816895,-2.779907,1.676074,
-    11.000000,-3.257813,0.000000,
-    12.367188,-0.656250,0.000000,
-    12.367188,-0.656250,0.000000,
-    12.132813,-0.377930,1.279688,
-    10.816895,-2.779907,1.676074,
-    12.132813,-0.377930,1.279688,
-    12.367188,-0.656250,0.000000,
-    13.265625,2.085938,0.000000,
-    13.265625,2.085938,0.000000,
-    12.979980,2.186646,0.883301,
-    12.132813,-0.377930,1.279688,
-    12.979980,2.186646,0.883301,
-    13.265625,2.085938,0.000000,
-    15.429688,4.125000,0.000000,
-    15.429688,4.125000,0.000000,
-    14.960938,4.125000,0.703125,
-    12.979980,2.186646,0.883301,
-    7.429688,-4.875000,0.000000,
-    7.429688,-4.230469,-1.856250,
-    10.816895,-2.779907,-1.676074,
-    10.816895,-2.779907,-1.676074,
-    11.000000,-3.257813,0.000000,
-    7.429688,-4.875000,0.000000,
-    11.000000,-3.257813,0.000000,
-    10.816895,-2.779907,-1.676074,
-    12.132813,-0.377930,-1.279688,
-    12.132813,-0.377930,-1.279688,
-    12.367188,-0.656250,0.000000,
-    11.000000,-3.257813,0.000000,
-    12.367188,-0.656250,0.000000,
-    12.132813,-0.377930,-1.279688,
-    12.979980,2.186646,-0.883301,
-    12.979980,2.186646,-0.883301,
-    13.265625,2.085938,0.000000,
-    12.367188,-0.656250,0.000000,
-    13.265625,2.085938,0.000000,
-    12.979980,2.186646,-0.883301,
-    14.960938,4.125000,-0.703125,
-    14.960938,4.125000,-0.703125,
-    15.429688,4.125000,0.000000,
-    13.265625,2.085938,0.000000,
-    7.429688,-4.230469,-1.856250,
-    7.429688,-2.812500,-2.475000,
-    10.414063,-1.728516,-2.234766,
-    10.414063,-1.728516,-2.234766,
-    10.816895,-2.779907,-1.676074,
-    7.429688,-4.230469,-1.856250,
-    10.816895,-2.779907,-1.676074,
-    10.414063,-1.728516,-2.234766,
-    11.617188,0.234375,-1.706250,
-    11.617188,0.234375,-1.706250,
-    12.132813,-0.377930,-1.279688,
-    10.816895,-2.779907,-1.676074,
-    12.132813,-0.377930,-1.279688,
-    11.617188,0.234375,-1.706250,
-    12.351563,2.408203,-1.177734,
-    12.351563,2.408203,-1.177734,
-    12.979980,2.186646,-0.883301,
-    12.132813,-0.377930,-1.279688,
-    12.979980,2.186646,-0.883301,
-    12.351563,2.408203,-1.177734,
-    13.929688,4.125000,-0.937500,
-    13.929688,4.125000,-0.937500,
-    14.960938,4.125000,-0.703125,
-    12.979980,2.186646,-0.883301,
-    7.429688,-2.812500,-2.475000,
-    7.429688,-1.394531,-1.856250,
-    10.011230,-0.677124,-1.676074,
-    10.011230,-0.677124,-1.676074,
-    10.414063,-1.728516,-2.234766,
-    7.429688,-2.812500,-2.475000,
-    10.414063,-1.728516,-2.234766,
-    10.011230,-0.677124,-1.676074,
-    11.101563,0.846680,-1.279688,
-    11.101563,0.846680,-1.279688,
-    11.617188,0.234375,-1.706250,
-    10.414063,-1.728516,-2.234766,
-    11.617188,0.234375,-1.706250,
-    11.101563,0.846680,-1.279688,
-    11.723145,2.629761,-0.883301,
-    11.723145,2.629761,-0.883301,
-    12.351563,2.408203,-1.177734,
-    11.617188,0.234375,-1.706250,
-    12.351563,2.408203,-1.177734,
-    11.723145,2.629761,-0.883301,
-    12.898438,4.125000,-0.703125,
-    12.898438,4.125000,-0.703125,
-    13.929688,4.125000,-0.937500,
-    12.351563,2.408203,-1.177734,
-    7.429688,-1.394531,-1.856250,
-    7.429688,-0.750000,0.000000,
-    9.828125,-0.199219,0.000000,
-    9.828125,-0.199219,0.000000,
-    10.011230,-0.677124,-1.676074,
-    7.429688,-1.394531,-1.856250,
-    10.011230,-0.677124,-1.676074,
-    9.828125,-0.199219,0.000000,
-    10.867188,1.125000,0.000000,
-    10.867188,1.125000,0.000000,
-    11.101563,0.846680,-1.279688,
-    10.011230,-0.677124,-1.676074,
-    11.101563,0.846680,-1.279688,
-    10.867188,1.125000,0.000000,
-    11.437500,2.730469,0.000000,
-    11.437500,2.730469,0.000000,
-    11.723145,2.629761,-0.883301,
-    11.101563,0.846680,-1.279688,
-    11.723145,2.629761,-0.883301,
-    11.437500,2.730469,0.000000,
-    12.429688,4.125000,0.000000,
-    12.429688,4.125000,0.000000,
-    12.898438,4.125000,-0.703125,
-    11.723145,2.629761,-0.883301,
-    12.429688,4.125000,0.000000,
-    12.898438,4.125000,0.703125,
-    13.291077,4.346237,0.659180,
-    13.291077,4.346237,0.659180,
-    12.789063,4.335938,0.000000,
-    12.429688,4.125000,0.000000,
-    12.789063,4.335938,0.000000,
-    13.291077,4.346237,0.659180,
-    13.525879,4.422729,0.562500,
-    13.525879,4.422729,0.562500,
-    13.054688,4.406250,0.000000,
-    12.789063,4.335938,0.000000,
-    13.054688,4.406250,0.000000,
-    13.525879,4.422729,0.562500,
-    13.532898,4.350357,0.465820,
-    13.532898,4.350357,0.465820,
-    13.132813,4.335938,0.000000,
-    13.054688,4.406250,0.000000,
-    13.132813,4.335938,0.000000,
-    13.532898,4.350357,0.465820,
-    13.242188,4.125000,0.421875,
-    13.242188,4.125000,0.421875,
-    12.929688,4.125000,0.000000,
-    13.132813,4.335938,0.000000,
-    12.898438,4.125000,0.703125,
-    13.929688,4.125000,0.937500,
-    14.395508,4.368896,0.878906,
-    14.395508,4.368896,0.878906,
-    13.291077,4.346237,0.659180,
-    12.898438,4.125000,0.703125,
-    13.291077,4.346237,0.659180,
-    14.395508,4.368896,0.878906,
-    14.562500,4.458984,0.750000,
-    14.562500,4.458984,0.750000,
-    13.525879,4.422729,0.562500,
-    13.291077,4.346237,0.659180,
-    13.525879,4.422729,0.562500,
-    14.562500,4.458984,0.750000,
-    14.413086,4.382080,0.621094,
-    14.413086,4.382080,0.621094,
-    13.532898,4.350357,0.465820,
-    13.525879,4.422729,0.562500,
-    13.532898,4.350357,0.465820,
-    14.413086,4.382080,0.621094,
-    13.929688,4.125000,0.562500,
-    13.929688,4.125000,0.562500,
-    13.242188,4.125000,0.421875,
-    13.532898,4.350357,0.465820,
-    13.929688,4.125000,0.937500,
-    14.960938,4.125000,0.703125,
-    15.499939,4.391556,0.659180,
-    15.499939,4.391556,0.659180,
-    14.395508,4.368896,0.878906,
-    13.929688,4.125000,0.937500,
-    14.395508,4.368896,0.878906,
-    15.499939,4.391556,0.659180,
-    15.599121,4.495239,0.562500,
-    15.599121,4.495239,0.562500,
-    14.562500,4.458984,0.750000,
-    14.395508,4.368896,0.878906,
-    14.562500,4.458984,0.750000,
-    15.599121,4.495239,0.562500,
-    15.293274,4.413804,0.465820,
-    15.293274,4.413804,0.465820,
-    14.413086,4.382080,0.621094,
-    14.562500,4.458984,0.750000,
-    14.413086,4.382080,0.621094,
-    15.293274,4.413804,0.465820,
-    14.617188,4.125000,0.421875,
-    14.617188,4.125000,0.421875,
-    13.929688,4.125000,0.562500,
-    14.413086,4.382080,0.621094,
-    14.960938,4.125000,0.703125,
-    15.429688,4.125000,0.000000,
-    16.001953,4.401855,0.000000,
-    16.001953,4.401855,0.000000,
-    15.499939,4.391556,0.659180,
-    14.960938,4.125000,0.703125,
-    15.499939,4.391556,0.659180,
-    16.001953,4.401855,0.000000,
-    16.070313,4.511719,0.000000,
-    16.070313,4.511719,0.000000,
-    15.599121,4.495239,0.562500,
-    15.499939,4.391556,0.659180,
-    15.599121,4.495239,0.562500,
-    16.070313,4.511719,0.000000,
-    15.693359,4.428224,0.000000,
-    15.693359,4.428224,0.000000,
-    15.293274,4.413804,0.465820,
-    15.599121,4.495239,0.562500,
-    15.293274,4.413804,0.465820,
-    15.693359,4.428224,0.000000,
-    14.929688,4.125000,0.000000,
-    14.929688,4.125000,0.000000,
-    14.617188,4.125000,0.421875,
-    15.293274,4.413804,0.465820,
-    15.429688,4.125000,0.000000,
-    14.960938,4.125000,-0.703125,
-    15.499939,4.391556,-0.659180,
-    15.499939,4.391556,-0.659180,
-    16.001953,4.401855,0.000000,
-    15.429688,4.125000,0.000000,
-    16.001953,4.401855,0.000000,
-    15.499939,4.391556,-0.659180,
-    15.599121,4.495239,-0.562500,
-    15.599121,4.495239,-0.562500,
-    16.070313,4.511719,0.000000,
-    16.001953,4.401855,0.000000,
-    16.070313,4.511719,0.000000,
-    15.599121,4.495239,-0.562500,
-    15.293274,4.413804,-0.465820,
-    15.293274,4.413804,-0.465820,
-    15.693359,4.428224,0.000000,
-    16.070313,4.511719,0.000000,
-    15.693359,4.428224,0.000000,
-    15.293274,4.413804,-0.465820,
-    14.617188,4.125000,-0.421875,
-    14.617188,4.125000,-0.421875,
-    14.929688,4.125000,0.000000,
-    15.693359,4.428224,0.000000,
-    14.960938,4.125000,-0.703125,
-    13.929688,4.125000,-0.937500,
-    14.395508,4.368896,-0.878906,
-    14.395508,4.368896,-0.878906,
-    15.499939,4.391556,-0.659180,
-    14.960938,4.125000,-0.703125,
-    15.499939,4.391556,-0.659180,
-    14.395508,4.368896,-0.878906,
-    14.562500,4.458984,-0.750000,
-    14.562500,4.458984,-0.750000,
-    15.599121,4.495239,-0.562500,
-    15.499939,4.391556,-0.659180,
-    15.599121,4.495239,-0.562500,
-    14.562500,4.458984,-0.750000,
-    14.413086,4.382080,-0.621094,
-    14.413086,4.382080,-0.621094,
-    15.293274,4.413804,-0.465820,
-    15.599121,4.495239,-0.562500,
-    15.293274,4.413804,-0.465820,
-    14.413086,4.382080,-0.621094,
-    13.929688,4.125000,-0.562500,
-    13.929688,4.125000,-0.562500,
-    14.617188,4.125000,-0.421875,
-    15.293274,4.413804,-0.465820,
-    13.929688,4.125000,-0.937500,
-    12.898438,4.125000,-0.703125,
-    13.291077,4.346237,-0.659180,
-    13.291077,4.346237,-0.659180,
-    14.395508,4.368896,-0.878906,
-    13.929688,4.125000,-0.937500,
-    14.395508,4.368896,-0.878906,
-    13.291077,4.346237,-0.659180,
-    13.525879,4.422729,-0.562500,
-    13.525879,4.422729,-0.562500,
-    14.562500,4.458984,-0.750000,
-    14.395508,4.368896,-0.878906,
-    14.562500,4.458984,-0.750000,
-    13.525879,4.422729,-0.562500,
-    13.532898,4.350357,-0.465820,
-    13.532898,4.350357,-0.465820,
-    14.413086,4.382080,-0.621094,
-    14.562500,4.458984,-0.750000,
-    14.413086,4.382080,-0.621094,
-    13.532898,4.350357,-0.465820,
-    13.242188,4.125000,-0.421875,
-    13.242188,4.125000,-0.421875,
-    13.929688,4.125000,-0.562500,
-    14.413086,4.382080,-0.621094,
-    12.898438,4.125000,-0.703125,
-    12.429688,4.125000,0.000000,
-    12.789063,4.335938,0.000000,
-    12.789063,4.335938,0.000000,
-    13.291077,4.346237,-0.659180,
-    12.898438,4.125000,-0.703125,
-    13.291077,4.346237,-0.659180,
-    12.789063,4.335938,0.000000,
-    13.054688,4.406250,0.000000,
-    13.054688,4.406250,0.000000,
-    13.525879,4.422729,-0.562500,
-    13.291077,4.346237,-0.659180,
-    13.525879,4.422729,-0.562500,
-    13.054688,4.406250,0.000000,
-    13.132813,4.335938,0.000000,
-    13.132813,4.335938,0.000000,
-    13.532898,4.350357,-0.465820,
-    13.525879,4.422729,-0.562500,
-    13.532898,4.350357,-0.465820,
-    13.132813,4.335938,0.000000,
-    12.929688,4.125000,0.000000,
-    12.929688,4.125000,0.000000,
-    13.242188,4.125000,-0.421875,
-    13.532898,4.350357,-0.465820,
-    0.501414,7.628906,0.670256,
-    0.632813,7.628906,0.000000,
-    -1.070312,7.875000,0.000000,
-    0.632813,7.628906,0.000000,
-    0.501414,7.628906,0.670256,
-    0.429278,7.031250,0.639395,
-    0.429278,7.031250,0.639395,
-    0.554688,7.031250,0.000000,
-    0.632813,7.628906,0.000000,
-    0.554688,7.031250,0.000000,
-    0.429278,7.031250,0.639395,
-    -0.162029,6.292969,0.386960,
-    -0.162029,6.292969,0.386960,
-    -0.085937,6.292969,0.000000,
-    0.554688,7.031250,0.000000,
-    -0.085937,6.292969,0.000000,
-    -0.162029,6.292969,0.386960,
-    -0.147812,5.625000,0.392500,
-    -0.147812,5.625000,0.392500,
-    -0.070312,5.625000,0.000000,
-    -0.085937,6.292969,0.000000,
-    0.140489,7.628906,1.210801,
-    0.501414,7.628906,0.670256,
-    -1.070312,7.875000,0.000000,
-    0.501414,7.628906,0.670256,
-    0.140489,7.628906,1.210801,
-    0.084844,7.031250,1.155156,
-    0.084844,7.031250,1.155156,
-    0.429278,7.031250,0.639395,
-    0.501414,7.628906,0.670256,
-    0.429278,7.031250,0.639395,
-    0.084844,7.031250,1.155156,
-    -0.370879,6.292969,0.699434,
-    -0.370879,6.292969,0.699434,
-    -0.162029,6.292969,0.386960,
-    0.429278,7.031250,0.639395,
-    -0.162029,6.292969,0.386960,
-    -0.370879,6.292969,0.699434,
-    -0.360312,5.625000,0.710000,
-    -0.360312,5.625000,0.710000,
-    -0.147812,5.625000,0.392500,
-    -0.162029,6.292969,0.386960,
-    -0.400056,7.628906,1.571726,
-    0.140489,7.628906,1.210801,
-    -1.070312,7.875000,0.000000,
-    0.140489,7.628906,1.210801,
-    -0.400056,7.628906,1.571726,
-    -0.430918,7.031250,1.499590,
-    -0.430918,7.031250,1.499590,
-    0.084844,7.031250,1.155156,
-    0.140489,7.628906,1.210801,
-    0.084844,7.031250,1.155156,
-    -0.430918,7.031250,1.499590,
-    -0.683352,6.292969,0.908284,
-    -0.683352,6.292969,0.908284,
-    -0.370879,6.292969,0.699434,
-    0.084844,7.031250,1.155156,
-    -0.370879,6.292969,0.699434,
-    -0.683352,6.292969,0.908284,
-    -0.677812,5.625000,0.922500,
-    -0.677812,5.625000,0.922500,
-    -0.360312,5.625000,0.710000,
-    -0.370879,6.292969,0.699434,
-    -1.070312,7.628906,1.703125,
-    -0.400056,7.628906,1.571726,
-    -1.070312,7.875000,0.000000,
-    -0.400056,7.628906,1.571726,
-    -1.070312,7.628906,1.703125,
-    -1.070312,7.031250,1.625000,
-    -1.070312,7.031250,1.625000,
-    -0.430918,7.031250,1.499590,
-    -0.400056,7.628906,1.571726,
-    -0.430918,7.031250,1.499590,
-    -1.070312,7.031250,1.625000,
-    -1.070312,6.292969,0.984375,
-    -1.070312,6.292969,0.984375,
-    -0.683352,6.292969,0.908284,
-    -0.430918,7.031250,1.499590,
-    -0.683352,6.292969,0.908284,
-    -1.070312,6.292969,0.984375,
-    -1.070312,5.625000,1.000000,
-    -1.070312,5.625000,1.000000,
-    -0.677812,5.625000,0.922500,
-    -0.683352,6.292969,0.908284,
-    -1.740569,7.628906,1.571726,
-    -1.070312,7.628906,1.703125,
-    -1.070312,7.875000,0.000000,
-    -1.070312,7.628906,1.703125,
-    -1.740569,7.628906,1.571726,
-    -1.709707,7.031250,1.499590,
-    -1.709707,7.031250,1.499590,
-    -1.070312,7.031250,1.625000,
-    -1.070312,7.628906,1.703125,
-    -1.070312,7.031250,1.625000,
-    -1.709707,7.031250,1.499590,
-    -1.457273,6.292969,0.908284,
-    -1.457273,6.292969,0.908284,
-    -1.070312,6.292969,0.984375,
-    -1.070312,7.031250,1.625000,
-    -1.070312,6.292969,0.984375,
-    -1.457273,6.292969,0.908284,
-    -1.462812,5.625000,0.922500,
-    -1.462812,5.625000,0.922500,
-    -1.070312,5.625000,1.000000,
-    -1.070312,6.292969,0.984375,
-    -2.281113,7.628906,1.210801,
-    -1.740569,7.628906,1.571726,
-    -1.070312,7.875000,0.000000,
-    -1.740569,7.628906,1.571726,
-    -2.281113,7.628906,1.210801,
-    -2.225469,7.031250,1.155156,
-    -2.225469,7.031250,1.155156,
-    -1.709707,7.031250,1.499590,
-    -1.740569,7.628906,1.571726,
-    -1.709707,7.031250,1.499590,
-    -2.225469,7.031250,1.155156,
-    -1.769746,6.292969,0.699434,
-    -1.769746,6.292969,0.699434,
-    -1.457273,6.292969,0.908284,
-    -1.709707,7.031250,1.499590,
-    -1.457273,6.292969,0.908284,
-    -1.769746,6.292969,0.699434,
-    -1.780312,5.625000,0.710000,
-    -1.780312,5.625000,0.710000,
-    -1.462812,5.625000,0.922500,
-    -1.457273,6.292969,0.908284,
-    -2.642038,7.628906,0.670256,
-    -2.281113,7.628906,1.210801,
-    -1.070312,7.875000,0.000000,
-    -2.281113,7.628906,1.210801,
-    -2.642038,7.628906,0.670256,
-    -2.569902,7.031250,0.639395,
-    -2.569902,7.031250,0.639395,
-    -2.225469,7.031250,1.155156,
-    -2.281113,7.628906,1.210801,
-    -2.225469,7.031250,1.155156,
-    -2.569902,7.031250,0.639395,
-    -1.978596,6.292969,0.386960,
-    -1.978596,6.292969,0.386960,
-    -1.769746,6.292969,0.699434,
-    -2.225469,7.031250,1.155156,
-    -1.769746,6.292969,0.699434,
-    -1.978596,6.292969,0.386960,
-    -1.992812,5.625000,0.392500,
-    -1.992812,5.625000,0.392500,
-    -1.780312,5.625000,0.710000,
-    -1.769746,6.292969,0.699434,
-    -2.773438,7.628906,0.000000,
-    -2.642038,7.628906,0.670256,
-    -1.070312,7.875000,0.000000,
-    -2.642038,7.628906,0.670256,
-    -2.773438,7.628906,0.000000,
-    -2.695313,7.031250,0.000000,
-    -2.695313,7.031250,0.000000,
-    -2.569902,7.031250,0.639395,
-    -2.642038,7.628906,0.670256,
-    -2.569902,7.031250,0.639395,
-    -2.695313,7.031250,0.000000,
-    -2.054687,6.292969,0.000000,
-    -2.054687,6.292969,0.000000,
-    -1.978596,6.292969,0.386960,
-    -2.569902,7.031250,0.639395,
-    -1.978596,6.292969,0.386960,
-    -2.054687,6.292969,0.000000,
-    -2.070312,5.625000,0.000000,
-    -2.070312,5.625000,0.000000,
-    -1.992812,5.625000,0.392500,
-    -1.978596,6.292969,0.386960,
-    -2.642038,7.628906,-0.670256,
-    -2.773438,7.628906,0.000000,
-    -1.070312,7.875000,0.000000,
-    -2.773438,7.628906,0.000000,
-    -2.642038,7.628906,-0.670256,
-    -2.569902,7.031250,-0.639395,
-    -2.569902,7.031250,-0.639395,
-    -2.695313,7.031250,0.000000,
-    -2.773438,7.628906,0.000000,
-    -2.695313,7.031250,0.000000,
-    -2.569902,7.031250,-0.639395,
-    -1.978596,6.292969,-0.386960,
-    -1.978596,6.292969,-0.386960,
-    -2.054687,6.292969,0.000000,
-    -2.695313,7.031250,0.000000,
-    -2.054687,6.292969,0.000000,
-    -1.978596,6.292969,-0.386960,
-    -1.992812,5.625000,-0.392500,
-    -1.992812,5.625000,-0.392500,
-    -2.070312,5.625000,0.000000,
-    -2.054687,6.292969,0.000000,
-    -2.281113,7.628906,-1.210801,
-    -2.642038,7.628906,-0.670256,
-    -1.070312,7.875000,0.000000,
-    -2.642038,7.628906,-0.670256,
-    -2.281113,7.628906,-1.210801,
-    -2.225469,7.031250,-1.155156,
-    -2.225469,7.031250,-1.155156,
-    -2.569902,7.031250,-0.639395,
-    -2.642038,7.628906,-0.670256,
-    -2.569902,7.031250,-0.639395,
-    -2.225469,7.031250,-1.155156,
-    -1.769746,6.292969,-0.699434,
-    -1.769746,6.292969,-0.699434,
-    -1.978596,6.292969,-0.386960,
-    -2.569902,7.031250,-0.639395,
-    -1.978596,6.292969,-0.386960,
-    -1.769746,6.292969,-0.699434,
-    -1.780312,5.625000,-0.710000,
-    -1.780312,5.625000,-0.710000,
-    -1.992812,5.625000,-0.392500,
-    -1.978596,6.292969,-0.386960,
-    -1.740569,7.628906,-1.571726,
-    -2.281113,7.628906,-1.210801,
-    -1.070312,7.875000,0.000000,
-    -2.281113,7.628906,-1.210801,
-    -1.740569,7.628906,-1.571726,
-    -1.709707,7.031250,-1.499590,
-    -1.709707,7.031250,-1.499590,
-    -2.225469,7.031250,-1.155156,
-    -2.281113,7.628906,-1.210801,
-    -2.225469,7.031250,-1.155156,
-    -1.709707,7.031250,-1.499590,
-    -1.457273,6.292969,-0.908284,
-    -1.457273,6.292969,-0.908284,
-    -1.769746,6.292969,-0.699434,
-    -2.225469,7.031250,-1.155156,
-    -1.769746,6.292969,-0.699434,
-    -1.457273,6.292969,-0.908284,
-    -1.462812,5.625000,-0.922500,
-    -1.462812,5.625000,-0.922500,
-    -1.780312,5.625000,-0.710000,
-    -1.769746,6.292969,-0.699434,
-    -1.070312,7.628906,-1.703125,
-    -1.740569,7.628906,-1.571726,
-    -1.070312,7.875000,0.000000,
-    -1.740569,7.628906,-1.571726,
-    -1.070312,7.628906,-1.703125,
-    -1.070312,7.031250,-1.625000,
-    -1.070312,7.031250,-1.625000,
-    -1.709707,7.031250,-1.499590,
-    -1.740569,7.628906,-1.571726,
-    -1.709707,7.031250,-1.499590,
-    -1.070312,7.031250,-1.625000,
-    -1.070312,6.292969,-0.984375,
-    -1.070312,6.292969,-0.984375,
-    -1.457273,6.292969,-0.908284,
-    -1.709707,7.031250,-1.499590,
-    -1.457273,6.292969,-0.908284,
-    -1.070312,6.292969,-0.984375,
-    -1.070312,5.625000,-1.000000,
-    -1.070312,5.625000,-1.000000,
-    -1.462812,5.625000,-0.922500,
-    -1.457273,6.292969,-0.908284,
-    -0.400056,7.628906,-1.571726,
-    -1.070312,7.628906,-1.703125,
-    -1.070312,7.875000,0.000000,
-    -1.070312,7.628906,-1.703125,
-    -0.400056,7.628906,-1.571726,
-    -0.430918,7.031250,-1.499590,
-    -0.430918,7.031250,-1.499590,
-    -1.070312,7.031250,-1.625000,
-    -1.070312,7.628906,-1.703125,
-    -1.070312,7.031250,-1.625000,
-    -0.430918,7.031250,-1.499590,
-    -0.683352,6.292969,-0.908284,
-    -0.683352,6.292969,-0.908284,
-    -1.070312,6.292969,-0.984375,
-    -1.070312,7.031250,-1.625000,
-    -1.070312,6.292969,-0.984375,
-    -0.683352,6.292969,-0.908284,
-    -0.677812,5.625000,-0.922500,
-    -0.677812,5.625000,-0.922500,
-    -1.070312,5.625000,-1.000000,
-    -1.070312,6.292969,-0.984375,
-    0.140489,7.628906,-1.210801,
-    -0.400056,7.628906,-1.571726,
-    -1.070312,7.875000,0.000000,
-    -0.400056,7.628906,-1.571726,
-    0.140489,7.628906,-1.210801,
-    0.084844,7.031250,-1.155156,
-    0.084844,7.031250,-1.155156,
-    -0.430918,7.031250,-1.499590,
-    -0.400056,7.628906,-1.571726,
-    -0.430918,7.031250,-1.499590,
-    0.084844,7.031250,-1.155156,
-    -0.370879,6.292969,-0.699434,
-    -0.370879,6.292969,-0.699434,
-    -0.683352,6.292969,-0.908284,
-    -0.430918,7.031250,-1.499590,
-    -0.683352,6.292969,-0.908284,
-    -0.370879,6.292969,-0.699434,
-    -0.360312,5.625000,-0.710000,
-    -0.360312,5.625000,-0.710000,
-    -0.677812,5.625000,-0.922500,
-    -0.683352,6.292969,-0.908284,
-    0.501414,7.628906,-0.670256,
-    0.140489,7.628906,-1.210801,
-    -1.070312,7.875000,0.000000,
-    0.140489,7.628906,-1.210801,
-    0.501414,7.628906,-0.670256,
-    0.429278,7.031250,-0.639395,
-    0.429278,7.031250,-0.639395,
-    0.084844,7.031250,-1.155156,
-    0.140489,7.628906,-1.210801,
-    0.084844,7.031250,-1.155156,
-    0.429278,7.031250,-0.639395,
-    -0.162029,6.292969,-0.386960,
-    -0.162029,6.292969,-0.386960,
-    -0.370879,6.292969,-0.699434,
-    0.084844,7.031250,-1.155156,
-    -0.370879,6.292969,-0.699434,
-    -0.162029,6.292969,-0.386960,
-    -0.147812,5.625000,-0.392500,
-    -0.147812,5.625000,-0.392500,
-    -0.360312,5.625000,-0.710000,
-    -0.370879,6.292969,-0.699434,
-    0.632813,7.628906,0.000000,
-    0.501414,7.628906,-0.670256,
-    -1.070312,7.875000,0.000000,
-    0.501414,7.628906,-0.670256,
-    0.632813,7.628906,0.000000,
-    0.554688,7.031250,0.000000,
-    0.554688,7.031250,0.000000,
-    0.429278,7.031250,-0.639395,
-    0.501414,7.628906,-0.670256,
-    0.429278,7.031250,-0.639395,
-    0.554688,7.031250,0.000000,
-    -0.085937,6.292969,0.000000,
-    -0.085937,6.292969,0.000000,
-    -0.162029,6.292969,-0.386960,
-    0.429278,7.031250,-0.639395,
-    -0.162029,6.292969,-0.386960,
-    -0.085937,6.292969,0.000000,
-    -0.070312,5.625000,0.000000,
-    -0.070312,5.625000,0.000000,
-    -0.147812,5.625000,-0.392500,
-    -0.162029,6.292969,-0.386960,
-    -0.070312,5.625000,0.000000,
-    -0.147812,5.625000,0.392500,
-    1.034141,5.179688,0.895391,
-    1.034141,5.179688,0.895391,
-    1.210938,5.179688,0.000000,
-    -0.070312,5.625000,0.000000,
-    1.210938,5.179688,0.000000,
-    1.034141,5.179688,0.895391,
-    2.735000,4.875000,1.619062,
-    2.735000,4.875000,1.619062,
-    3.054688,4.875000,0.000000,
-    1.210938,5.179688,0.000000,
-    3.054688,4.875000,0.000000,
-    2.735000,4.875000,1.619062,
-    4.262891,4.570313,2.269140,
-    4.262891,4.570313,2.269140,
-    4.710938,4.570313,0.000000,
-    3.054688,4.875000,0.000000,
-    4.710938,4.570313,0.000000,
-    4.262891,4.570313,2.269140,
-    4.925938,4.125000,2.551250,
-    4.925938,4.125000,2.551250,
-    5.429688,4.125000,0.000000,
-    4.710938,4.570313,0.000000,
-    -0.147812,5.625000,0.392500,
-    -0.360312,5.625000,0.710000,
-    0.549375,5.179688,1.619688,
-    0.549375,5.179688,1.619688,
-    1.034141,5.179688,0.895391,
-    -0.147812,5.625000,0.392500,
-    1.034141,5.179688,0.895391,
-    0.549375,5.179688,1.619688,
-    1.858438,4.875000,2.928750,
-    1.858438,4.875000,2.928750,
-    2.735000,4.875000,1.619062,
-    1.034141,5.179688,0.895391,
-    2.735000,4.875000,1.619062,
-    1.858438,4.875000,2.928750,
-    3.034375,4.570313,4.104687,
-    3.034375,4.570313,4.104687,
-    4.262891,4.570313,2.269140,
-    2.735000,4.875000,1.619062,
-    4.262891,4.570313,2.269140,
-    3.034375,4.570313,4.104687,
-    3.544688,4.125000,4.615000,
-    3.544688,4.125000,4.615000,
-    4.925938,4.125000,2.551250,
-    4.262891,4.570313,2.269140,
-    -0.360312,5.625000,0.710000,
-    -0.677812,5.625000,0.922500,
-    -0.174922,5.179688,2.104453,
-    -0.174922,5.179688,2.104453,
-    0.549375,5.179688,1.619688,
-    -0.360312,5.625000,0.710000,
-    0.549375,5.179688,1.619688,
-    -0.174922,5.179688,2.104453,
-    0.548750,4.875000,3.805313,
-    0.548750,4.875000,3.805313,
-    1.858438,4.875000,2.928750,
-    0.549375,5.179688,1.619688,
-    1.858438,4.875000,2.928750,
-    0.548750,4.875000,3.805313,
-    1.198828,4.570313,5.333203,
-    1.198828,4.570313,5.333203,
-    3.034375,4.570313,4.104687,
-    1.858438,4.875000,2.928750,
-    3.034375,4.570313,4.104687,
-    1.198828,4.570313,5.333203,
-    1.480938,4.125000,5.996250,
-    1.480938,4.125000,5.996250,
-    3.544688,4.125000,4.615000,
-    3.034375,4.570313,4.104687,
-    -0.677812,5.625000,0.922500,
-    -1.070312,5.625000,1.000000,
-    -1.070312,5.179688,2.281250,
-    -1.070312,5.179688,2.281250,
-    -0.174922,5.179688,2.104453,
-    -0.677812,5.625000,0.922500,
-    -0.174922,5.179688,2.104453,
-    -1.070312,5.179688,2.281250,
-    -1.070312,4.875000,4.125000,
-    -1.070312,4.875000,4.125000,
-    0.548750,4.875000,3.805313,
-    -0.174922,5.179688,2.104453,
-    0.548750,4.875000,3.805313,
-    -1.070312,4.875000,4.125000,
-    -1.070312,4.570313,5.781250,
-    -1.070312,4.570313,5.781250,
-    1.198828,4.570313,5.333203,
-    0.548750,4.875000,3.805313,
-    1.198828,4.570313,5.333203,
-    -1.070312,4.570313,5.781250,
-    -1.070312,4.125000,6.500000,
-    -1.070312,4.125000,6.500000,
-    1.480938,4.125000,5.996250,
-    1.198828,4.570313,5.333203,
-    -1.070312,5.625000,1.000000,
-    -1.462812,5.625000,0.922500,
-    -1.965703,5.179688,2.104453,
-    -1.965703,5.179688,2.104453,
-    -1.070312,5.179688,2.281250,
-    -1.070312,5.625000,1.000000,
-    -1.070312,5.179688,2.281250,
-    -1.965703,5.179688,2.104453,
-    -2.689375,4.875000,3.805313,
-    -2.689375,4.875000,3.805313,
-    -1.070312,4.875000,4.125000,
-    -1.070312,5.179688,2.281250,
-    -1.070312,4.875000,4.125000,
-    -2.689375,4.875000,3.805313,
-    -3.339453,4.570313,5.333203,
-    -3.339453,4.570313,5.333203,
-    -1.070312,4.570313,5.781250,
-    -1.070312,4.875000,4.125000,
-    -1.070312,4.570313,5.781250,
-    -3.339453,4.570313,5.333203,
-    -3.621562,4.125000,5.996250,
-    -3.621562,4.125000,5.996250,
-    -1.070312,4.125000,6.500000,
-    -1.070312,4.570313,5.781250,
-    -1.462812,5.625000,0.922500,
-    -1.780312,5.625000,0.710000,
-    -2.690000,5.179688,1.619688,
-    -2.690000,5.179688,1.619688,
-    -1.965703,5.179688,2.104453,
-    -1.462812,5.625000,0.922500,
-    -1.965703,5.179688,2.104453,
-    -2.690000,5.179688,1.619688,
-    -3.999062,4.875000,2.928750,
-    -3.999062,4.875000,2.928750,
-    -2.689375,4.875000,3.805313,
-    -1.965703,5.179688,2.104453,
-    -2.689375,4.875000,3.805313,
-    -3.999062,4.875000,2.928750,
-    -5.174999,4.570313,4.104687,
-    -5.174999,4.570313,4.104687,
-    -3.339453,4.570313,5.333203,
-    -2.689375,4.875000,3.805313,
-    -3.339453,4.570313,5.333203,
-    -5.174999,4.570313,4.104687,
-    -5.685312,4.125000,4.615000,
-    -5.685312,4.125000,4.615000,
-    -3.621562,4.125000,5.996250,
-    -3.339453,4.570313,5.333203,
-    -1.780312,5.625000,0.710000,
-    -1.992812,5.625000,0.392500,
-    -3.174765,5.179688,0.895391,
-    -3.174765,5.179688,0.895391,
-    -2.690000,5.179688,1.619688,
-    -1.780312,5.625000,0.710000,
-    -2.690000,5.179688,1.619688,
-    -3.174765,5.179688,0.895391,
-    -4.875625,4.875000,1.619062,
-    -4.875625,4.875000,1.619062,
-    -3.999062,4.875000,2.928750,
-    -2.690000,5.179688,1.619688,
-    -3.999062,4.875000,2.928750,
-    -4.875625,4.875000,1.619062,
-    -6.403516,4.570313,2.269140,
-    -6.403516,4.570313,2.269140,
-    -5.174999,4.570313,4.104687,
-    -3.999062,4.875000,2.928750,
-    -5.174999,4.570313,4.104687,
-    -6.403516,4.570313,2.269140,
-    -7.066563,4.125000,2.551250,
-    -7.066563,4.125000,2.551250,
-    -5.685312,4.125000,4.615000,
-    -5.174999,4.570313,4.104687,
-    -1.992812,5.625000,0.392500,
-    -2.070312,5.625000,0.000000,
-    -3.351562,5.179688,0.000000,
-    -3.351562,5.179688,0.000000,
-    -3.174765,5.179688,0.895391,
-    -1.992812,5.625000,0.392500,
-    -3.174765,5.179688,0.895391,
-    -3.351562,5.179688,0.000000,
-    -5.195313,4.875000,0.000000,
-    -5.195313,4.875000,0.000000,
-    -4.875625,4.875000,1.619062,
-    -3.174765,5.179688,0.895391,
-    -4.875625,4.875000,1.619062,
-    -5.195313,4.875000,0.000000,
-    -6.851563,4.570313,0.000000,
-    -6.851563,4.570313,0.000000,
-    -6.403516,4.570313,2.269140,
-    -4.875625,4.875000,1.619062,
-    -6.403516,4.570313,2.269140,
-    -6.851563,4.570313,0.000000,
-    -7.570313,4.125000,0.000000,
-    -7.570313,4.125000,0.000000,
-    -7.066563,4.125000,2.551250,
-    -6.403516,4.570313,2.269140,
-    -2.070312,5.625000,0.000000,
-    -1.992812,5.625000,-0.392500,
-    -3.174765,5.179688,-0.895391,
-    -3.174765,5.179688,-0.895391,
-    -3.351562,5.179688,0.000000,
-    -2.070312,5.625000,0.000000,
-    -3.351562,5.179688,0.000000,
-    -3.174765,5.179688,-0.895391,
-    -4.875625,4.875000,-1.619062,
-    -4.875625,4.875000,-1.619062,
-    -5.195313,4.875000,0.000000,
-    -3.351562,5.179688,0.000000,
-    -5.195313,4.875000,0.000000,
-    -4.875625,4.875000,-1.619062,
-    -6.403516,4.570313,-2.269140,
-    -6.403516,4.570313,-2.269140,
-    -6.851563,4.570313,0.000000,
-    -5.195313,4.875000,0.000000,
-    -6.851563,4.570313,0.000000,
-    -6.403516,4.570313,-2.269140,
-    -7.066563,4.125000,-2.551250,
-    -7.066563,4.125000,-2.551250,
-    -7.570313,4.125000,0.000000,
-    -6.851563,4.570313,0.000000,
-    -1.992812,5.625000,-0.392500,
-    -1.780312,5.625000,-0.710000,
-    -2.690000,5.179688,-1.619688,
-    -2.690000,5.179688,-1.619688,
-    -3.174765,5.179688,-0.895391,
-    -1.992812,5.625000,-0.392500,
-    -3.174765,5.179688,-0.895391,
-    -2.690000,5.179688,-1.619688,
-    -3.999062,4.875000,-2.928750,
-    -3.999062,4.875000,-2.928750,
-    -4.875625,4.875000,-1.619062,
-    -3.174765,5.179688,-0.895391,
-    -4.875625,4.875000,-1.619062,
-    -3.999062,4.875000,-2.928750,
-    -5.174999,4.570313,-4.104687,
-    -5.174999,4.570313,-4.104687,
-    -6.403516,4.570313,-2.269140,
-    -4.875625,4.875000,-1.619062,
-    -6.403516,4.570313,-2.269140,
-    -5.174999,4.570313,-4.104687,
-    -5.685312,4.125000,-4.615000,
-    -5.685312,4.125000,-4.615000,
-    -7.066563,4.125000,-2.551250,
-    -6.403516,4.570313,-2.269140,
-    -1.780312,5.625000,-0.710000,
-    -1.462812,5.625000,-0.922500,
-    -1.965703,5.179688,-2.104453,
-    -1.965703,5.179688,-2.104453,
-    -2.690000,5.179688,-1.619688,
-    -1.780312,5.625000,-0.710000,
-    -2.690000,5.179688,-1.619688,
-    -1.965703,5.179688,-2.104453,
-    -2.689375,4.875000,-3.805313,
-    -2.689375,4.875000,-3.805313,
-    -3.999062,4.875000,-2.928750,
-    -2.690000,5.179688,-1.619688,
-    -3.999062,4.875000,-2.928750,
-    -2.689375,4.875000,-3.805313,
-    -3.339453,4.570313,-5.333203,
-    -3.339453,4.570313,-5.333203,
-    -5.174999,4.570313,-4.104687,
-    -3.999062,4.875000,-2.928750,
-    -5.174999,4.570313,-4.104687,
-    -3.339453,4.570313,-5.333203,
-    -3.621562,4.125000,-5.996250,
-    -3.621562,4.125000,-5.996250,
-    -5.685312,4.125000,-4.615000,
-    -5.174999,4.570313,-4.104687,
-    -1.462812,5.625000,-0.922500,
-    -1.070312,5.625000,-1.000000,
-    -1.070312,5.179688,-2.281250,
-    -1.070312,5.179688,-2.281250,
-    -1.965703,5.179688,-2.104453,
-    -1.462812,5.625000,-0.922500,
-    -1.965703,5.179688,-2.104453,
-    -1.070312,5.179688,-2.281250,
-    -1.070312,4.875000,-4.125000,
-    -1.070312,4.875000,-4.125000,
-    -2.689375,4.875000,-3.805313,
-    -1.965703,5.179688,-2.104453,
-    -2.689375,4.875000,-3.805313,
-    -1.070312,4.875000,-4.125000,
-    -1.070312,4.570313,-5.781250,
-    -1.070312,4.570313,-5.781250,
-    -3.339453,4.570313,-5.333203,
-    -2.689375,4.875000,-3.805313,
-    -3.339453,4.570313,-5.333203,
-    -1.070312,4.570313,-5.781250,
-    -1.070312,4.125000,-6.500000,
-    -1.070312,4.125000,-6.500000,
-    -3.621562,4.125000,-5.996250,
-    -3.339453,4.570313,-5.333203,
-    -1.070312,5.625000,-1.000000,
-    -0.677812,5.625000,-0.922500,
-    -0.174922,5.179688,-2.104453,
-    -0.174922,5.179688,-2.104453,
-    -1.070312,5.179688,-2.281250,
-    -1.070312,5.625000,-1.000000,
-    -1.070312,5.179688,-2.281250,
-    -0.174922,5.179688,-2.104453,
-    0.548750,4.875000,-3.805313,
-    0.548750,4.875000,-3.805313,
-    -1.070312,4.875000,-4.125000,
-    -1.070312,5.179688,-2.281250,
-    -1.070312,4.875000,-4.125000,
-    0.548750,4.875000,-3.805313,
-    1.198828,4.570313,-5.333203,
-    1.198828,4.570313,-5.333203,
-    -1.070312,4.570313,-5.781250,
-    -1.070312,4.875000,-4.125000,
-    -1.070312,4.570313,-5.781250,
-    1.198828,4.570313,-5.333203,
-    1.480938,4.125000,-5.996250,
-    1.480938,4.125000,-5.996250,
-    -1.070312,4.125000,-6.500000,
-    -1.070312,4.570313,-5.781250,
-    -0.677812,5.625000,-0.922500,
-    -0.360312,5.625000,-0.710000,
-    0.549375,5.179688,-1.619688,
-    0.549375,5.179688,-1.619688,
-    -0.174922,5.179688,-2.104453,
-    -0.677812,5.625000,-0.922500,
-    -0.174922,5.179688,-2.104453,
-    0.549375,5.179688,-1.619688,
-    1.858438,4.875000,-2.928750,
-    1.858438,4.875000,-2.928750,
-    0.548750,4.875000,-3.805313,
-    -0.174922,5.179688,-2.104453,
-    0.548750,4.875000,-3.805313,
-    1.858438,4.875000,-2.928750,
-    3.034375,4.570313,-4.104687,
-    3.034375,4.570313,-4.104687,
-    1.198828,4.570313,-5.333203,
-    0.548750,4.875000,-3.805313,
-    1.198828,4.570313,-5.333203,
-    3.034375,4.570313,-4.104687,
-    3.544688,4.125000,-4.615000,
-    3.544688,4.125000,-4.615000,
-    1.480938,4.125000,-5.996250,
-    1.198828,4.570313,-5.333203,
-    -0.360312,5.625000,-0.710000,
-    -0.147812,5.625000,-0.392500,
-    1.034141,5.179688,-0.895391,
-    1.034141,5.179688,-0.895391,
-    0.549375,5.179688,-1.619688,
-    -0.360312,5.625000,-0.710000,
-    0.549375,5.179688,-1.619688,
-    1.034141,5.179688,-0.895391,
-    2.735000,4.875000,-1.619062,
-    2.735000,4.875000,-1.619062,
-    1.858438,4.875000,-2.928750,
-    0.549375,5.179688,-1.619688,
-    1.858438,4.875000,-2.928750,
-    2.735000,4.875000,-1.619062,
-    4.262891,4.570313,-2.269140,
-    4.262891,4.570313,-2.269140,
-    3.034375,4.570313,-4.104687,
-    1.858438,4.875000,-2.928750,
-    3.034375,4.570313,-4.104687,
-    4.262891,4.570313,-2.269140,
-    4.925938,4.125000,-2.551250,
-    4.925938,4.125000,-2.551250,
-    3.544688,4.125000,-4.615000,
-    3.034375,4.570313,-4.104687,
-    -0.147812,5.625000,-0.392500,
-    -0.070312,5.625000,0.000000,
-    1.210938,5.179688,0.000000,
-    1.210938,5.179688,0.000000,
-    1.034141,5.179688,-0.895391,
-    -0.147812,5.625000,-0.392500,
-    1.034141,5.179688,-0.895391,
-    1.210938,5.179688,0.000000,
-    3.054688,4.875000,0.000000,
-    3.054688,4.875000,0.000000,
-    2.735000,4.875000,-1.619062,
-    1.034141,5.179688,-0.895391,
-    2.735000,4.875000,-1.619062,
-    3.054688,4.875000,0.000000,
-    4.710938,4.570313,0.000000,
-    4.710938,4.570313,0.000000,
-    4.262891,4.570313,-2.269140,
-    2.735000,4.875000,-1.619062,
-    4.262891,4.570313,-2.269140,
-    4.710938,4.570313,0.000000,
-    5.429688,4.125000,0.000000,
-    5.429688,4.125000,0.000000,
-    4.925938,4.125000,-2.551250,
-    4.262891,4.570313,-2.269140,
-];
-
-pub static INDEX: &[u16] = &[
-    0,1,2,
-    3,4,5,
-    6,7,8,
-    9,10,11,
-    12,13,14,
-    15,16,17,
-    18,19,20,
-    21,22,23,
-    24,25,26,
-    27,28,29,
-    30,31,32,
-    33,34,35,
-    36,37,38,
-    39,40,41,
-    42,43,44,
-    45,46,47,
-    48,49,50,
-    51,52,53,
-    54,55,56,
-    57,58,59,
-    60,61,62,
-    63,64,65,
-    66,67,68,
-    69,70,71,
-    72,73,74,
-    75,76,77,
-    78,79,80,
-    81,82,83,
-    84,85,86,
-    87,88,89,
-    90,91,92,
-    93,94,95,
-    96,97,98,
-    99,100,101,
-    102,103,104,
-    105,106,107,
-    108,109,110,
-    111,112,113,
-    114,115,116,
-    117,118,119,
-    120,121,122,
-    123,124,125,
-    126,127,128,
-    129,130,131,
-    132,133,134,
-    135,136,137,
-    138,139,140,
-    141,142,143,
-    144,145,146,
-    147,148,149,
-    150,151,152,
-    153,154,155,
-    156,157,158,
-    159,160,161,
-    162,163,164,
-    165,166,167,
-    168,169,170,
-    171,172,173,
-    174,175,176,
-    177,178,179,
-    180,181,182,
-    183,184,185,
-    186,187,188,
-    189,190,191,
-    192,193,194,
-    195,196,197,
-    198,199,200,
-    201,202,203,
-    204,205,206,
-    207,208,209,
-    210,211,212,
-    213,214,215,
-    216,217,218,
-    219,220,221,
-    222,223,224,
-    225,226,227,
-    228,229,230,
-    231,232,233,
-    234,235,236,
-    237,238,239,
-    240,241,242,
-    243,244,245,
-    246,247,248,
-    249,250,251,
-    252,253,254,
-    255,256,257,
-    258,259,260,
-    261,262,263,
-    264,265,266,
-    267,268,269,
-    270,271,272,
-    273,274,275,
-    276,277,278,
-    279,280,281,
-    282,283,284,
-    285,286,287,
-    288,289,290,
-    291,292,293,
-    294,295,296,
-    297,298,299,
-    300,301,302,
-    303,304,305,
-    306,307,308,
-    309,310,311,
-    312,313,314,
-    315,316,317,
-    318,319,320,
-    321,322,323,
-    324,325,326,
-    327,328,329,
-    330,331,332,
-    333,334,335,
-    336,337,338,
-    339,340,341,
-    342,343,344,
-    345,346,347,
-    348,349,350,
-    351,352,353,
-    354,355,356,
-    357,358,359,
-    360,361,362,
-    363,364,365,
-    366,367,368,
-    369,370,371,
-    372,373,374,
-    375,376,377,
-    378,379,380,
-    381,382,383,
-    384,385,386,
-    387,388,389,
-    390,391,392,
-    393,394,395,
-    396,397,398,
-    399,400,401,
-    402,403,404,
-    405,406,407,
-    408,409,410,
-    411,412,413,
-    414,415,416,
-    417,418,419,
-    420,421,422,
-    423,424,425,
-    426,427,428,
-    429,430,431,
-    432,433,434,
-    435,436,437,
-    438,439,440,
-    441,442,443,
-    444,445,446,
-    447,448,449,
-    450,451,452,
-    453,454,455,
-    456,457,458,
-    459,460,461,
-    462,463,464,
-    465,466,467,
-    468,469,470,
-    471,472,473,
-    474,475,476,
-    477,478,479,
-    480,481,482,
-    483,484,485,
-    486,487,488,
-    489,490,491,
-    492,493,494,
-    495,496,497,
-    498,499,500,
-    501,502,503,
-    504,505,506,
-    507,508,509,
-    510,511,512,
-    513,514,515,
-    516,517,518,
-    519,520,521,
-    522,523,524,
-    525,526,527,
-    528,529,530,
-    531,532,533,
-    534,535,536,
-    537,538,539,
-    540,541,542,
-    543,544,545,
-    546,547,548,
-    549,550,551,
-    552,553,554,
-    555,556,557,
-    558,559,560,
-    561,562,563,
-    564,565,566,
-    567,568,569,
-    570,571,572,
-    573,574,575,
-    576,577,578,
-    579,580,581,
-    582,583,584,
-    585,586,587,
-    588,589,590,
-    591,592,593,
-    594,595,596,
-    597,598,599,
-    600,601,602,
-    603,604,605,
-    606,607,608,
-    609,610,611,
-    612,613,614,
-    615,616,617,
-    618,619,620,
-    621,622,623,
-    624,625,626,
-    627,628,629,
-    630,631,632,
-    633,634,635,
-    636,637,638,
-    639,640,641,
-    642,643,644,
-    645,646,647,
-    648,649,650,
-    651,652,653,
-    654,655,656,
-    657,658,659,
-    660,661,662,
-    663,664,665,
-    666,667,668,
-    669,670,671,
-    672,673,674,
-    675,676,677,
-    678,679,680,
-    681,682,683,
-    684,685,686,
-    687,688,689,
-    690,691,692,
-    693,694,695,
-    696,697,698,
-    699,700,701,
-    702,703,704,
-    705,706,707,
-    708,709,710,
-    711,712,713,
-    714,715,716,
-    717,718,719,
-    720,721,722,
-    723,724,725,
-    726,727,728,
-    729,730,731,
-    732,733,734,
-    735,736,737,
-    738,739,740,
-    741,742,743,
-    744,745,746,
-    747,748,749,
-    750,751,752,
-    753,754,755,
-    756,757,758,
-    759,760,761,
-    762,763,764,
-    765,766,767,
-    768,769,770,
-    771,772,773,
-    774,775,776,
-    777,778,779,
-    780,781,782,
-    783,784,785,
-    786,787,788,
-    789,790,791,
-    792,793,794,
-    795,796,797,
-    798,799,800,
-    801,802,803,
-    804,805,806,
-    807,808,809,
-    810,811,812,
-    813,814,815,
-    816,817,818,
-    819,820,821,
-    822,823,824,
-    825,826,827,
-    828,829,830,
-    831,832,833,
-    834,835,836,
-    837,838,839,
-    840,841,842,
-    843,844,845,
-    846,847,848,
-    849,850,851,
-    852,853,854,
-    855,856,857,
-    858,859,860,
-    861,862,863,
-    864,865,866,
-    867,868,869,
-    870,871,872,
-    873,874,875,
-    876,877,878,
-    879,880,881,
-    882,883,884,
-    885,886,887,
-    888,889,890,
-    891,892,893,
-    894,895,896,
-    897,898,899,
-    900,901,902,
-    903,904,905,
-    906,907,908,
-    909,910,911,
-    912,913,914,
-    915,916,917,
-    918,919,920,
-    921,922,923,
-    924,925,926,
-    927,928,929,
-    930,931,932,
-    933,934,935,
-    936,937,938,
-    939,940,941,
-    942,943,944,
-    945,946,947,
-    948,949,950,
-    951,952,953,
-    954,955,956,
-    957,958,959,
-    960,961,962,
-    963,964,965,
-    966,967,968,
-    969,970,971,
-    972,973,974,
-    975,976,977,
-    978,979,980,
-    981,982,983,
-    984,985,986,
-    987,988,989,
-    990,991,992,
-    993,994,995,
-    996,997,998,
-    999,1000,1001,
-    1002,1003,1004,
-    1005,1006,1007,
-    1008,1009,1010,
-    1011,1012,1013,
-    1014,1015,1016,
-    1017,1018,1019,
-    1020,1021,1022,
-    1023,1024,1025,
-    1026,1027,1028,
-    1029,1030,1031,
-    1032,1033,1034,
-    1035,1036,1037,
-    1038,1039,1040,
-    1041,1042,1043,
-    1044,1045,1046,
-    1047,1048,1049,
-    1050,1051,1052,
-    1053,1054,1055,
-    1056,1057,1058,
-    1059,1060,1061,
-    1062,1063,1064,
-    1065,1066,1067,
-    1068,1069,1070,
-    1071,1072,1073,
-    1074,1075,1076,
-    1077,1078,1079,
-    1080,1081,1082,
-    1083,1084,1085,
-    1086,1087,1088,
-    1089,1090,1091,
-    1092,1093,1094,
-    1095,1096,1097,
-    1098,1099,1100,
-    1101,1102,1103,
-    1104,1105,1106,
-    1107,1108,1109,
-    1110,1111,1112,
-    1113,1114,1115,
-    1116,1117,1118,
-    1119,1120,1121,
-    1122,1123,1124,
-    1125,1126,1127,
-    1128,1129,1130,
-    1131,1132,1133,
-    1134,1135,1136,
-    1137,1138,1139,
-    1140,1141,1142,
-    1143,1144,1145,
-    1146,1147,1148,
-    1149,1150,1151,
-    1152,1153,1154,
-    1155,1156,1157,
-    1158,1159,1160,
-    1161,1162,1163,
-    1164,1165,1166,
-    1167,1168,1169,
-    1170,1171,1172,
-    1173,1174,1175,
-    1176,1177,1178,
-    1179,1180,1181,
-    1182,1183,1184,
-    1185,1186,1187,
-    1188,1189,1190,
-    1191,1192,1193,
-    1194,1195,1196,
-    1197,1198,1199,
-    1200,1201,1202,
-    1203,1204,1205,
-    1206,1207,1208,
-    1209,1210,1211,
-    1212,1213,1214,
-    1215,1216,1217,
-    1218,1219,1220,
-    1221,1222,1223,
-    1224,1225,1226,
-    1227,1228,1229,
-    1230,1231,1232,
-    1233,1234,1235,
-    1236,1237,1238,
-    1239,1240,1241,
-    1242,1243,1244,
-    1245,1246,1247,
-    1248,1249,1250,
-    1251,1252,1253,
-    1254,1255,1256,
-    1257,1258,1259,
-    1260,1261,1262,
-    1263,1264,1265,
-    1266,1267,1268,
-    1269,1270,1271,
-    1272,1273,1274,
-    1275,1276,1277,
-    1278,1279,1280,
-    1281,1282,1283,
-    1284,1285,1286,
-    1287,1288,1289,
-    1290,1291,1292,
-    1293,1294,1295,
-    1296,1297,1298,
-    1299,1300,1301,
-    1302,1303,1304,
-    1305,1306,1307,
-    1308,1309,1310,
-    1311,1312,1313,
-    1314,1315,1316,
-    1317,1318,1319,
-    1320,1321,1322,
-    1323,1324,1325,
-    1326,1327,1328,
-    1329,1330,1331,
-    1332,1333,1334,
-    1335,1336,1337,
-    1338,1339,1340,
-    1341,1342,1343,
-    1344,1345,1346,
-    1347,1348,1349,
-    1350,1351,1352,
-    1353,1354,1355,
-    1356,1357,1358,
-    1359,1360,1361,
-    1362,1363,1364,
-    1365,1366,1367,
-    1368,1369,1370,
-    1371,1372,1373,
-    1374,1375,1376,
-    1377,1378,1379,
-    1380,1381,1382,
-    1383,1384,1385,
-    1386,1387,1388,
-    1389,1390,1391,
-    1392,1393,1394,
-    1395,1396,1397,
-    1398,1399,1400,
-    1401,1402,1403,
-    1404,1405,1406,
-    1407,1408,1409,
-    1410,1411,1412,
-    1413,1414,1415,
-    1416,1417,1418,
-    1419,1420,1421,
-    1422,1423,1424,
-    1425,1426,1427,
-    1428,1429,1430,
-    1431,1432,1433,
-    1434,1435,1436,
-    1437,1438,1439,
-    1440,1441,1442,
-    1443,1444,1445,
-    1446,1447,1448,
-    1449,1450,1451,
-    1452,1453,1454,
-    1455,1456,1457,
-    1458,1459,1460,
-    1461,1462,1463,
-    1464,1465,1466,
-    1467,1468,1469,
-    1470,1471,1472,
-    1473,1474,1475,
-    1476,1477,1478,
-    1479,1480,1481,
-    1482,1483,1484,
-    1485,1486,1487,
-    1488,1489,1490,
-    1491,1492,1493,
-    1494,1495,1496,
-    1497,1498,1499,
-    1500,1501,1502,
-    1503,1504,1505,
-    1506,1507,1508,
-    1509,1510,1511,
-    1512,1513,1514,
-    1515,1516,1517,
-    1518,1519,1520,
-    1521,1522,1523,
-    1524,1525,1526,
-    1527,1528,1529,
-    1530,1531,1532,
-    1533,1534,1535,
-    1536,1537,1538,
-    1539,1540,1541,
-    1542,1543,1544,
-    1545,1546,1547,
-    1548,1549,1550,
-    1551,1552,1553,
-    1554,1555,1556,
-    1557,1558,1559,
-    1560,1561,1562,
-    1563,1564,1565,
-    1566,1567,1568,
-    1569,1570,1571,
-    1572,1573,1574,
-    1575,1576,1577,
-    1578,1579,1580,
-    1581,1582,1583,
-    1584,1585,1586,
-    1587,1588,1589,
-    1590,1591,1592,
-    1593,1594,1595,
-    1596,1597,1598,
-    1599,1600,1601,
-    1602,1603,1604,
-    1605,1606,1607,
-    1608,1609,1610,
-    1611,1612,1613,
-    1614,1615,1616,
-    1617,1618,1619,
-    1620,1621,1622,
-    1623,1624,1625,
-    1626,1627,1628,
-    1629,1630,1631,
-    1632,1633,1634,
-    1635,1636,1637,
-    1638,1639,1640,
-    1641,1642,1643,
-    1644,1645,1646,
-    1647,1648,1649,
-    1650,1651,1652,
-    1653,1654,1655,
-    1656,1657,1658,
-    1659,1660,1661,
-    1662,1663,1664,
-    1665,1666,1667,
-    1668,1669,1670,
-    1671,1672,1673,
-    1674,1675,1676,
-    1677,1678,1679,
-    1680,1681,1682,
-    1683,1684,1685,
-    1686,1687,1688,
-    1689,1690,1691,
-    1692,1693,1694,
-    1695,1696,1697,
-    1698,1699,1700,
-    1701,1702,1703,
-    1704,1705,1706,
-    1707,1708,1709,
-    1710,1711,1712,
-    1713,1714,1715,
-    1716,1717,1718,
-    1719,1720,1721,
-    1722,1723,1724,
-    1725,1726,1727,
-    1728,1729,1730,
-    1731,1732,1733,
-    1734,1735,1736,
-    1737,1738,1739,
-    1740,1741,1742,
-    1743,1744,1745,
-    1746,1747,1748,
-    1749,1750,1751,
-    1752,1753,1754,
-    1755,1756,1757,
-    1758,1759,1760,
-    1761,1762,1763,
-    1764,1765,1766,
-    1767,1768,1769,
-    1770,1771,1772,
-    1773,1774,1775,
-    1776,1777,1778,
-    1779,1780,1781,
-    1782,1783,1784,
-    1785,1786,1787,
-    1788,1789,1790,
-    1791,1792,1793,
-    1794,1795,1796,
-    1797,1798,1799,
-    1800,1801,1802,
-    1803,1804,1805,
-    1806,1807,1808,
-    1809,1810,1811,
-    1812,1813,1814,
-    1815,1816,1817,
-    1818,1819,1820,
-    1821,1822,1823,
-    1824,1825,1826,
-    1827,1828,1829,
-    1830,1831,1832,
-    1833,1834,1835,
-    1836,1837,1838,
-    1839,1840,1841,
-    1842,1843,1844,
-    1845,1846,1847,
-    1848,1849,1850,
-    1851,1852,1853,
-    1854,1855,1856,
-    1857,1858,1859,
-    1860,1861,1862,
-    1863,1864,1865,
-    1866,1867,1868,
-    1869,1870,1871,
-    1872,1873,1874,
-    1875,1876,1877,
-    1878,1879,1880,
-    1881,1882,1883,
-    1884,1885,1886,
-    1887,1888,1889,
-    1890,1891,1892,
-    1893,1894,1895,
-    1896,1897,1898,
-    1899,1900,1901,
-    1902,1903,1904,
-    1905,1906,1907,
-    1908,1909,1910,
-    1911,1912,1913,
-    1914,1915,1916,
-    1917,1918,1919,
-    1920,1921,1922,
-    1923,1924,1925,
-    1926,1927,1928,
-    1929,1930,1931,
-    1932,1933,1934,
-    1935,1936,1937,
-    1938,1939,1940,
-    1941,1942,1943,
-    1944,1945,1946,
-    1947,1948,1949,
-    1950,1951,1952,
-    1953,1954,1955,
-    1956,1957,1958,
-    1959,1960,1961,
-    1962,1963,1964,
-    1965,1966,1967,
-    1968,1969,1970,
-    1971,1972,1973,
-    1974,1975,1976,
-    1977,1978,1979,
-    1980,1981,1982,
-    1983,1984,1985,
-    1986,1987,1988,
-    1989,1990,1991,
-    1992,1993,1994,
-    1995,1996,1997,
-    1998,1999,2000,
-    2001,2002,2003,
-    2004,2005,2006,
-    2007,2008,2009,
-    2010,2011,2012,
-    2013,2014,2015,
-    2016,2017,2018,
-    2019,2020,2021,
-    2022,2023,2024,
-    2025,2026,2027,
-    2028,2029,2030,
-    2031,2032,2033,
-    2034,2035,2036,
-    2037,2038,2039,
-    2040,2041,2042,
-    2043,2044,2045,
-    2046,2047,2048,
-    2049,2050,2051,
-    2052,2053,2054,
-    2055,2056,2057,
-    2058,2059,2060,
-    2061,2062,2063,
-    2064,2065,2066,
-    2067,2068,2069,
-    2070,2071,2072,
-    2073,2074,2075,
-    2076,2077,2078,
-    2079,2080,2081,
-    2082,2083,2084,
-    2085,2086,2087,
-    2088,2089,2090,
-    2091,2092,2093,
-    2094,2095,2096,
-    2097,2098,2099,
-    2100,2101,2102,
-    2103,2104,2105,
-    2106,2107,2108,
-    2109,2110,2111,
-    2112,2113,2114,
-    2115,2116,2117,
-    2118,2119,2120,
-    2121,2122,2123,
-    2124,2125,2126,
-    2127,2128,2129,
-    2130,2131,2132,
-    2133,2134,2135,
-    2136,2137,2138,
-    2139,2140,2141,
-    2142,2143,2144,
-    2145,2146,2147,
-    2148,2149,2150,
-    2151,2152,2153,
-    2154,2155,2156,
-    2157,2158,2159,
-    2160,2161,2162,
-    2163,2164,2165,
-    2166,2167,2168,
-    2169,2170,2171,
-    2172,2173,2174,
-    2175,2176,2177,
-    2178,2179,2180,
-    2181,2182,2183,
-    2184,2185,2186,
-    2187,2188,2189,
-    2190,2191,2192,
-    2193,2194,2195,
-    2196,2197,2198,
-    2199,2200,2201,
-    2202,2203,2204,
-    2205,2206,2207,
-    2208,2209,2210,
-    2211,2212,2213,
-    2214,2215,2216,
-    2217,2218,2219,
-    2220,2221,2222,
-    2223,2224,2225,
-    2226,2227,2228,
-    2229,2230,2231,
-    2232,2233,2234,
-    2235,2236,2237,
-    2238,2239,2240,
-    2241,2242,2243,
-    2244,2245,2246,
-    2247,2248,2249,
-    2250,2251,2252,
-    2253,2254,2255,
-    2256,2257,2258,
-    2259,2260,2261,
-    2262,2263,2264,
-    2265,2266,2267,
-    2268,2269,2270,
-    2271,2272,2273,
-    2274,2275,2276,
-    2277,2278,2279,
-    2280,2281,2282,
-    2283,2284,2285,
-    2286,2287,2288,
-    2289,2290,2291,
-    2292,2293,2294,
-    2295,2296,2297,
-    2298,2299,2300,
-    2301,2302,2303,
-    2304,2305,2306,
-    2307,2308,2309,
-    2310,2311,2312,
-    2313,2314,2315,
-    2316,2317,2318,
-    2319,2320,2321,
-    2322,2323,2324,
-    2325,2326,2327,
-    2328,2329,2330,
-    2331,2332,2333,
-    2334,2335,2336,
-    2337,2338,2339,
-    2340,2341,2342,
-    2343,2344,2345,
-    2346,2347,2348,
-    2349,2350,2351,
-    2352,2353,2354,
-    2355,2356,2357,
-    2358,2359,2360,
-    2361,2362,2363,
-    2364,2365,2366,
-    2367,2368,2369,
-    2370,2371,2372,
-    2373,2374,2375,
-    2376,2377,2378,
-    2379,2380,2381,
-    2382,2383,2384,
-    2385,2386,2387,
-    2388,2389,2390,
-    2391,2392,2393,
-    2394,2395,2396,
-    2397,2398,2399,
-    2400,2401,2402,
-    2403,2404,2405,
-    2406,2407,2408,
-    2409,2410,2411,
-    2412,2413,2414,
-    2415,2416,2417,
-    2418,2419,2420,
-    2421,2422,2423,
-    2424,2425,2426,
-    2427,2428,2429,
-    2430,2431,2432,
-    2433,2434,2435,
-    2436,2437,2438,
-    2439,2440,2441,
-    2442,2443,2444,
-    2445,2446,2447,
-    2448,2449,2450,
-    2451,2452,2453,
-    2454,2455,2456,
-    2457,2458,2459,
-    2460,2461,2462,
-    2463,2464,2465,
-    2466,2467,2468,
-    2469,2470,2471,
-    2472,2473,2474,
-    2475,2476,2477,
-    2478,2479,2480,
-    2481,2482,2483,
-    2484,2485,2486,
-    2487,2488,2489,
-    2490,2491,2492,
-    2493,2494,2495,
-    2496,2497,2498,
-    2499,2500,2501,
-    2502,2503,2504,
-    2505,2506,2507,
-    2508,2509,2510,
-    2511,2512,2513,
-    2514,2515,2516,
-    2517,2518,2519,
-    2520,2521,2522,
-    2523,2524,2525,
-    2526,2527,2528,
-    2529,2530,2531,
-    2532,2533,2534,
-    2535,2536,2537,
-    2538,2539,2540,
-    2541,2542,2543,
-    2544,2545,2546,
-    2547,2548,2549,
-    2550,2551,2552,
-    2553,2554,2555,
-    2556,2557,2558,
-    2559,2560,2561,
-    2562,2563,2564,
-    2565,2566,2567,
-    2568,2569,2570,
-    2571,2572,2573,
-    2574,2575,2576,
-    2577,2578,2579,
-    2580,2581,2582,
-    2583,2584,2585,
-    2586,2587,2588,
-    2589,2590,2591,
-    2592,2593,2594,
-    2595,2596,2597,
-    2598,2599,2600,
-    2601,2602,2603,
-    2604,2605,2606,
-    2607,2608,2609,
-    2610,2611,2612,
-    2613,2614,2615,
-    2616,2617,2618,
-    2619,2620,2621,
-    2622,2623,2624,
-    2625,2626,2627,
-    2628,2629,2630,
-    2631,2632,2633,
-    2634,2635,2636,
-    2637,2638,2639,
-    2640,2641,2642,
-    2643,2644,2645,
-    2646,2647,2648,
-    2649,2650,2651,
-    2652,2653,2654,
-    2655,2656,2657,
-    2658,2659,2660,
-    2661,2662,2663,
-    2664,2665,2666,
-    2667,2668,2669,
-    2670,2671,2672,
-    2673,2674,2675,
-    2676,2677,2678,
-    2679,2680,2681,
-    2682,2683,2684,
-    2685,2686,2687,
-    2688,2689,2690,
-    2691,2692,2693,
-    2694,2695,2696,
-    2697,2698,2699,
-    2700,2701,2702,
-    2703,2704,2705,
-    2706,2707,2708,
-    2709,2710,2711,
-    2712,2713,2714,
-    2715,2716,2717,
-    2718,2719,2720,
-    2721,2722,2723,
-    2724,2725,2726,
-    2727,2728,2729,
-    2730,2731,2732,
-    2733,2734,2735,
-    2736,2737,2738,
-    2739,2740,2741,
-    2742,2743,2744,
-    2745,2746,2747,
-    2748,2749,2750,
-    2751,2752,2753,
-    2754,2755,2756,
-    2757,2758,2759,
-    2760,2761,2762,
-    2763,2764,2765,
-    2766,2767,2768,
-    2769,2770,2771,
-    2772,2773,2774,
-    2775,2776,2777,
-    2778,2779,2780,
-    2781,2782,2783,
-    2784,2785,2786,
-    2787,2788,2789,
-    2790,2791,2792,
-    2793,2794,2795,
-    2796,2797,2798,
-    2799,2800,2801,
-    2802,2803,2804,
-    2805,2806,2807,
-    2808,2809,2810,
-    2811,2812,2813,
-    2814,2815,2816,
-    2817,2818,2819,
-    2820,2821,2822,
-    2823,2824,2825,
-    2826,2827,2828,
-    2829,2830,2831,
-    2832,2833,2834,
-    2835,2836,2837,
-    2838,2839,2840,
-    2841,2842,2843,
-    2844,2845,2846,
-    2847,2848,2849,
-    2850,2851,2852,
-    2853,2854,2855,
-    2856,2857,2858,
-    2859,2860,2861,
-    2862,2863,2864,
-    2865,2866,2867,
-    2868,2869,2870,
-    2871,2872,2873,
-    2874,2875,2876,
-    2877,2878,2879,
-    2880,2881,2882,
-    2883,2884,2885,
-    2886,2887,2888,
-    2889,2890,2891,
-    2892,2893,2894,
-    2895,2896,2897,
-    2898,2899,2900,
-    2901,2902,2903,
-    2904,2905,2906,
-    2907,2908,2909,
-    2910,2911,2912,
-    2913,2914,2915,
-    2916,2917,2918,
-    2919,2920,2921,
-    2922,2923,2924,
-    2925,2926,2927,
-    2928,2929,2930,
-    2931,2932,2933,
-    2934,2935,2936,
-    2937,2938,2939,
-    2940,2941,2942,
-    2943,2944,2945,
-    2946,2947,2948,
-    2949,2950,2951,
-    2952,2953,2954,
-    2955,2956,2957,
-    2958,2959,2960,
-    2961,2962,2963,
-    2964,2965,2966,
-    2967,2968,2969,
-    2970,2971,2972,
-    2973,2974,2975
-];
-
-pub static NORMAL:  &'static [f32] = &[
-    -0.966742,-0.255752,0.000000,
-    -0.893014,-0.256345,-0.369882,
-    -0.893437,0.255996,-0.369102,
-    -0.893437,0.255996,-0.369102,
-    -0.966824,0.255443,0.000000,
-    -0.966742,-0.255752,0.000000,
-    -0.966824,0.255443,0.000000,
-    -0.893437,0.255996,-0.369102,
-    -0.083877,0.995843,-0.035507,
-    -0.083877,0.995843,-0.035507,
-    -0.092052,0.995754,0.000000,
-    -0.966824,0.255443,0.000000,
-    -0.092052,0.995754,0.000000,
-    -0.083877,0.995843,-0.035507,
-    0.629724,0.731860,0.260439,
-    0.629724,0.731860,0.260439,
-    0.682050,0.731305,0.000000,
-    -0.092052,0.995754,0.000000,
-    0.682050,0.731305,0.000000,
-    0.629724,0.731860,0.260439,
-    0.803725,0.493370,0.332584,
-    0.803725,0.493370,0.332584,
-    0.870301,0.492521,0.000000,
-    0.682050,0.731305,0.000000,
-    -0.893014,-0.256345,-0.369882,
-    -0.683407,-0.256728,-0.683407,
-    -0.683531,0.256068,-0.683531,
-    -0.683531,0.256068,-0.683531,
-    -0.893437,0.255996,-0.369102,
-    -0.893014,-0.256345,-0.369882,
-    -0.893437,0.255996,-0.369102,
-    -0.683531,0.256068,-0.683531,
-    -0.064925,0.995776,-0.064925,
-    -0.064925,0.995776,-0.064925,
-    -0.083877,0.995843,-0.035507,
-    -0.893437,0.255996,-0.369102,
-    -0.083877,0.995843,-0.035507,
-    -0.064925,0.995776,-0.064925,
-    0.481399,0.732469,0.481399,
-    0.481399,0.732469,0.481399,
-    0.629724,0.731860,0.260439,
-    -0.083877,0.995843,-0.035507,
-    0.629724,0.731860,0.260439,
-    0.481399,0.732469,0.481399,
-    0.614804,0.493997,0.614804,
-    0.614804,0.493997,0.614804,
-    0.803725,0.493370,0.332584,
-    0.629724,0.731860,0.260439,
-    -0.683407,-0.256728,-0.683407,
-    -0.369882,-0.256345,-0.893014,
-    -0.369102,0.255996,-0.893437,
-    -0.369102,0.255996,-0.893437,
-    -0.683531,0.256068,-0.683531,
-    -0.683407,-0.256728,-0.683407,
-    -0.683531,0.256068,-0.683531,
-    -0.369102,0.255996,-0.893437,
-    -0.035507,0.995843,-0.083877,
-    -0.035507,0.995843,-0.083877,
-    -0.064925,0.995776,-0.064925,
-    -0.683531,0.256068,-0.683531,
-    -0.064925,0.995776,-0.064925,
-    -0.035507,0.995843,-0.083877,
-    0.260439,0.731860,0.629724,
-    0.260439,0.731860,0.629724,
-    0.481399,0.732469,0.481399,
-    -0.064925,0.995776,-0.064925,
-    0.481399,0.732469,0.481399,
-    0.260439,0.731860,0.629724,
-    0.332584,0.493369,0.803725,
-    0.332584,0.493369,0.803725,
-    0.614804,0.493997,0.614804,
-    0.481399,0.732469,0.481399,
-    -0.369882,-0.256345,-0.893014,
-    -0.002848,-0.257863,-0.966177,
-    -0.001923,0.254736,-0.967009,
-    -0.001923,0.254736,-0.967009,
-    -0.369102,0.255996,-0.893437,
-    -0.369882,-0.256345,-0.893014,
-    -0.369102,0.255996,-0.893437,
-    -0.001923,0.254736,-0.967009,
-    -0.000266,0.995734,-0.092270,
-    -0.000266,0.995734,-0.092270,
-    -0.035507,0.995843,-0.083877,
-    -0.369102,0.255996,-0.893437,
-    -0.035507,0.995843,-0.083877,
-    -0.000266,0.995734,-0.092270,
-    0.000024,0.731295,0.682061,
-    0.000024,0.731295,0.682061,
-    0.260439,0.731860,0.629724,
-    -0.035507,0.995843,-0.083877,
-    0.260439,0.731860,0.629724,
-    0.000024,0.731295,0.682061,
-    0.000000,0.492521,0.870301,
-    0.000000,0.492521,0.870301,
-    0.332584,0.493369,0.803725,
-    0.260439,0.731860,0.629724,
-    -0.002848,-0.257863,-0.966177,
-    0.379058,-0.359300,-0.852771,
-    0.377110,0.149085,-0.914091,
-    0.377110,0.149085,-0.914091,
-    -0.001923,0.254736,-0.967009,
-    -0.002848,-0.257863,-0.966177,
-    -0.001923,0.254736,-0.967009,
-    0.377110,0.149085,-0.914091,
-    0.027502,0.992081,-0.122552,
-    0.027502,0.992081,-0.122552,
-    -0.000266,0.995734,-0.092270,
-    -0.001923,0.254736,-0.967009,
-    -0.000266,0.995734,-0.092270,
-    0.027502,0.992081,-0.122552,
-    -0.261010,0.726762,0.635367,
-    -0.261010,0.726762,0.635367,
-    0.000024,0.731295,0.682061,
-    -0.000266,0.995734,-0.092270,
-    0.000024,0.731295,0.682061,
-    -0.261010,0.726762,0.635367,
-    -0.332485,0.492546,0.804271,
-    -0.332485,0.492546,0.804271,
-    0.000000,0.492521,0.870301,
-    0.000024,0.731295,0.682061,
-    0.379058,-0.359300,-0.852771,
-    0.663548,-0.410790,-0.625264,
-    0.712664,0.073722,-0.697621,
-    0.712664,0.073722,-0.697621,
-    0.377110,0.149085,-0.914091,
-    0.379058,-0.359300,-0.852771,
-    0.377110,0.149085,-0.914091,
-    0.712664,0.073722,-0.697621,
-    0.099726,0.987509,-0.121983,
-    0.099726,0.987509,-0.121983,
-    0.027502,0.992081,-0.122552,
-    0.377110,0.149085,-0.914091,
-    0.027502,0.992081,-0.122552,
-    0.099726,0.987509,-0.121983,
-    -0.487320,0.723754,0.488569,
-    -0.487320,0.723754,0.488569,
-    -0.261010,0.726762,0.635367,
-    0.027502,0.992081,-0.122552,
-    -0.261010,0.726762,0.635367,
-    -0.487320,0.723754,0.488569,
-    -0.615242,0.492602,0.615484,
-    -0.615242,0.492602,0.615484,
-    -0.332485,0.492546,0.804271,
-    -0.261010,0.726762,0.635367,
-    0.663548,-0.410790,-0.625264,
-    0.880028,-0.332906,-0.338709,
-    0.917276,0.167113,-0.361493,
-    0.917276,0.167113,-0.361493,
-    0.712664,0.073722,-0.697621,
-    0.663548,-0.410790,-0.625264,
-    0.712664,0.073722,-0.697621,
-    0.917276,0.167113,-0.361493,
-    0.113584,0.992365,-0.048070,
-    0.113584,0.992365,-0.048070,
-    0.099726,0.987509,-0.121983,
-    0.712664,0.073722,-0.697621,
-    0.099726,0.987509,-0.121983,
-    0.113584,0.992365,-0.048070,
-    -0.634150,0.727508,0.261889,
-    -0.634150,0.727508,0.261889,
-    -0.487320,0.723754,0.488569,
-    0.099726,0.987509,-0.121983,
-    -0.487320,0.723754,0.488569,
-    -0.634150,0.727508,0.261889,
-    -0.804126,0.492634,0.332705,
-    -0.804126,0.492634,0.332705,
-    -0.615242,0.492602,0.615484,
-    -0.487320,0.723754,0.488569,
-    0.880028,-0.332906,-0.338709,
-    0.966690,-0.255738,0.010454,
-    0.967442,0.252962,0.008103,
-    0.967442,0.252962,0.008103,
-    0.917276,0.167113,-0.361493,
-    0.880028,-0.332906,-0.338709,
-    0.917276,0.167113,-0.361493,
-    0.967442,0.252962,0.008103,
-    0.093436,0.995624,0.001281,
-    0.093436,0.995624,0.001281,
-    0.113584,0.992365,-0.048070,
-    0.917276,0.167113,-0.361493,
-    0.113584,0.992365,-0.048070,
-    0.093436,0.995624,0.001281,
-    -0.682167,0.731196,-0.000343,
-    -0.682167,0.731196,-0.000343,
-    -0.634150,0.727508,0.261889,
-    0.113584,0.992365,-0.048070,
-    -0.634150,0.727508,0.261889,
-    -0.682167,0.731196,-0.000343,
-    -0.870322,0.492483,-0.000054,
-    -0.870322,0.492483,-0.000054,
-    -0.804126,0.492634,0.332705,
-    -0.634150,0.727508,0.261889,
-    0.966690,-0.255738,0.010454,
-    0.893014,-0.256345,0.369882,
-    0.893437,0.255996,0.369102,
-    0.893437,0.255996,0.369102,
-    0.967442,0.252962,0.008103,
-    0.966690,-0.255738,0.010454,
-    0.967442,0.252962,0.008103,
-    0.893437,0.255996,0.369102,
-    0.083877,0.995843,0.035507,
-    0.083877,0.995843,0.035507,
-    0.093436,0.995624,0.001281,
-    0.967442,0.252962,0.008103,
-    0.093436,0.995624,0.001281,
-    0.083877,0.995843,0.035507,
-    -0.629724,0.731860,-0.260439,
-    -0.629724,0.731860,-0.260439,
-    -0.682167,0.731196,-0.000343,
-    0.093436,0.995624,0.001281,
-    -0.682167,0.731196,-0.000343,
-    -0.629724,0.731860,-0.260439,
-    -0.803725,0.493370,-0.332584,
-    -0.803725,0.493370,-0.332584,
-    -0.870322,0.492483,-0.000054,
-    -0.682167,0.731196,-0.000343,
-    0.893014,-0.256345,0.369882,
-    0.683407,-0.256728,0.683407,
-    0.683531,0.256068,0.683531,
-    0.683531,0.256068,0.683531,
-    0.893437,0.255996,0.369102,
-    0.893014,-0.256345,0.369882,
-    0.893437,0.255996,0.369102,
-    0.683531,0.256068,0.683531,
-    0.064925,0.995776,0.064925,
-    0.064925,0.995776,0.064925,
-    0.083877,0.995843,0.035507,
-    0.893437,0.255996,0.369102,
-    0.083877,0.995843,0.035507,
-    0.064925,0.995776,0.064925,
-    -0.481399,0.732469,-0.481399,
-    -0.481399,0.732469,-0.481399,
-    -0.629724,0.731860,-0.260439,
-    0.083877,0.995843,0.035507,
-    -0.629724,0.731860,-0.260439,
-    -0.481399,0.732469,-0.481399,
-    -0.614804,0.493997,-0.614804,
-    -0.614804,0.493997,-0.614804,
-    -0.803725,0.493370,-0.332584,
-    -0.629724,0.731860,-0.260439,
-    0.683407,-0.256728,0.683407,
-    0.369882,-0.256345,0.893014,
-    0.369102,0.255996,0.893437,
-    0.369102,0.255996,0.893437,
-    0.683531,0.256068,0.683531,
-    0.683407,-0.256728,0.683407,
-    0.683531,0.256068,0.683531,
-    0.369102,0.255996,0.893437,
-    0.035507,0.995843,0.083877,
-    0.035507,0.995843,0.083877,
-    0.064925,0.995776,0.064925,
-    0.683531,0.256068,0.683531,
-    0.064925,0.995776,0.064925,
-    0.035507,0.995843,0.083877,
-    -0.260439,0.731860,-0.629724,
-    -0.260439,0.731860,-0.629724,
-    -0.481399,0.732469,-0.481399,
-    0.064925,0.995776,0.064925,
-    -0.481399,0.732469,-0.481399,
-    -0.260439,0.731860,-0.629724,
-    -0.332584,0.493369,-0.803725,
-    -0.332584,0.493369,-0.803725,
-    -0.614804,0.493997,-0.614804,
-    -0.481399,0.732469,-0.481399,
-    0.369882,-0.256345,0.893014,
-    0.000000,-0.255752,0.966742,
-    0.000000,0.255443,0.966824,
-    0.000000,0.255443,0.966824,
-    0.369102,0.255996,0.893437,
-    0.369882,-0.256345,0.893014,
-    0.369102,0.255996,0.893437,
-    0.000000,0.255443,0.966824,
-    0.000000,0.995754,0.092052,
-    0.000000,0.995754,0.092052,
-    0.035507,0.995843,0.083877,
-    0.369102,0.255996,0.893437,
-    0.035507,0.995843,0.083877,
-    0.000000,0.995754,0.092052,
-    0.000000,0.731305,-0.682050,
-    0.000000,0.731305,-0.682050,
-    -0.260439,0.731860,-0.629724,
-    0.035507,0.995843,0.083877,
-    -0.260439,0.731860,-0.629724,
-    0.000000,0.731305,-0.682050,
-    0.000000,0.492521,-0.870301,
-    0.000000,0.492521,-0.870301,
-    -0.332584,0.493369,-0.803725,
-    -0.260439,0.731860,-0.629724,
-    0.000000,-0.255752,0.966742,
-    -0.369882,-0.256345,0.893014,
-    -0.369102,0.255996,0.893437,
-    -0.369102,0.255996,0.893437,
-    0.000000,0.255443,0.966824,
-    0.000000,-0.255752,0.966742,
-    0.000000,0.255443,0.966824,
-    -0.369102,0.255996,0.893437,
-    -0.035507,0.995843,0.083877,
-    -0.035507,0.995843,0.083877,
-    0.000000,0.995754,0.092052,
-    0.000000,0.255443,0.966824,
-    0.000000,0.995754,0.092052,
-    -0.035507,0.995843,0.083877,
-    0.260439,0.731860,-0.629724,
-    0.260439,0.731860,-0.629724,
-    0.000000,0.731305,-0.682050,
-    0.000000,0.995754,0.092052,
-    0.000000,0.731305,-0.682050,
-    0.260439,0.731860,-0.629724,
-    0.332584,0.493370,-0.803725,
-    0.332584,0.493370,-0.803725,
-    0.000000,0.492521,-0.870301,
-    0.000000,0.731305,-0.682050,
-    -0.369882,-0.256345,0.893014,
-    -0.683407,-0.256728,0.683407,
-    -0.683531,0.256068,0.683531,
-    -0.683531,0.256068,0.683531,
-    -0.369102,0.255996,0.893437,
-    -0.369882,-0.256345,0.893014,
-    -0.369102,0.255996,0.893437,
-    -0.683531,0.256068,0.683531,
-    -0.064925,0.995776,0.064925,
-    -0.064925,0.995776,0.064925,
-    -0.035507,0.995843,0.083877,
-    -0.369102,0.255996,0.893437,
-    -0.035507,0.995843,0.083877,
-    -0.064925,0.995776,0.064925,
-    0.481399,0.732469,-0.481399,
-    0.481399,0.732469,-0.481399,
-    0.260439,0.731860,-0.629724,
-    -0.035507,0.995843,0.083877,
-    0.260439,0.731860,-0.629724,
-    0.481399,0.732469,-0.481399,
-    0.614804,0.493997,-0.614804,
-    0.614804,0.493997,-0.614804,
-    0.332584,0.493370,-0.803725,
-    0.260439,0.731860,-0.629724,
-    -0.683407,-0.256728,0.683407,
-    -0.893014,-0.256345,0.369882,
-    -0.893437,0.255996,0.369102,
-    -0.893437,0.255996,0.369102,
-    -0.683531,0.256068,0.683531,
-    -0.683407,-0.256728,0.683407,
-    -0.683531,0.256068,0.683531,
-    -0.893437,0.255996,0.369102,
-    -0.083877,0.995843,0.035507,
-    -0.083877,0.995843,0.035507,
-    -0.064925,0.995776,0.064925,
-    -0.683531,0.256068,0.683531,
-    -0.064925,0.995776,0.064925,
-    -0.083877,0.995843,0.035507,
-    0.629724,0.731860,-0.260439,
-    0.629724,0.731860,-0.260439,
-    0.481399,0.732469,-0.481399,
-    -0.064925,0.995776,0.064925,
-    0.481399,0.732469,-0.481399,
-    0.629724,0.731860,-0.260439,
-    0.803725,0.493369,-0.332584,
-    0.803725,0.493369,-0.332584,
-    0.614804,0.493997,-0.614804,
-    0.481399,0.732469,-0.481399,
-    -0.893014,-0.256345,0.369882,
-    -0.966742,-0.255752,0.000000,
-    -0.966824,0.255443,0.000000,
-    -0.966824,0.255443,0.000000,
-    -0.893437,0.255996,0.369102,
-    -0.893014,-0.256345,0.369882,
-    -0.893437,0.255996,0.369102,
-    -0.966824,0.255443,0.000000,
-    -0.092052,0.995754,0.000000,
-    -0.092052,0.995754,0.000000,
-    -0.083877,0.995843,0.035507,
-    -0.893437,0.255996,0.369102,
-    -0.083877,0.995843,0.035507,
-    -0.092052,0.995754,0.000000,
-    0.682050,0.731305,0.000000,
-    0.682050,0.731305,0.000000,
-    0.629724,0.731860,-0.260439,
-    -0.083877,0.995843,0.035507,
-    0.629724,0.731860,-0.260439,
-    0.682050,0.731305,0.000000,
-    0.870301,0.492521,0.000000,
-    0.870301,0.492521,0.000000,
-    0.803725,0.493369,-0.332584,
-    0.629724,0.731860,-0.260439,
-    0.870301,0.492521,0.000000,
-    0.803725,0.493370,0.332584,
-    0.845438,0.403546,0.349835,
-    0.845438,0.403546,0.349835,
-    0.915321,0.402725,0.000000,
-    0.870301,0.492521,0.000000,
-    0.915321,0.402725,0.000000,
-    0.845438,0.403546,0.349835,
-    0.869996,0.336859,0.360047,
-    0.869996,0.336859,0.360047,
-    0.941808,0.336151,0.000000,
-    0.915321,0.402725,0.000000,
-    0.941808,0.336151,0.000000,
-    0.869996,0.336859,0.360047,
-    0.904193,0.205791,0.374280,
-    0.904193,0.205791,0.374280,
-    0.978690,0.205342,0.000000,
-    0.941808,0.336151,0.000000,
-    0.978690,0.205342,0.000000,
-    0.904193,0.205791,0.374280,
-    0.921879,-0.066370,0.381752,
-    0.921879,-0.066370,0.381752,
-    0.997804,-0.066240,0.000000,
-    0.978690,0.205342,0.000000,
-    0.803725,0.493370,0.332584,
-    0.614804,0.493997,0.614804,
-    0.646802,0.404096,0.646802,
-    0.646802,0.404096,0.646802,
-    0.845438,0.403546,0.349835,
-    0.803725,0.493370,0.332584,
-    0.845438,0.403546,0.349835,
-    0.646802,0.404096,0.646802,
-    0.665655,0.337351,0.665655,
-    0.665655,0.337351,0.665655,
-    0.869996,0.336859,0.360047,
-    0.845438,0.403546,0.349835,
-    0.869996,0.336859,0.360047,
-    0.665655,0.337351,0.665655,
-    0.691923,0.206120,0.691923,
-    0.691923,0.206120,0.691923,
-    0.904193,0.205791,0.374280,
-    0.869996,0.336859,0.360047,
-    0.904193,0.205791,0.374280,
-    0.691923,0.206120,0.691923,
-    0.705543,-0.066480,0.705542,
-    0.705543,-0.066480,0.705542,
-    0.921879,-0.066370,0.381752,
-    0.904193,0.205791,0.374280,
-    0.614804,0.493997,0.614804,
-    0.332584,0.493369,0.803725,
-    0.349835,0.403546,0.845438,
-    0.349835,0.403546,0.845438,
-    0.646802,0.404096,0.646802,
-    0.614804,0.493997,0.614804,
-    0.646802,0.404096,0.646802,
-    0.349835,0.403546,0.845438,
-    0.360047,0.336859,0.869996,
-    0.360047,0.336859,0.869996,
-    0.665655,0.337351,0.665655,
-    0.646802,0.404096,0.646802,
-    0.665655,0.337351,0.665655,
-    0.360047,0.336859,0.869996,
-    0.374280,0.205791,0.904193,
-    0.374280,0.205791,0.904193,
-    0.691923,0.206120,0.691923,
-    0.665655,0.337351,0.665655,
-    0.691923,0.206120,0.691923,
-    0.374280,0.205791,0.904193,
-    0.381752,-0.066369,0.921879,
-    0.381752,-0.066369,0.921879,
-    0.705543,-0.066480,0.705542,
-    0.691923,0.206120,0.691923,
-    0.332584,0.493369,0.803725,
-    0.000000,0.492521,0.870301,
-    0.000000,0.402725,0.915321,
-    0.000000,0.402725,0.915321,
-    0.349835,0.403546,0.845438,
-    0.332584,0.493369,0.803725,
-    0.349835,0.403546,0.845438,
-    0.000000,0.402725,0.915321,
-    0.000000,0.336151,0.941808,
-    0.000000,0.336151,0.941808,
-    0.360047,0.336859,0.869996,
-    0.349835,0.403546,0.845438,
-    0.360047,0.336859,0.869996,
-    0.000000,0.336151,0.941808,
-    0.000000,0.205342,0.978690,
-    0.000000,0.205342,0.978690,
-    0.374280,0.205791,0.904193,
-    0.360047,0.336859,0.869996,
-    0.374280,0.205791,0.904193,
-    0.000000,0.205342,0.978690,
-    0.000000,-0.066240,0.997804,
-    0.000000,-0.066240,0.997804,
-    0.381752,-0.066369,0.921879,
-    0.374280,0.205791,0.904193,
-    0.000000,0.492521,0.870301,
-    -0.332485,0.492546,0.804271,
-    -0.349835,0.403546,0.845438,
-    -0.349835,0.403546,0.845438,
-    0.000000,0.402725,0.915321,
-    0.000000,0.492521,0.870301,
-    0.000000,0.402725,0.915321,
-    -0.349835,0.403546,0.845438,
-    -0.360047,0.336859,0.869996,
-    -0.360047,0.336859,0.869996,
-    0.000000,0.336151,0.941808,
-    0.000000,0.402725,0.915321,
-    0.000000,0.336151,0.941808,
-    -0.360047,0.336859,0.869996,
-    -0.374280,0.205791,0.904193,
-    -0.374280,0.205791,0.904193,
-    0.000000,0.205342,0.978690,
-    0.000000,0.336151,0.941808,
-    0.000000,0.205342,0.978690,
-    -0.374280,0.205791,0.904193,
-    -0.381752,-0.066370,0.921879,
-    -0.381752,-0.066370,0.921879,
-    0.000000,-0.066240,0.997804,
-    0.000000,0.205342,0.978690,
-    -0.332485,0.492546,0.804271,
-    -0.615242,0.492602,0.615484,
-    -0.646802,0.404096,0.646802,
-    -0.646802,0.404096,0.646802,
-    -0.349835,0.403546,0.845438,
-    -0.332485,0.492546,0.804271,
-    -0.349835,0.403546,0.845438,
-    -0.646802,0.404096,0.646802,
-    -0.665655,0.337351,0.665655,
-    -0.665655,0.337351,0.665655,
-    -0.360047,0.336859,0.869996,
-    -0.349835,0.403546,0.845438,
-    -0.360047,0.336859,0.869996,
-    -0.665655,0.337351,0.665655,
-    -0.691923,0.206120,0.691923,
-    -0.691923,0.206120,0.691923,
-    -0.374280,0.205791,0.904193,
-    -0.360047,0.336859,0.869996,
-    -0.374280,0.205791,0.904193,
-    -0.691923,0.206120,0.691923,
-    -0.705542,-0.066480,0.705543,
-    -0.705542,-0.066480,0.705543,
-    -0.381752,-0.066370,0.921879,
-    -0.374280,0.205791,0.904193,
-    -0.615242,0.492602,0.615484,
-    -0.804126,0.492634,0.332705,
-    -0.845438,0.403546,0.349835,
-    -0.845438,0.403546,0.349835,
-    -0.646802,0.404096,0.646802,
-    -0.615242,0.492602,0.615484,
-    -0.646802,0.404096,0.646802,
-    -0.845438,0.403546,0.349835,
-    -0.869996,0.336859,0.360047,
-    -0.869996,0.336859,0.360047,
-    -0.665655,0.337351,0.665655,
-    -0.646802,0.404096,0.646802,
-    -0.665655,0.337351,0.665655,
-    -0.869996,0.336859,0.360047,
-    -0.904193,0.205791,0.374280,
-    -0.904193,0.205791,0.374280,
-    -0.691923,0.206120,0.691923,
-    -0.665655,0.337351,0.665655,
-    -0.691923,0.206120,0.691923,
-    -0.904193,0.205791,0.374280,
-    -0.921879,-0.066369,0.381752,
-    -0.921879,-0.066369,0.381752,
-    -0.705542,-0.066480,0.705543,
-    -0.691923,0.206120,0.691923,
-    -0.804126,0.492634,0.332705,
-    -0.870322,0.492483,-0.000054,
-    -0.915321,0.402725,0.000000,
-    -0.915321,0.402725,0.000000,
-    -0.845438,0.403546,0.349835,
-    -0.804126,0.492634,0.332705,
-    -0.845438,0.403546,0.349835,
-    -0.915321,0.402725,0.000000,
-    -0.941808,0.336151,0.000000,
-    -0.941808,0.336151,0.000000,
-    -0.869996,0.336859,0.360047,
-    -0.845438,0.403546,0.349835,
-    -0.869996,0.336859,0.360047,
-    -0.941808,0.336151,0.000000,
-    -0.978690,0.205342,0.000000,
-    -0.978690,0.205342,0.000000,
-    -0.904193,0.205791,0.374280,
-    -0.869996,0.336859,0.360047,
-    -0.904193,0.205791,0.374280,
-    -0.978690,0.205342,0.000000,
-    -0.997804,-0.066240,0.000000,
-    -0.997804,-0.066240,0.000000,
-    -0.921879,-0.066369,0.381752,
-    -0.904193,0.205791,0.374280,
-    -0.870322,0.492483,-0.000054,
-    -0.803725,0.493370,-0.332584,
-    -0.845438,0.403546,-0.349835,
-    -0.845438,0.403546,-0.349835,
-    -0.915321,0.402725,0.000000,
-    -0.870322,0.492483,-0.000054,
-    -0.915321,0.402725,0.000000,
-    -0.845438,0.403546,-0.349835,
-    -0.869996,0.336859,-0.360047,
-    -0.869996,0.336859,-0.360047,
-    -0.941808,0.336151,0.000000,
-    -0.915321,0.402725,0.000000,
-    -0.941808,0.336151,0.000000,
-    -0.869996,0.336859,-0.360047,
-    -0.904193,0.205791,-0.374280,
-    -0.904193,0.205791,-0.374280,
-    -0.978690,0.205342,0.000000,
-    -0.941808,0.336151,0.000000,
-    -0.978690,0.205342,0.000000,
-    -0.904193,0.205791,-0.374280,
-    -0.921879,-0.066370,-0.381752,
-    -0.921879,-0.066370,-0.381752,
-    -0.997804,-0.066240,0.000000,
-    -0.978690,0.205342,0.000000,
-    -0.803725,0.493370,-0.332584,
-    -0.614804,0.493997,-0.614804,
-    -0.646802,0.404096,-0.646802,
-    -0.646802,0.404096,-0.646802,
-    -0.845438,0.403546,-0.349835,
-    -0.803725,0.493370,-0.332584,
-    -0.845438,0.403546,-0.349835,
-    -0.646802,0.404096,-0.646802,
-    -0.665655,0.337351,-0.665655,
-    -0.665655,0.337351,-0.665655,
-    -0.869996,0.336859,-0.360047,
-    -0.845438,0.403546,-0.349835,
-    -0.869996,0.336859,-0.360047,
-    -0.665655,0.337351,-0.665655,
-    -0.691923,0.206120,-0.691923,
-    -0.691923,0.206120,-0.691923,
-    -0.904193,0.205791,-0.374280,
-    -0.869996,0.336859,-0.360047,
-    -0.904193,0.205791,-0.374280,
-    -0.691923,0.206120,-0.691923,
-    -0.705543,-0.066480,-0.705542,
-    -0.705543,-0.066480,-0.705542,
-    -0.921879,-0.066370,-0.381752,
-    -0.904193,0.205791,-0.374280,
-    -0.614804,0.493997,-0.614804,
-    -0.332584,0.493369,-0.803725,
-    -0.349835,0.403546,-0.845438,
-    -0.349835,0.403546,-0.845438,
-    -0.646802,0.404096,-0.646802,
-    -0.614804,0.493997,-0.614804,
-    -0.646802,0.404096,-0.646802,
-    -0.349835,0.403546,-0.845438,
-    -0.360047,0.336859,-0.869996,
-    -0.360047,0.336859,-0.869996,
-    -0.665655,0.337351,-0.665655,
-    -0.646802,0.404096,-0.646802,
-    -0.665655,0.337351,-0.665655,
-    -0.360047,0.336859,-0.869996,
-    -0.374280,0.205791,-0.904193,
-    -0.374280,0.205791,-0.904193,
-    -0.691923,0.206120,-0.691923,
-    -0.665655,0.337351,-0.665655,
-    -0.691923,0.206120,-0.691923,
-    -0.374280,0.205791,-0.904193,
-    -0.381752,-0.066369,-0.921879,
-    -0.381752,-0.066369,-0.921879,
-    -0.705543,-0.066480,-0.705542,
-    -0.691923,0.206120,-0.691923,
-    -0.332584,0.493369,-0.803725,
-    0.000000,0.492521,-0.870301,
-    0.000000,0.402725,-0.915321,
-    0.000000,0.402725,-0.915321,
-    -0.349835,0.403546,-0.845438,
-    -0.332584,0.493369,-0.803725,
-    -0.349835,0.403546,-0.845438,
-    0.000000,0.402725,-0.915321,
-    0.000000,0.336151,-0.941808,
-    0.000000,0.336151,-0.941808,
-    -0.360047,0.336859,-0.869996,
-    -0.349835,0.403546,-0.845438,
-    -0.360047,0.336859,-0.869996,
-    0.000000,0.336151,-0.941808,
-    0.000000,0.205342,-0.978690,
-    0.000000,0.205342,-0.978690,
-    -0.374280,0.205791,-0.904193,
-    -0.360047,0.336859,-0.869996,
-    -0.374280,0.205791,-0.904193,
-    0.000000,0.205342,-0.978690,
-    0.000000,-0.066240,-0.997804,
-    0.000000,-0.066240,-0.997804,
-    -0.381752,-0.066369,-0.921879,
-    -0.374280,0.205791,-0.904193,
-    0.000000,0.492521,-0.870301,
-    0.332584,0.493370,-0.803725,
-    0.349835,0.403546,-0.845438,
-    0.349835,0.403546,-0.845438,
-    0.000000,0.402725,-0.915321,
-    0.000000,0.492521,-0.870301,
-    0.000000,0.402725,-0.915321,
-    0.349835,0.403546,-0.845438,
-    0.360047,0.336859,-0.869996,
-    0.360047,0.336859,-0.869996,
-    0.000000,0.336151,-0.941808,
-    0.000000,0.402725,-0.915321,
-    0.000000,0.336151,-0.941808,
-    0.360047,0.336859,-0.869996,
-    0.374280,0.205791,-0.904193,
-    0.374280,0.205791,-0.904193,
-    0.000000,0.205342,-0.978690,
-    0.000000,0.336151,-0.941808,
-    0.000000,0.205342,-0.978690,
-    0.374280,0.205791,-0.904193,
-    0.381752,-0.066370,-0.921879,
-    0.381752,-0.066370,-0.921879,
-    0.000000,-0.066240,-0.997804,
-    0.000000,0.205342,-0.978690,
-    0.332584,0.493370,-0.803725,
-    0.614804,0.493997,-0.614804,
-    0.646802,0.404096,-0.646802,
-    0.646802,0.404096,-0.646802,
-    0.349835,0.403546,-0.845438,
-    0.332584,0.493370,-0.803725,
-    0.349835,0.403546,-0.845438,
-    0.646802,0.404096,-0.646802,
-    0.665655,0.337351,-0.665655,
-    0.665655,0.337351,-0.665655,
-    0.360047,0.336859,-0.869996,
-    0.349835,0.403546,-0.845438,
-    0.360047,0.336859,-0.869996,
-    0.665655,0.337351,-0.665655,
-    0.691923,0.206120,-0.691923,
-    0.691923,0.206120,-0.691923,
-    0.374280,0.205791,-0.904193,
-    0.360047,0.336859,-0.869996,
-    0.374280,0.205791,-0.904193,
-    0.691923,0.206120,-0.691923,
-    0.705542,-0.066480,-0.705543,
-    0.705542,-0.066480,-0.705543,
-    0.381752,-0.066370,-0.921879,
-    0.374280,0.205791,-0.904193,
-    0.614804,0.493997,-0.614804,
-    0.803725,0.493369,-0.332584,
-    0.845438,0.403546,-0.349835,
-    0.845438,0.403546,-0.349835,
-    0.646802,0.404096,-0.646802,
-    0.614804,0.493997,-0.614804,
-    0.646802,0.404096,-0.646802,
-    0.845438,0.403546,-0.349835,
-    0.869996,0.336859,-0.360047,
-    0.869996,0.336859,-0.360047,
-    0.665655,0.337351,-0.665655,
-    0.646802,0.404096,-0.646802,
-    0.665655,0.337351,-0.665655,
-    0.869996,0.336859,-0.360047,
-    0.904193,0.205791,-0.374280,
-    0.904193,0.205791,-0.374280,
-    0.691923,0.206120,-0.691923,
-    0.665655,0.337351,-0.665655,
-    0.691923,0.206120,-0.691923,
-    0.904193,0.205791,-0.374280,
-    0.921879,-0.066369,-0.381752,
-    0.921879,-0.066369,-0.381752,
-    0.705542,-0.066480,-0.705543,
-    0.691923,0.206120,-0.691923,
-    0.803725,0.493369,-0.332584,
-    0.870301,0.492521,0.000000,
-    0.915321,0.402725,0.000000,
-    0.915321,0.402725,0.000000,
-    0.845438,0.403546,-0.349835,
-    0.803725,0.493369,-0.332584,
-    0.845438,0.403546,-0.349835,
-    0.915321,0.402725,0.000000,
-    0.941808,0.336151,0.000000,
-    0.941808,0.336151,0.000000,
-    0.869996,0.336859,-0.360047,
-    0.845438,0.403546,-0.349835,
-    0.869996,0.336859,-0.360047,
-    0.941808,0.336151,0.000000,
-    0.978690,0.205342,0.000000,
-    0.978690,0.205342,0.000000,
-    0.904193,0.205791,-0.374280,
-    0.869996,0.336859,-0.360047,
-    0.904193,0.205791,-0.374280,
-    0.978690,0.205342,0.000000,
-    0.997804,-0.066240,0.000000,
-    0.997804,-0.066240,0.000000,
-    0.921879,-0.066369,-0.381752,
-    0.904193,0.205791,-0.374280,
-    0.997804,-0.066240,0.000000,
-    0.921879,-0.066370,0.381752,
-    0.831437,-0.436180,0.344179,
-    0.831437,-0.436180,0.344179,
-    0.900182,-0.435513,0.000000,
-    0.997804,-0.066240,0.000000,
-    0.900182,-0.435513,0.000000,
-    0.831437,-0.436180,0.344179,
-    0.673512,-0.684666,0.278594,
-    0.673512,-0.684666,0.278594,
-    0.729611,-0.683863,0.000000,
-    0.900182,-0.435513,0.000000,
-    0.729611,-0.683863,0.000000,
-    0.673512,-0.684666,0.278594,
-    0.640399,-0.720924,0.264874,
-    0.640399,-0.720924,0.264874,
-    0.693951,-0.720022,0.000000,
-    0.729611,-0.683863,0.000000,
-    0.693951,-0.720022,0.000000,
-    0.640399,-0.720924,0.264874,
-    0.732949,-0.608995,0.303167,
-    0.732949,-0.608995,0.303167,
-    0.793950,-0.607983,0.000000,
-    0.693951,-0.720022,0.000000,
-    0.921879,-0.066370,0.381752,
-    0.705543,-0.066480,0.705542,
-    0.636092,-0.436778,0.636092,
-    0.636092,-0.436778,0.636092,
-    0.831437,-0.436180,0.344179,
-    0.921879,-0.066370,0.381752,
-    0.831437,-0.436180,0.344179,
-    0.636092,-0.436778,0.636092,
-    0.514965,-0.685290,0.514965,
-    0.514965,-0.685290,0.514965,
-    0.673512,-0.684666,0.278594,
-    0.831437,-0.436180,0.344179,
-    0.673512,-0.684666,0.278594,
-    0.514965,-0.685290,0.514965,
-    0.489651,-0.721446,0.489651,
-    0.489651,-0.721446,0.489651,
-    0.640399,-0.720924,0.264874,
-    0.673512,-0.684666,0.278594,
-    0.640399,-0.720924,0.264874,
-    0.489651,-0.721446,0.489651,
-    0.560555,-0.609554,0.560555,
-    0.560555,-0.609554,0.560555,
-    0.732949,-0.608995,0.303167,
-    0.640399,-0.720924,0.264874,
-    0.705543,-0.066480,0.705542,
-    0.381752,-0.066369,0.921879,
-    0.344179,-0.436180,0.831437,
-    0.344179,-0.436180,0.831437,
-    0.636092,-0.436778,0.636092,
-    0.705543,-0.066480,0.705542,
-    0.636092,-0.436778,0.636092,
-    0.344179,-0.436180,0.831437,
-    0.278595,-0.684666,0.673512,
-    0.278595,-0.684666,0.673512,
-    0.514965,-0.685290,0.514965,
-    0.636092,-0.436778,0.636092,
-    0.514965,-0.685290,0.514965,
-    0.278595,-0.684666,0.673512,
-    0.264874,-0.720924,0.640399,
-    0.264874,-0.720924,0.640399,
-    0.489651,-0.721446,0.489651,
-    0.514965,-0.685290,0.514965,
-    0.489651,-0.721446,0.489651,
-    0.264874,-0.720924,0.640399,
-    0.303167,-0.608995,0.732949,
-    0.303167,-0.608995,0.732949,
-    0.560555,-0.609554,0.560555,
-    0.489651,-0.721446,0.489651,
-    0.381752,-0.066369,0.921879,
-    0.000000,-0.066240,0.997804,
-    0.000000,-0.435513,0.900182,
-    0.000000,-0.435513,0.900182,
-    0.344179,-0.436180,0.831437,
-    0.381752,-0.066369,0.921879,
-    0.344179,-0.436180,0.831437,
-    0.000000,-0.435513,0.900182,
-    0.000000,-0.683863,0.729611,
-    0.000000,-0.683863,0.729611,
-    0.278595,-0.684666,0.673512,
-    0.344179,-0.436180,0.831437,
-    0.278595,-0.684666,0.673512,
-    0.000000,-0.683863,0.729611,
-    0.000000,-0.720022,0.693951,
-    0.000000,-0.720022,0.693951,
-    0.264874,-0.720924,0.640399,
-    0.278595,-0.684666,0.673512,
-    0.264874,-0.720924,0.640399,
-    0.000000,-0.720022,0.693951,
-    0.000000,-0.607983,0.793950,
-    0.000000,-0.607983,0.793950,
-    0.303167,-0.608995,0.732949,
-    0.264874,-0.720924,0.640399,
-    0.000000,-0.066240,0.997804,
-    -0.381752,-0.066370,0.921879,
-    -0.344179,-0.436180,0.831437,
-    -0.344179,-0.436180,0.831437,
-    0.000000,-0.435513,0.900182,
-    0.000000,-0.066240,0.997804,
-    0.000000,-0.435513,0.900182,
-    -0.344179,-0.436180,0.831437,
-    -0.278594,-0.684666,0.673512,
-    -0.278594,-0.684666,0.673512,
-    0.000000,-0.683863,0.729611,
-    0.000000,-0.435513,0.900182,
-    0.000000,-0.683863,0.729611,
-    -0.278594,-0.684666,0.673512,
-    -0.264874,-0.720924,0.640399,
-    -0.264874,-0.720924,0.640399,
-    0.000000,-0.720022,0.693951,
-    0.000000,-0.683863,0.729611,
-    0.000000,-0.720022,0.693951,
-    -0.264874,-0.720924,0.640399,
-    -0.303167,-0.608995,0.732949,
-    -0.303167,-0.608995,0.732949,
-    0.000000,-0.607983,0.793950,
-    0.000000,-0.720022,0.693951,
-    -0.381752,-0.066370,0.921879,
-    -0.705542,-0.066480,0.705543,
-    -0.636092,-0.436778,0.636092,
-    -0.636092,-0.436778,0.636092,
-    -0.344179,-0.436180,0.831437,
-    -0.381752,-0.066370,0.921879,
-    -0.344179,-0.436180,0.831437,
-    -0.636092,-0.436778,0.636092,
-    -0.514965,-0.685290,0.514965,
-    -0.514965,-0.685290,0.514965,
-    -0.278594,-0.684666,0.673512,
-    -0.344179,-0.436180,0.831437,
-    -0.278594,-0.684666,0.673512,
-    -0.514965,-0.685290,0.514965,
-    -0.489651,-0.721446,0.489651,
-    -0.489651,-0.721446,0.489651,
-    -0.264874,-0.720924,0.640399,
-    -0.278594,-0.684666,0.673512,
-    -0.264874,-0.720924,0.640399,
-    -0.489651,-0.721446,0.489651,
-    -0.560555,-0.609554,0.560555,
-    -0.560555,-0.609554,0.560555,
-    -0.303167,-0.608995,0.732949,
-    -0.264874,-0.720924,0.640399,
-    -0.705542,-0.066480,0.705543,
-    -0.921879,-0.066369,0.381752,
-    -0.831437,-0.436180,0.344179,
-    -0.831437,-0.436180,0.344179,
-    -0.636092,-0.436778,0.636092,
-    -0.705542,-0.066480,0.705543,
-    -0.636092,-0.436778,0.636092,
-    -0.831437,-0.436180,0.344179,
-    -0.673512,-0.684666,0.278595,
-    -0.673512,-0.684666,0.278595,
-    -0.514965,-0.685290,0.514965,
-    -0.636092,-0.436778,0.636092,
-    -0.514965,-0.685290,0.514965,
-    -0.673512,-0.684666,0.278595,
-    -0.640399,-0.720924,0.264874,
-    -0.640399,-0.720924,0.264874,
-    -0.489651,-0.721446,0.489651,
-    -0.514965,-0.685290,0.514965,
-    -0.489651,-0.721446,0.489651,
-    -0.640399,-0.720924,0.264874,
-    -0.732949,-0.608995,0.303167,
-    -0.732949,-0.608995,0.303167,
-    -0.560555,-0.609554,0.560555,
-    -0.489651,-0.721446,0.489651,
-    -0.921879,-0.066369,0.381752,
-    -0.997804,-0.066240,0.000000,
-    -0.900182,-0.435513,0.000000,
-    -0.900182,-0.435513,0.000000,
-    -0.831437,-0.436180,0.344179,
-    -0.921879,-0.066369,0.381752,
-    -0.831437,-0.436180,0.344179,
-    -0.900182,-0.435513,0.000000,
-    -0.729611,-0.683863,0.000000,
-    -0.729611,-0.683863,0.000000,
-    -0.673512,-0.684666,0.278595,
-    -0.831437,-0.436180,0.344179,
-    -0.673512,-0.684666,0.278595,
-    -0.729611,-0.683863,0.000000,
-    -0.693951,-0.720022,0.000000,
-    -0.693951,-0.720022,0.000000,
-    -0.640399,-0.720924,0.264874,
-    -0.673512,-0.684666,0.278595,
-    -0.640399,-0.720924,0.264874,
-    -0.693951,-0.720022,0.000000,
-    -0.793950,-0.607983,0.000000,
-    -0.793950,-0.607983,0.000000,
-    -0.732949,-0.608995,0.303167,
-    -0.640399,-0.720924,0.264874,
-    -0.997804,-0.066240,0.000000,
-    -0.921879,-0.066370,-0.381752,
-    -0.831437,-0.436180,-0.344179,
-    -0.831437,-0.436180,-0.344179,
-    -0.900182,-0.435513,0.000000,
-    -0.997804,-0.066240,0.000000,
-    -0.900182,-0.435513,0.000000,
-    -0.831437,-0.436180,-0.344179,
-    -0.673512,-0.684666,-0.278594,
-    -0.673512,-0.684666,-0.278594,
-    -0.729611,-0.683863,0.000000,
-    -0.900182,-0.435513,0.000000,
-    -0.729611,-0.683863,0.000000,
-    -0.673512,-0.684666,-0.278594,
-    -0.640399,-0.720924,-0.264874,
-    -0.640399,-0.720924,-0.264874,
-    -0.693951,-0.720022,0.000000,
-    -0.729611,-0.683863,0.000000,
-    -0.693951,-0.720022,0.000000,
-    -0.640399,-0.720924,-0.264874,
-    -0.732949,-0.608995,-0.303167,
-    -0.732949,-0.608995,-0.303167,
-    -0.793950,-0.607983,0.000000,
-    -0.693951,-0.720022,0.000000,
-    -0.921879,-0.066370,-0.381752,
-    -0.705543,-0.066480,-0.705542,
-    -0.636092,-0.436778,-0.636092,
-    -0.636092,-0.436778,-0.636092,
-    -0.831437,-0.436180,-0.344179,
-    -0.921879,-0.066370,-0.381752,
-    -0.831437,-0.436180,-0.344179,
-    -0.636092,-0.436778,-0.636092,
-    -0.514965,-0.685290,-0.514965,
-    -0.514965,-0.685290,-0.514965,
-    -0.673512,-0.684666,-0.278594,
-    -0.831437,-0.436180,-0.344179,
-    -0.673512,-0.684666,-0.278594,
-    -0.514965,-0.685290,-0.514965,
-    -0.489651,-0.721446,-0.489651,
-    -0.489651,-0.721446,-0.489651,
-    -0.640399,-0.720924,-0.264874,
-    -0.673512,-0.684666,-0.278594,
-    -0.640399,-0.720924,-0.264874,
-    -0.489651,-0.721446,-0.489651,
-    -0.560555,-0.609554,-0.560555,
-    -0.560555,-0.609554,-0.560555,
-    -0.732949,-0.608995,-0.303167,
-    -0.640399,-0.720924,-0.264874,
-    -0.705543,-0.066480,-0.705542,
-    -0.381752,-0.066369,-0.921879,
-    -0.344179,-0.436180,-0.831437,
-    -0.344179,-0.436180,-0.831437,
-    -0.636092,-0.436778,-0.636092,
-    -0.705543,-0.066480,-0.705542,
-    -0.636092,-0.436778,-0.636092,
-    -0.344179,-0.436180,-0.831437,
-    -0.278595,-0.684666,-0.673512,
-    -0.278595,-0.684666,-0.673512,
-    -0.514965,-0.685290,-0.514965,
-    -0.636092,-0.436778,-0.636092,
-    -0.514965,-0.685290,-0.514965,
-    -0.278595,-0.684666,-0.673512,
-    -0.264874,-0.720924,-0.640399,
-    -0.264874,-0.720924,-0.640399,
-    -0.489651,-0.721446,-0.489651,
-    -0.514965,-0.685290,-0.514965,
-    -0.489651,-0.721446,-0.489651,
-    -0.264874,-0.720924,-0.640399,
-    -0.303167,-0.608995,-0.732949,
-    -0.303167,-0.608995,-0.732949,
-    -0.560555,-0.609554,-0.560555,
-    -0.489651,-0.721446,-0.489651,
-    -0.381752,-0.066369,-0.921879,
-    0.000000,-0.066240,-0.997804,
-    0.000000,-0.435513,-0.900182,
-    0.000000,-0.435513,-0.900182,
-    -0.344179,-0.436180,-0.831437,
-    -0.381752,-0.066369,-0.921879,
-    -0.344179,-0.436180,-0.831437,
-    0.000000,-0.435513,-0.900182,
-    0.000000,-0.683863,-0.729611,
-    0.000000,-0.683863,-0.729611,
-    -0.278595,-0.684666,-0.673512,
-    -0.344179,-0.436180,-0.831437,
-    -0.278595,-0.684666,-0.673512,
-    0.000000,-0.683863,-0.729611,
-    0.000000,-0.720022,-0.693951,
-    0.000000,-0.720022,-0.693951,
-    -0.264874,-0.720924,-0.640399,
-    -0.278595,-0.684666,-0.673512,
-    -0.264874,-0.720924,-0.640399,
-    0.000000,-0.720022,-0.693951,
-    0.000000,-0.607983,-0.793950,
-    0.000000,-0.607983,-0.793950,
-    -0.303167,-0.608995,-0.732949,
-    -0.264874,-0.720924,-0.640399,
-    0.000000,-0.066240,-0.997804,
-    0.381752,-0.066370,-0.921879,
-    0.344179,-0.436180,-0.831437,
-    0.344179,-0.436180,-0.831437,
-    0.000000,-0.435513,-0.900182,
-    0.000000,-0.066240,-0.997804,
-    0.000000,-0.435513,-0.900182,
-    0.344179,-0.436180,-0.831437,
-    0.278594,-0.684666,-0.673512,
-    0.278594,-0.684666,-0.673512,
-    0.000000,-0.683863,-0.729611,
-    0.000000,-0.435513,-0.900182,
-    0.000000,-0.683863,-0.729611,
-    0.278594,-0.684666,-0.673512,
-    0.264874,-0.720924,-0.640399,
-    0.264874,-0.720924,-0.640399,
-    0.000000,-0.720022,-0.693951,
-    0.000000,-0.683863,-0.729611,
-    0.000000,-0.720022,-0.693951,
-    0.264874,-0.720924,-0.640399,
-    0.303167,-0.608995,-0.732949,
-    0.303167,-0.608995,-0.732949,
-    0.000000,-0.607983,-0.793950,
-    0.000000,-0.720022,-0.693951,
-    0.381752,-0.066370,-0.921879,
-    0.705542,-0.066480,-0.705543,
-    0.636092,-0.436778,-0.636092,
-    0.636092,-0.436778,-0.636092,
-    0.344179,-0.436180,-0.831437,
-    0.381752,-0.066370,-0.921879,
-    0.344179,-0.436180,-0.831437,
-    0.636092,-0.436778,-0.636092,
-    0.514965,-0.685290,-0.514965,
-    0.514965,-0.685290,-0.514965,
-    0.278594,-0.684666,-0.673512,
-    0.344179,-0.436180,-0.831437,
-    0.278594,-0.684666,-0.673512,
-    0.514965,-0.685290,-0.514965,
-    0.489651,-0.721446,-0.489651,
-    0.489651,-0.721446,-0.489651,
-    0.264874,-0.720924,-0.640399,
-    0.278594,-0.684666,-0.673512,
-    0.264874,-0.720924,-0.640399,
-    0.489651,-0.721446,-0.489651,
-    0.560555,-0.609554,-0.560555,
-    0.560555,-0.609554,-0.560555,
-    0.303167,-0.608995,-0.732949,
-    0.264874,-0.720924,-0.640399,
-    0.705542,-0.066480,-0.705543,
-    0.921879,-0.066369,-0.381752,
-    0.831437,-0.436180,-0.344179,
-    0.831437,-0.436180,-0.344179,
-    0.636092,-0.436778,-0.636092,
-    0.705542,-0.066480,-0.705543,
-    0.636092,-0.436778,-0.636092,
-    0.831437,-0.436180,-0.344179,
-    0.673512,-0.684666,-0.278595,
-    0.673512,-0.684666,-0.278595,
-    0.514965,-0.685290,-0.514965,
-    0.636092,-0.436778,-0.636092,
-    0.514965,-0.685290,-0.514965,
-    0.673512,-0.684666,-0.278595,
-    0.640399,-0.720924,-0.264874,
-    0.640399,-0.720924,-0.264874,
-    0.489651,-0.721446,-0.489651,
-    0.514965,-0.685290,-0.514965,
-    0.489651,-0.721446,-0.489651,
-    0.640399,-0.720924,-0.264874,
-    0.732949,-0.608995,-0.303167,
-    0.732949,-0.608995,-0.303167,
-    0.560555,-0.609554,-0.560555,
-    0.489651,-0.721446,-0.489651,
-    0.921879,-0.066369,-0.381752,
-    0.997804,-0.066240,0.000000,
-    0.900182,-0.435513,0.000000,
-    0.900182,-0.435513,0.000000,
-    0.831437,-0.436180,-0.344179,
-    0.921879,-0.066369,-0.381752,
-    0.831437,-0.436180,-0.344179,
-    0.900182,-0.435513,0.000000,
-    0.729611,-0.683863,0.000000,
-    0.729611,-0.683863,0.000000,
-    0.673512,-0.684666,-0.278595,
-    0.831437,-0.436180,-0.344179,
-    0.673512,-0.684666,-0.278595,
-    0.729611,-0.683863,0.000000,
-    0.693951,-0.720022,0.000000,
-    0.693951,-0.720022,0.000000,
-    0.640399,-0.720924,-0.264874,
-    0.673512,-0.684666,-0.278595,
-    0.640399,-0.720924,-0.264874,
-    0.693951,-0.720022,0.000000,
-    0.793950,-0.607983,0.000000,
-    0.793950,-0.607983,0.000000,
-    0.732949,-0.608995,-0.303167,
-    0.640399,-0.720924,-0.264874,
-    0.793950,-0.607983,0.000000,
-    0.732949,-0.608995,0.303167,
-    0.576230,-0.781801,0.238217,
-    0.576230,-0.781801,0.238217,
-    0.623860,-0.781536,0.000000,
-    0.793950,-0.607983,0.000000,
-    0.623860,-0.781536,0.000000,
-    0.576230,-0.781801,0.238217,
-    0.163628,-0.984208,0.067527,
-    0.163628,-0.984208,0.067527,
-    0.177291,-0.984159,0.000000,
-    0.623860,-0.781536,0.000000,
-    0.177291,-0.984159,0.000000,
-    0.163628,-0.984208,0.067527,
-    0.045422,-0.998792,0.018736,
-    0.045422,-0.998792,0.018736,
-    0.049207,-0.998789,0.000000,
-    0.177291,-0.984159,0.000000,
-    0.049207,-0.998789,0.000000,
-    0.045422,-0.998792,0.018736,
-    0.000000,-1.000000,0.000000,
-    0.732949,-0.608995,0.303167,
-    0.560555,-0.609554,0.560555,
-    0.440416,-0.782348,0.440416,
-    0.440416,-0.782348,0.440416,
-    0.576230,-0.781801,0.238217,
-    0.732949,-0.608995,0.303167,
-    0.576230,-0.781801,0.238217,
-    0.440416,-0.782348,0.440416,
-    0.124903,-0.984276,0.124903,
-    0.124903,-0.984276,0.124903,
-    0.163628,-0.984208,0.067527,
-    0.576230,-0.781801,0.238217,
-    0.163628,-0.984208,0.067527,
-    0.124903,-0.984276,0.124903,
-    0.034662,-0.998798,0.034662,
-    0.034662,-0.998798,0.034662,
-    0.045422,-0.998792,0.018736,
-    0.163628,-0.984208,0.067527,
-    0.045422,-0.998792,0.018736,
-    0.034662,-0.998798,0.034662,
-    0.000000,-1.000000,0.000000,
-    0.560555,-0.609554,0.560555,
-    0.303167,-0.608995,0.732949,
-    0.238217,-0.781801,0.576230,
-    0.238217,-0.781801,0.576230,
-    0.440416,-0.782348,0.440416,
-    0.560555,-0.609554,0.560555,
-    0.440416,-0.782348,0.440416,
-    0.238217,-0.781801,0.576230,
-    0.067527,-0.984208,0.163628,
-    0.067527,-0.984208,0.163628,
-    0.124903,-0.984276,0.124903,
-    0.440416,-0.782348,0.440416,
-    0.124903,-0.984276,0.124903,
-    0.067527,-0.984208,0.163628,
-    0.018736,-0.998792,0.045422,
-    0.018736,-0.998792,0.045422,
-    0.034662,-0.998798,0.034662,
-    0.124903,-0.984276,0.124903,
-    0.034662,-0.998798,0.034662,
-    0.018736,-0.998792,0.045422,
-    0.000000,-1.000000,0.000000,
-    0.303167,-0.608995,0.732949,
-    0.000000,-0.607983,0.793950,
-    0.000000,-0.781536,0.623860,
-    0.000000,-0.781536,0.623860,
-    0.238217,-0.781801,0.576230,
-    0.303167,-0.608995,0.732949,
-    0.238217,-0.781801,0.576230,
-    0.000000,-0.781536,0.623860,
-    0.000000,-0.984159,0.177291,
-    0.000000,-0.984159,0.177291,
-    0.067527,-0.984208,0.163628,
-    0.238217,-0.781801,0.576230,
-    0.067527,-0.984208,0.163628,
-    0.000000,-0.984159,0.177291,
-    0.000000,-0.998789,0.049207,
-    0.000000,-0.998789,0.049207,
-    0.018736,-0.998792,0.045422,
-    0.067527,-0.984208,0.163628,
-    0.018736,-0.998792,0.045422,
-    0.000000,-0.998789,0.049207,
-    0.000000,-1.000000,0.000000,
-    0.000000,-0.607983,0.793950,
-    -0.303167,-0.608995,0.732949,
-    -0.238217,-0.781801,0.576230,
-    -0.238217,-0.781801,0.576230,
-    0.000000,-0.781536,0.623860,
-    0.000000,-0.607983,0.793950,
-    0.000000,-0.781536,0.623860,
-    -0.238217,-0.781801,0.576230,
-    -0.067527,-0.984208,0.163628,
-    -0.067527,-0.984208,0.163628,
-    0.000000,-0.984159,0.177291,
-    0.000000,-0.781536,0.623860,
-    0.000000,-0.984159,0.177291,
-    -0.067527,-0.984208,0.163628,
-    -0.018736,-0.998792,0.045422,
-    -0.018736,-0.998792,0.045422,
-    0.000000,-0.998789,0.049207,
-    0.000000,-0.984159,0.177291,
-    0.000000,-0.998789,0.049207,
-    -0.018736,-0.998792,0.045422,
-    0.000000,-1.000000,0.000000,
-    -0.303167,-0.608995,0.732949,
-    -0.560555,-0.609554,0.560555,
-    -0.440416,-0.782348,0.440416,
-    -0.440416,-0.782348,0.440416,
-    -0.238217,-0.781801,0.576230,
-    -0.303167,-0.608995,0.732949,
-    -0.238217,-0.781801,0.576230,
-    -0.440416,-0.782348,0.440416,
-    -0.124903,-0.984276,0.124903,
-    -0.124903,-0.984276,0.124903,
-    -0.067527,-0.984208,0.163628,
-    -0.238217,-0.781801,0.576230,
-    -0.067527,-0.984208,0.163628,
-    -0.124903,-0.984276,0.124903,
-    -0.034662,-0.998798,0.034662,
-    -0.034662,-0.998798,0.034662,
-    -0.018736,-0.998792,0.045422,
-    -0.067527,-0.984208,0.163628,
-    -0.018736,-0.998792,0.045422,
-    -0.034662,-0.998798,0.034662,
-    0.000000,-1.000000,0.000000,
-    -0.560555,-0.609554,0.560555,
-    -0.732949,-0.608995,0.303167,
-    -0.576230,-0.781801,0.238217,
-    -0.576230,-0.781801,0.238217,
-    -0.440416,-0.782348,0.440416,
-    -0.560555,-0.609554,0.560555,
-    -0.440416,-0.782348,0.440416,
-    -0.576230,-0.781801,0.238217,
-    -0.163628,-0.984208,0.067527,
-    -0.163628,-0.984208,0.067527,
-    -0.124903,-0.984276,0.124903,
-    -0.440416,-0.782348,0.440416,
-    -0.124903,-0.984276,0.124903,
-    -0.163628,-0.984208,0.067527,
-    -0.045422,-0.998792,0.018736,
-    -0.045422,-0.998792,0.018736,
-    -0.034662,-0.998798,0.034662,
-    -0.124903,-0.984276,0.124903,
-    -0.034662,-0.998798,0.034662,
-    -0.045422,-0.998792,0.018736,
-    0.000000,-1.000000,0.000000,
-    -0.732949,-0.608995,0.303167,
-    -0.793950,-0.607983,0.000000,
-    -0.623860,-0.781536,0.000000,
-    -0.623860,-0.781536,0.000000,
-    -0.576230,-0.781801,0.238217,
-    -0.732949,-0.608995,0.303167,
-    -0.576230,-0.781801,0.238217,
-    -0.623860,-0.781536,0.000000,
-    -0.177291,-0.984159,0.000000,
-    -0.177291,-0.984159,0.000000,
-    -0.163628,-0.984208,0.067527,
-    -0.576230,-0.781801,0.238217,
-    -0.163628,-0.984208,0.067527,
-    -0.177291,-0.984159,0.000000,
-    -0.049207,-0.998789,0.000000,
-    -0.049207,-0.998789,0.000000,
-    -0.045422,-0.998792,0.018736,
-    -0.163628,-0.984208,0.067527,
-    -0.045422,-0.998792,0.018736,
-    -0.049207,-0.998789,0.000000,
-    0.000000,-1.000000,0.000000,
-    -0.793950,-0.607983,0.000000,
-    -0.732949,-0.608995,-0.303167,
-    -0.576230,-0.781801,-0.238217,
-    -0.576230,-0.781801,-0.238217,
-    -0.623860,-0.781536,0.000000,
-    -0.793950,-0.607983,0.000000,
-    -0.623860,-0.781536,0.000000,
-    -0.576230,-0.781801,-0.238217,
-    -0.163628,-0.984208,-0.067527,
-    -0.163628,-0.984208,-0.067527,
-    -0.177291,-0.984159,0.000000,
-    -0.623860,-0.781536,0.000000,
-    -0.177291,-0.984159,0.000000,
-    -0.163628,-0.984208,-0.067527,
-    -0.045422,-0.998792,-0.018736,
-    -0.045422,-0.998792,-0.018736,
-    -0.049207,-0.998789,0.000000,
-    -0.177291,-0.984159,0.000000,
-    -0.049207,-0.998789,0.000000,
-    -0.045422,-0.998792,-0.018736,
-    0.000000,-1.000000,0.000000,
-    -0.732949,-0.608995,-0.303167,
-    -0.560555,-0.609554,-0.560555,
-    -0.440416,-0.782348,-0.440416,
-    -0.440416,-0.782348,-0.440416,
-    -0.576230,-0.781801,-0.238217,
-    -0.732949,-0.608995,-0.303167,
-    -0.576230,-0.781801,-0.238217,
-    -0.440416,-0.782348,-0.440416,
-    -0.124903,-0.984276,-0.124903,
-    -0.124903,-0.984276,-0.124903,
-    -0.163628,-0.984208,-0.067527,
-    -0.576230,-0.781801,-0.238217,
-    -0.163628,-0.984208,-0.067527,
-    -0.124903,-0.984276,-0.124903,
-    -0.034662,-0.998798,-0.034662,
-    -0.034662,-0.998798,-0.034662,
-    -0.045422,-0.998792,-0.018736,
-    -0.163628,-0.984208,-0.067527,
-    -0.045422,-0.998792,-0.018736,
-    -0.034662,-0.998798,-0.034662,
-    0.000000,-1.000000,0.000000,
-    -0.560555,-0.609554,-0.560555,
-    -0.303167,-0.608995,-0.732949,
-    -0.238217,-0.781801,-0.576230,
-    -0.238217,-0.781801,-0.576230,
-    -0.440416,-0.782348,-0.440416,
-    -0.560555,-0.609554,-0.560555,
-    -0.440416,-0.782348,-0.440416,
-    -0.238217,-0.781801,-0.576230,
-    -0.067527,-0.984208,-0.163628,
-    -0.067527,-0.984208,-0.163628,
-    -0.124903,-0.984276,-0.124903,
-    -0.440416,-0.782348,-0.440416,
-    -0.124903,-0.984276,-0.124903,
-    -0.067527,-0.984208,-0.163628,
-    -0.018736,-0.998792,-0.045422,
-    -0.018736,-0.998792,-0.045422,
-    -0.034662,-0.998798,-0.034662,
-    -0.124903,-0.984276,-0.124903,
-    -0.034662,-0.998798,-0.034662,
-    -0.018736,-0.998792,-0.045422,
-    0.000000,-1.000000,0.000000,
-    -0.303167,-0.608995,-0.732949,
-    0.000000,-0.607983,-0.793950,
-    0.000000,-0.781536,-0.623860,
-    0.000000,-0.781536,-0.623860,
-    -0.238217,-0.781801,-0.576230,
-    -0.303167,-0.608995,-0.732949,
-    -0.238217,-0.781801,-0.576230,
-    0.000000,-0.781536,-0.623860,
-    0.000000,-0.984159,-0.177291,
-    0.000000,-0.984159,-0.177291,
-    -0.067527,-0.984208,-0.163628,
-    -0.238217,-0.781801,-0.576230,
-    -0.067527,-0.984208,-0.163628,
-    0.000000,-0.984159,-0.177291,
-    0.000000,-0.998789,-0.049207,
-    0.000000,-0.998789,-0.049207,
-    -0.018736,-0.998792,-0.045422,
-    -0.067527,-0.984208,-0.163628,
-    -0.018736,-0.998792,-0.045422,
-    0.000000,-0.998789,-0.049207,
-    0.000000,-1.000000,0.000000,
-    0.000000,-0.607983,-0.793950,
-    0.303167,-0.608995,-0.732949,
-    0.238217,-0.781801,-0.576230,
-    0.238217,-0.781801,-0.576230,
-    0.000000,-0.781536,-0.623860,
-    0.000000,-0.607983,-0.793950,
-    0.000000,-0.781536,-0.623860,
-    0.238217,-0.781801,-0.576230,
-    0.067527,-0.984208,-0.163628,
-    0.067527,-0.984208,-0.163628,
-    0.000000,-0.984159,-0.177291,
-    0.000000,-0.781536,-0.623860,
-    0.000000,-0.984159,-0.177291,
-    0.067527,-0.984208,-0.163628,
-    0.018736,-0.998792,-0.045422,
-    0.018736,-0.998792,-0.045422,
-    0.000000,-0.998789,-0.049207,
-    0.000000,-0.984159,-0.177291,
-    0.000000,-0.998789,-0.049207,
-    0.018736,-0.998792,-0.045422,
-    0.000000,-1.000000,0.000000,
-    0.303167,-0.608995,-0.732949,
-    0.560555,-0.609554,-0.560555,
-    0.440416,-0.782348,-0.440416,
-    0.440416,-0.782348,-0.440416,
-    0.238217,-0.781801,-0.576230,
-    0.303167,-0.608995,-0.732949,
-    0.238217,-0.781801,-0.576230,
-    0.440416,-0.782348,-0.440416,
-    0.124903,-0.984276,-0.124903,
-    0.124903,-0.984276,-0.124903,
-    0.067527,-0.984208,-0.163628,
-    0.238217,-0.781801,-0.576230,
-    0.067527,-0.984208,-0.163628,
-    0.124903,-0.984276,-0.124903,
-    0.034662,-0.998798,-0.034662,
-    0.034662,-0.998798,-0.034662,
-    0.018736,-0.998792,-0.045422,
-    0.067527,-0.984208,-0.163628,
-    0.018736,-0.998792,-0.045422,
-    0.034662,-0.998798,-0.034662,
-    0.000000,-1.000000,0.000000,
-    0.560555,-0.609554,-0.560555,
-    0.732949,-0.608995,-0.303167,
-    0.576230,-0.781801,-0.238217,
-    0.576230,-0.781801,-0.238217,
-    0.440416,-0.782348,-0.440416,
-    0.560555,-0.609554,-0.560555,
-    0.440416,-0.782348,-0.440416,
-    0.576230,-0.781801,-0.238217,
-    0.163628,-0.984208,-0.067527,
-    0.163628,-0.984208,-0.067527,
-    0.124903,-0.984276,-0.124903,
-    0.440416,-0.782348,-0.440416,
-    0.124903,-0.984276,-0.124903,
-    0.163628,-0.984208,-0.067527,
-    0.045422,-0.998792,-0.018736,
-    0.045422,-0.998792,-0.018736,
-    0.034662,-0.998798,-0.034662,
-    0.124903,-0.984276,-0.124903,
-    0.034662,-0.998798,-0.034662,
-    0.045422,-0.998792,-0.018736,
-    0.000000,-1.000000,0.000000,
-    0.732949,-0.608995,-0.303167,
-    0.793950,-0.607983,0.000000,
-    0.623860,-0.781536,0.000000,
-    0.623860,-0.781536,0.000000,
-    0.576230,-0.781801,-0.238217,
-    0.732949,-0.608995,-0.303167,
-    0.576230,-0.781801,-0.238217,
-    0.623860,-0.781536,0.000000,
-    0.177291,-0.984159,0.000000,
-    0.177291,-0.984159,0.000000,
-    0.163628,-0.984208,-0.067527,
-    0.576230,-0.781801,-0.238217,
-    0.163628,-0.984208,-0.067527,
-    0.177291,-0.984159,0.000000,
-    0.049207,-0.998789,0.000000,
-    0.049207,-0.998789,0.000000,
-    0.045422,-0.998792,-0.018736,
-    0.163628,-0.984208,-0.067527,
-    0.045422,-0.998792,-0.018736,
-    0.049207,-0.998789,0.000000,
-    0.000000,-1.000000,0.000000,
-    0.007786,-0.999970,-0.000216,
-    0.007039,-0.812495,0.582926,
-    0.036127,-0.837257,0.545614,
-    0.036127,-0.837257,0.545614,
-    0.039138,-0.999233,-0.000989,
-    0.007786,-0.999970,-0.000216,
-    0.039138,-0.999233,-0.000989,
-    0.036127,-0.837257,0.545614,
-    0.161846,-0.810421,0.563048,
-    0.161846,-0.810421,0.563048,
-    0.179512,-0.983746,-0.004369,
-    0.039138,-0.999233,-0.000989,
-    0.179512,-0.983746,-0.004369,
-    0.161846,-0.810421,0.563048,
-    0.482365,-0.595148,0.642746,
-    0.482365,-0.595148,0.642746,
-    0.612299,-0.790557,-0.010460,
-    0.179512,-0.983746,-0.004369,
-    0.612299,-0.790557,-0.010460,
-    0.482365,-0.595148,0.642746,
-    0.738720,-0.114594,0.664199,
-    0.738720,-0.114594,0.664199,
-    0.986152,-0.165708,-0.006670,
-    0.612299,-0.790557,-0.010460,
-    0.007039,-0.812495,0.582926,
-    -0.001909,0.162121,0.986769,
-    0.002762,0.017107,0.999850,
-    0.002762,0.017107,0.999850,
-    0.036127,-0.837257,0.545614,
-    0.007039,-0.812495,0.582926,
-    0.036127,-0.837257,0.545614,
-    0.002762,0.017107,0.999850,
-    0.010533,0.073398,0.997247,
-    0.010533,0.073398,0.997247,
-    0.161846,-0.810421,0.563048,
-    0.036127,-0.837257,0.545614,
-    0.161846,-0.810421,0.563048,
-    0.010533,0.073398,0.997247,
-    -0.066041,0.130070,0.989303,
-    -0.066041,0.130070,0.989303,
-    0.482365,-0.595148,0.642746,
-    0.161846,-0.810421,0.563048,
-    0.482365,-0.595148,0.642746,
-    -0.066041,0.130070,0.989303,
-    -0.094427,0.016594,0.995393,
-    -0.094427,0.016594,0.995393,
-    0.738720,-0.114594,0.664199,
-    0.482365,-0.595148,0.642746,
-    -0.001909,0.162121,0.986769,
-    -0.009203,0.871509,0.490293,
-    -0.048606,0.840609,0.539457,
-    -0.048606,0.840609,0.539457,
-    0.002762,0.017107,0.999850,
-    -0.001909,0.162121,0.986769,
-    0.002762,0.017107,0.999850,
-    -0.048606,0.840609,0.539457,
-    -0.223298,0.802880,0.552739,
-    -0.223298,0.802880,0.552739,
-    0.010533,0.073398,0.997247,
-    0.002762,0.017107,0.999850,
-    0.010533,0.073398,0.997247,
-    -0.223298,0.802880,0.552739,
-    -0.596365,0.559971,0.575135,
-    -0.596365,0.559971,0.575135,
-    -0.066041,0.130070,0.989303,
-    0.010533,0.073398,0.997247,
-    -0.066041,0.130070,0.989303,
-    -0.596365,0.559971,0.575135,
-    -0.803337,0.068236,0.591603,
-    -0.803337,0.068236,0.591603,
-    -0.094427,0.016594,0.995393,
-    -0.066041,0.130070,0.989303,
-    -0.009203,0.871509,0.490293,
-    -0.010561,0.999944,0.000103,
-    -0.058798,0.998270,0.000710,
-    -0.058798,0.998270,0.000710,
-    -0.048606,0.840609,0.539457,
-    -0.009203,0.871509,0.490293,
-    -0.048606,0.840609,0.539457,
-    -0.058798,0.998270,0.000710,
-    -0.280710,0.959787,0.003269,
-    -0.280710,0.959787,0.003269,
-    -0.223298,0.802880,0.552739,
-    -0.048606,0.840609,0.539457,
-    -0.223298,0.802880,0.552739,
-    -0.280710,0.959787,0.003269,
-    -0.749723,0.661738,0.004268,
-    -0.749723,0.661738,0.004268,
-    -0.596365,0.559971,0.575135,
-    -0.223298,0.802880,0.552739,
-    -0.596365,0.559971,0.575135,
-    -0.749723,0.661738,0.004268,
-    -0.997351,0.072714,0.002059,
-    -0.997351,0.072714,0.002059,
-    -0.803337,0.068236,0.591603,
-    -0.596365,0.559971,0.575135,
-    -0.010561,0.999944,0.000103,
-    -0.008792,0.871493,-0.490330,
-    -0.046494,0.841178,-0.538756,
-    -0.046494,0.841178,-0.538756,
-    -0.058798,0.998270,0.000710,
-    -0.010561,0.999944,0.000103,
-    -0.058798,0.998270,0.000710,
-    -0.046494,0.841178,-0.538756,
-    -0.217909,0.806807,-0.549161,
-    -0.217909,0.806807,-0.549161,
-    -0.280710,0.959787,0.003269,
-    -0.058798,0.998270,0.000710,
-    -0.280710,0.959787,0.003269,
-    -0.217909,0.806807,-0.549161,
-    -0.597291,0.560026,-0.574121,
-    -0.597291,0.560026,-0.574121,
-    -0.749723,0.661738,0.004268,
-    -0.280710,0.959787,0.003269,
-    -0.749723,0.661738,0.004268,
-    -0.597291,0.560026,-0.574121,
-    -0.804000,0.062913,-0.591292,
-    -0.804000,0.062913,-0.591292,
-    -0.997351,0.072714,0.002059,
-    -0.749723,0.661738,0.004268,
-    -0.008792,0.871493,-0.490330,
-    -0.001806,0.161691,-0.986840,
-    0.002031,0.014555,-0.999892,
-    0.002031,0.014555,-0.999892,
-    -0.046494,0.841178,-0.538756,
-    -0.008792,0.871493,-0.490330,
-    -0.046494,0.841178,-0.538756,
-    0.002031,0.014555,-0.999892,
-    0.009215,0.060069,-0.998152,
-    0.009215,0.060069,-0.998152,
-    -0.217909,0.806807,-0.549161,
-    -0.046494,0.841178,-0.538756,
-    -0.217909,0.806807,-0.549161,
-    0.009215,0.060069,-0.998152,
-    -0.059334,0.113865,-0.991723,
-    -0.059334,0.113865,-0.991723,
-    -0.597291,0.560026,-0.574121,
-    -0.217909,0.806807,-0.549161,
-    -0.597291,0.560026,-0.574121,
-    -0.059334,0.113865,-0.991723,
-    -0.086899,0.012290,-0.996141,
-    -0.086899,0.012290,-0.996141,
-    -0.804000,0.062913,-0.591292,
-    -0.597291,0.560026,-0.574121,
-    -0.001806,0.161691,-0.986840,
-    0.006418,-0.812379,-0.583095,
-    0.033783,-0.837512,-0.545373,
-    0.033783,-0.837512,-0.545373,
-    0.002031,0.014555,-0.999892,
-    -0.001806,0.161691,-0.986840,
-    0.002031,0.014555,-0.999892,
-    0.033783,-0.837512,-0.545373,
-    0.157113,-0.811947,-0.562190,
-    0.157113,-0.811947,-0.562190,
-    0.009215,0.060069,-0.998152,
-    0.002031,0.014555,-0.999892,
-    0.009215,0.060069,-0.998152,
-    0.157113,-0.811947,-0.562190,
-    0.484406,-0.589366,-0.646528,
-    0.484406,-0.589366,-0.646528,
-    -0.059334,0.113865,-0.991723,
-    0.009215,0.060069,-0.998152,
-    -0.059334,0.113865,-0.991723,
-    0.484406,-0.589366,-0.646528,
-    0.738870,-0.101320,-0.666187,
-    0.738870,-0.101320,-0.666187,
-    -0.086899,0.012290,-0.996141,
-    -0.059334,0.113865,-0.991723,
-    0.006418,-0.812379,-0.583095,
-    0.007786,-0.999970,-0.000216,
-    0.039138,-0.999233,-0.000989,
-    0.039138,-0.999233,-0.000989,
-    0.033783,-0.837512,-0.545373,
-    0.006418,-0.812379,-0.583095,
-    0.033783,-0.837512,-0.545373,
-    0.039138,-0.999233,-0.000989,
-    0.179512,-0.983746,-0.004369,
-    0.179512,-0.983746,-0.004369,
-    0.157113,-0.811947,-0.562190,
-    0.033783,-0.837512,-0.545373,
-    0.157113,-0.811947,-0.562190,
-    0.179512,-0.983746,-0.004369,
-    0.612299,-0.790557,-0.010460,
-    0.612299,-0.790557,-0.010460,
-    0.484406,-0.589366,-0.646528,
-    0.157113,-0.811947,-0.562190,
-    0.484406,-0.589366,-0.646528,
-    0.612299,-0.790557,-0.010460,
-    0.986152,-0.165708,-0.006670,
-    0.986152,-0.165708,-0.006670,
-    0.738870,-0.101320,-0.666187,
-    0.484406,-0.589366,-0.646528,
-    0.986152,-0.165708,-0.006670,
-    0.738720,-0.114594,0.664199,
-    0.725608,0.259351,0.637361,
-    0.725608,0.259351,0.637361,
-    0.946512,0.322650,-0.003357,
-    0.986152,-0.165708,-0.006670,
-    0.946512,0.322650,-0.003357,
-    0.725608,0.259351,0.637361,
-    0.645945,0.461988,0.607719,
-    0.645945,0.461988,0.607719,
-    0.825830,0.563870,-0.007452,
-    0.946512,0.322650,-0.003357,
-    0.825830,0.563870,-0.007452,
-    0.645945,0.461988,0.607719,
-    0.531615,0.636660,0.558614,
-    0.531615,0.636660,0.558614,
-    0.650011,0.759893,-0.006937,
-    0.825830,0.563870,-0.007452,
-    0.650011,0.759893,-0.006937,
-    0.531615,0.636660,0.558614,
-    0.424964,0.681717,0.595540,
-    0.424964,0.681717,0.595540,
-    0.532429,0.846459,-0.005245,
-    0.650011,0.759893,-0.006937,
-    0.738720,-0.114594,0.664199,
-    -0.094427,0.016594,0.995393,
-    -0.049562,-0.019755,0.998576,
-    -0.049562,-0.019755,0.998576,
-    0.725608,0.259351,0.637361,
-    0.738720,-0.114594,0.664199,
-    0.725608,0.259351,0.637361,
-    -0.049562,-0.019755,0.998576,
-    -0.037816,-0.035624,0.998650,
-    -0.037816,-0.035624,0.998650,
-    0.645945,0.461988,0.607719,
-    0.725608,0.259351,0.637361,
-    0.645945,0.461988,0.607719,
-    -0.037816,-0.035624,0.998650,
-    -0.037914,-0.036512,0.998614,
-    -0.037914,-0.036512,0.998614,
-    0.531615,0.636660,0.558614,
-    0.645945,0.461988,0.607719,
-    0.531615,0.636660,0.558614,
-    -0.037914,-0.036512,0.998614,
-    -0.168854,-0.297945,0.939530,
-    -0.168854,-0.297945,0.939530,
-    0.424964,0.681717,0.595540,
-    0.531615,0.636660,0.558614,
-    -0.094427,0.016594,0.995393,
-    -0.803337,0.068236,0.591603,
-    -0.742342,-0.299166,0.599523,
-    -0.742342,-0.299166,0.599523,
-    -0.049562,-0.019755,0.998576,
-    -0.094427,0.016594,0.995393,
-    -0.049562,-0.019755,0.998576,
-    -0.742342,-0.299166,0.599523,
-    -0.619602,-0.529406,0.579502,
-    -0.619602,-0.529406,0.579502,
-    -0.037816,-0.035624,0.998650,
-    -0.049562,-0.019755,0.998576,
-    -0.037816,-0.035624,0.998650,
-    -0.619602,-0.529406,0.579502,
-    -0.483708,-0.685760,0.543837,
-    -0.483708,-0.685760,0.543837,
-    -0.037914,-0.036512,0.998614,
-    -0.037816,-0.035624,0.998650,
-    -0.037914,-0.036512,0.998614,
-    -0.483708,-0.685760,0.543837,
-    -0.445293,-0.794355,0.413177,
-    -0.445293,-0.794355,0.413177,
-    -0.168854,-0.297945,0.939530,
-    -0.037914,-0.036512,0.998614,
-    -0.803337,0.068236,0.591603,
-    -0.997351,0.072714,0.002059,
-    -0.926513,-0.376258,0.001996,
-    -0.926513,-0.376258,0.001996,
-    -0.742342,-0.299166,0.599523,
-    -0.803337,0.068236,0.591603,
-    -0.742342,-0.299166,0.599523,
-    -0.926513,-0.376258,0.001996,
-    -0.753920,-0.656952,0.004317,
-    -0.753920,-0.656952,0.004317,
-    -0.619602,-0.529406,0.579502,
-    -0.742342,-0.299166,0.599523,
-    -0.619602,-0.529406,0.579502,
-    -0.753920,-0.656952,0.004317,
-    -0.566224,-0.824244,0.003461,
-    -0.566224,-0.824244,0.003461,
-    -0.483708,-0.685760,0.543837,
-    -0.619602,-0.529406,0.579502,
-    -0.483708,-0.685760,0.543837,
-    -0.566224,-0.824244,0.003461,
-    -0.481804,-0.876277,0.001850,
-    -0.481804,-0.876277,0.001850,
-    -0.445293,-0.794355,0.413177,
-    -0.483708,-0.685760,0.543837,
-    -0.997351,0.072714,0.002059,
-    -0.804000,0.062913,-0.591292,
-    -0.744675,-0.294425,-0.598977,
-    -0.744675,-0.294425,-0.598977,
-    -0.926513,-0.376258,0.001996,
-    -0.997351,0.072714,0.002059,
-    -0.926513,-0.376258,0.001996,
-    -0.744675,-0.294425,-0.598977,
-    -0.621949,-0.528114,-0.578165,
-    -0.621949,-0.528114,-0.578165,
-    -0.753920,-0.656952,0.004317,
-    -0.926513,-0.376258,0.001996,
-    -0.753920,-0.656952,0.004317,
-    -0.621949,-0.528114,-0.578165,
-    -0.481171,-0.688340,-0.542828,
-    -0.481171,-0.688340,-0.542828,
-    -0.566224,-0.824244,0.003461,
-    -0.753920,-0.656952,0.004317,
-    -0.566224,-0.824244,0.003461,
-    -0.481171,-0.688340,-0.542828,
-    -0.438055,-0.797035,-0.415744,
-    -0.438055,-0.797035,-0.415744,
-    -0.481804,-0.876277,0.001850,
-    -0.566224,-0.824244,0.003461,
-    -0.804000,0.062913,-0.591292,
-    -0.086899,0.012290,-0.996141,
-    -0.044337,-0.017056,-0.998871,
-    -0.044337,-0.017056,-0.998871,
-    -0.744675,-0.294425,-0.598977,
-    -0.804000,0.062913,-0.591292,
-    -0.744675,-0.294425,-0.598977,
-    -0.044337,-0.017056,-0.998871,
-    -0.026176,-0.028166,-0.999260,
-    -0.026176,-0.028166,-0.999260,
-    -0.621949,-0.528114,-0.578165,
-    -0.744675,-0.294425,-0.598977,
-    -0.621949,-0.528114,-0.578165,
-    -0.026176,-0.028166,-0.999260,
-    -0.025294,-0.028332,-0.999278,
-    -0.025294,-0.028332,-0.999278,
-    -0.481171,-0.688340,-0.542828,
-    -0.621949,-0.528114,-0.578165,
-    -0.481171,-0.688340,-0.542828,
-    -0.025294,-0.028332,-0.999278,
-    -0.157482,-0.289392,-0.944167,
-    -0.157482,-0.289392,-0.944167,
-    -0.438055,-0.797035,-0.415744,
-    -0.481171,-0.688340,-0.542828,
-    -0.086899,0.012290,-0.996141,
-    0.738870,-0.101320,-0.666187,
-    0.728244,0.252410,-0.637142,
-    0.728244,0.252410,-0.637142,
-    -0.044337,-0.017056,-0.998871,
-    -0.086899,0.012290,-0.996141,
-    -0.044337,-0.017056,-0.998871,
-    0.728244,0.252410,-0.637142,
-    0.647055,0.459725,-0.608254,
-    0.647055,0.459725,-0.608254,
-    -0.026176,-0.028166,-0.999260,
-    -0.044337,-0.017056,-0.998871,
-    -0.026176,-0.028166,-0.999260,
-    0.647055,0.459725,-0.608254,
-    0.522994,0.640657,-0.562170,
-    0.522994,0.640657,-0.562170,
-    -0.025294,-0.028332,-0.999278,
-    -0.026176,-0.028166,-0.999260,
-    -0.025294,-0.028332,-0.999278,
-    0.522994,0.640657,-0.562170,
-    0.409978,0.682857,-0.604669,
-    0.409978,0.682857,-0.604669,
-    -0.157482,-0.289392,-0.944167,
-    -0.025294,-0.028332,-0.999278,
-    0.738870,-0.101320,-0.666187,
-    0.986152,-0.165708,-0.006670,
-    0.946512,0.322650,-0.003357,
-    0.946512,0.322650,-0.003357,
-    0.728244,0.252410,-0.637142,
-    0.738870,-0.101320,-0.666187,
-    0.728244,0.252410,-0.637142,
-    0.946512,0.322650,-0.003357,
-    0.825830,0.563870,-0.007452,
-    0.825830,0.563870,-0.007452,
-    0.647055,0.459725,-0.608254,
-    0.728244,0.252410,-0.637142,
-    0.647055,0.459725,-0.608254,
-    0.825830,0.563870,-0.007452,
-    0.650011,0.759893,-0.006937,
-    0.650011,0.759893,-0.006937,
-    0.522994,0.640657,-0.562170,
-    0.647055,0.459725,-0.608254,
-    0.522994,0.640657,-0.562170,
-    0.650011,0.759893,-0.006937,
-    0.532429,0.846459,-0.005245,
-    0.532429,0.846459,-0.005245,
-    0.409978,0.682857,-0.604669,
-    0.522994,0.640657,-0.562170,
-    -0.230787,0.972982,-0.006523,
-    -0.152877,0.687211,0.710190,
-    -0.316721,0.637750,0.702113,
-    -0.316721,0.637750,0.702113,
-    -0.548936,0.835863,-0.001511,
-    -0.230787,0.972982,-0.006523,
-    -0.548936,0.835863,-0.001511,
-    -0.316721,0.637750,0.702113,
-    -0.601067,0.471452,0.645330,
-    -0.601067,0.471452,0.645330,
-    -0.875671,0.482806,0.009893,
-    -0.548936,0.835863,-0.001511,
-    -0.875671,0.482806,0.009893,
-    -0.601067,0.471452,0.645330,
-    -0.635889,0.446090,0.629801,
-    -0.635889,0.446090,0.629801,
-    -0.877554,0.479097,0.019092,
-    -0.875671,0.482806,0.009893,
-    -0.877554,0.479097,0.019092,
-    -0.635889,0.446090,0.629801,
-    -0.435746,0.601008,0.670011,
-    -0.435746,0.601008,0.670011,
-    -0.696190,0.717439,0.024497,
-    -0.877554,0.479097,0.019092,
-    -0.152877,0.687211,0.710190,
-    0.111113,-0.085070,0.990160,
-    0.223310,0.006540,0.974726,
-    0.223310,0.006540,0.974726,
-    -0.316721,0.637750,0.702113,
-    -0.152877,0.687211,0.710190,
-    -0.316721,0.637750,0.702113,
-    0.223310,0.006540,0.974726,
-    0.190097,0.154964,0.969458,
-    0.190097,0.154964,0.969458,
-    -0.601067,0.471452,0.645330,
-    -0.316721,0.637750,0.702113,
-    -0.601067,0.471452,0.645330,
-    0.190097,0.154964,0.969458,
-    0.005271,0.189482,0.981870,
-    0.005271,0.189482,0.981870,
-    -0.635889,0.446090,0.629801,
-    -0.601067,0.471452,0.645330,
-    -0.635889,0.446090,0.629801,
-    0.005271,0.189482,0.981870,
-    -0.011752,0.246688,0.969024,
-    -0.011752,0.246688,0.969024,
-    -0.435746,0.601008,0.670011,
-    -0.635889,0.446090,0.629801,
-    0.111113,-0.085070,0.990160,
-    0.343906,-0.722796,0.599412,
-    0.572489,-0.567656,0.591627,
-    0.572489,-0.567656,0.591627,
-    0.223310,0.006540,0.974726,
-    0.111113,-0.085070,0.990160,
-    0.223310,0.006540,0.974726,
-    0.572489,-0.567656,0.591627,
-    0.787436,-0.256459,0.560512,
-    0.787436,-0.256459,0.560512,
-    0.190097,0.154964,0.969458,
-    0.223310,0.006540,0.974726,
-    0.190097,0.154964,0.969458,
-    0.787436,-0.256459,0.560512,
-    0.647097,-0.306374,0.698141,
-    0.647097,-0.306374,0.698141,
-    0.005271,0.189482,0.981870,
-    0.190097,0.154964,0.969458,
-    0.005271,0.189482,0.981870,
-    0.647097,-0.306374,0.698141,
-    0.427528,-0.499343,0.753576,
-    0.427528,-0.499343,0.753576,
-    -0.011752,0.246688,0.969024,
-    0.005271,0.189482,0.981870,
-    0.343906,-0.722796,0.599412,
-    0.410926,-0.911668,0.001284,
-    0.671520,-0.740986,-0.000899,
-    0.671520,-0.740986,-0.000899,
-    0.572489,-0.567656,0.591627,
-    0.343906,-0.722796,0.599412,
-    0.572489,-0.567656,0.591627,
-    0.671520,-0.740986,-0.000899,
-    0.922026,-0.387060,-0.007253,
-    0.922026,-0.387060,-0.007253,
-    0.787436,-0.256459,0.560512,
-    0.572489,-0.567656,0.591627,
-    0.787436,-0.256459,0.560512,
-    0.922026,-0.387060,-0.007253,
-    0.846910,-0.531556,-0.013854,
-    0.846910,-0.531556,-0.013854,
-    0.647097,-0.306374,0.698141,
-    0.787436,-0.256459,0.560512,
-    0.647097,-0.306374,0.698141,
-    0.846910,-0.531556,-0.013854,
-    0.535924,-0.844201,-0.010505,
-    0.535924,-0.844201,-0.010505,
-    0.427528,-0.499343,0.753576,
-    0.647097,-0.306374,0.698141,
-    0.410926,-0.911668,0.001284,
-    0.341188,-0.722823,-0.600931,
-    0.578664,-0.561139,-0.591838,
-    0.578664,-0.561139,-0.591838,
-    0.671520,-0.740986,-0.000899,
-    0.410926,-0.911668,0.001284,
-    0.671520,-0.740986,-0.000899,
-    0.578664,-0.561139,-0.591838,
-    0.784869,-0.251020,-0.566542,
-    0.784869,-0.251020,-0.566542,
-    0.922026,-0.387060,-0.007253,
-    0.671520,-0.740986,-0.000899,
-    0.922026,-0.387060,-0.007253,
-    0.784869,-0.251020,-0.566542,
-    0.642681,-0.302257,-0.703990,
-    0.642681,-0.302257,-0.703990,
-    0.846910,-0.531556,-0.013854,
-    0.922026,-0.387060,-0.007253,
-    0.846910,-0.531556,-0.013854,
-    0.642681,-0.302257,-0.703990,
-    0.418589,-0.500042,-0.758117,
-    0.418589,-0.500042,-0.758117,
-    0.535924,-0.844201,-0.010505,
-    0.846910,-0.531556,-0.013854,
-    0.341188,-0.722823,-0.600931,
-    0.115806,-0.079139,-0.990114,
-    0.232811,0.012565,-0.972441,
-    0.232811,0.012565,-0.972441,
-    0.578664,-0.561139,-0.591838,
-    0.341188,-0.722823,-0.600931,
-    0.578664,-0.561139,-0.591838,
-    0.232811,0.012565,-0.972441,
-    0.206662,0.153601,-0.966280,
-    0.206662,0.153601,-0.966280,
-    0.784869,-0.251020,-0.566542,
-    0.578664,-0.561139,-0.591838,
-    0.784869,-0.251020,-0.566542,
-    0.206662,0.153601,-0.966280,
-    0.024500,0.161443,-0.986578,
-    0.024500,0.161443,-0.986578,
-    0.642681,-0.302257,-0.703990,
-    0.784869,-0.251020,-0.566542,
-    0.642681,-0.302257,-0.703990,
-    0.024500,0.161443,-0.986578,
-    0.003382,0.211115,-0.977455,
-    0.003382,0.211115,-0.977455,
-    0.418589,-0.500042,-0.758117,
-    0.642681,-0.302257,-0.703990,
-    0.115806,-0.079139,-0.990114,
-    -0.134912,0.687491,-0.713551,
-    -0.319540,0.633073,-0.705063,
-    -0.319540,0.633073,-0.705063,
-    0.232811,0.012565,-0.972441,
-    0.115806,-0.079139,-0.990114,
-    0.232811,0.012565,-0.972441,
-    -0.319540,0.633073,-0.705063,
-    -0.603902,0.461442,-0.649903,
-    -0.603902,0.461442,-0.649903,
-    0.206662,0.153601,-0.966280,
-    0.232811,0.012565,-0.972441,
-    0.206662,0.153601,-0.966280,
-    -0.603902,0.461442,-0.649903,
-    -0.631815,0.437169,-0.640072,
-    -0.631815,0.437169,-0.640072,
-    0.024500,0.161443,-0.986578,
-    0.206662,0.153601,-0.966280,
-    0.024500,0.161443,-0.986578,
-    -0.631815,0.437169,-0.640072,
-    -0.424306,0.612706,-0.666750,
-    -0.424306,0.612706,-0.666750,
-    0.003382,0.211115,-0.977455,
-    0.024500,0.161443,-0.986578,
-    -0.134912,0.687491,-0.713551,
-    -0.230787,0.972982,-0.006523,
-    -0.548936,0.835863,-0.001511,
-    -0.548936,0.835863,-0.001511,
-    -0.319540,0.633073,-0.705063,
-    -0.134912,0.687491,-0.713551,
-    -0.319540,0.633073,-0.705063,
-    -0.548936,0.835863,-0.001511,
-    -0.875671,0.482806,0.009893,
-    -0.875671,0.482806,0.009893,
-    -0.603902,0.461442,-0.649903,
-    -0.319540,0.633073,-0.705063,
-    -0.603902,0.461442,-0.649903,
-    -0.875671,0.482806,0.009893,
-    -0.877554,0.479097,0.019092,
-    -0.877554,0.479097,0.019092,
-    -0.631815,0.437169,-0.640072,
-    -0.603902,0.461442,-0.649903,
-    -0.631815,0.437169,-0.640072,
-    -0.877554,0.479097,0.019092,
-    -0.696190,0.717439,0.024497,
-    -0.696190,0.717439,0.024497,
-    -0.424306,0.612706,-0.666750,
-    -0.631815,0.437169,-0.640072,
-    -0.696190,0.717439,0.024497,
-    -0.435746,0.601008,0.670011,
-    -0.259858,0.791937,0.552548,
-    -0.259858,0.791937,0.552548,
-    -0.425801,0.904753,0.010805,
-    -0.696190,0.717439,0.024497,
-    -0.425801,0.904753,0.010805,
-    -0.259858,0.791937,0.552548,
-    0.009539,0.999720,-0.021674,
-    0.009539,0.999720,-0.021674,
-    0.022046,0.999756,0.001623,
-    -0.425801,0.904753,0.010805,
-    0.022046,0.999756,0.001623,
-    0.009539,0.999720,-0.021674,
-    0.410157,0.332912,-0.849082,
-    0.410157,0.332912,-0.849082,
-    0.999598,0.025875,0.011556,
-    0.022046,0.999756,0.001623,
-    0.999598,0.025875,0.011556,
-    0.410157,0.332912,-0.849082,
-    0.541523,-0.548619,-0.637001,
-    0.541523,-0.548619,-0.637001,
-    0.709587,-0.704552,0.009672,
-    0.999598,0.025875,0.011556,
-    -0.435746,0.601008,0.670011,
-    -0.011752,0.246688,0.969024,
-    0.046311,0.455223,0.889172,
-    0.046311,0.455223,0.889172,
-    -0.259858,0.791937,0.552548,
-    -0.435746,0.601008,0.670011,
-    -0.259858,0.791937,0.552548,
-    0.046311,0.455223,0.889172,
-    -0.010688,0.988794,0.148900,
-    -0.010688,0.988794,0.148900,
-    0.009539,0.999720,-0.021674,
-    -0.259858,0.791937,0.552548,
-    0.009539,0.999720,-0.021674,
-    -0.010688,0.988794,0.148900,
-    -0.044376,0.682946,-0.729120,
-    -0.044376,0.682946,-0.729120,
-    0.410157,0.332912,-0.849082,
-    0.009539,0.999720,-0.021674,
-    0.410157,0.332912,-0.849082,
-    -0.044376,0.682946,-0.729120,
-    0.122824,0.009233,-0.992385,
-    0.122824,0.009233,-0.992385,
-    0.541523,-0.548619,-0.637001,
-    0.410157,0.332912,-0.849082,
-    -0.011752,0.246688,0.969024,
-    0.427528,-0.499343,0.753576,
-    0.481839,-0.180440,0.857480,
-    0.481839,-0.180440,0.857480,
-    0.046311,0.455223,0.889172,
-    -0.011752,0.246688,0.969024,
-    0.046311,0.455223,0.889172,
-    0.481839,-0.180440,0.857480,
-    0.455272,0.736752,0.499925,
-    0.455272,0.736752,0.499925,
-    -0.010688,0.988794,0.148900,
-    0.046311,0.455223,0.889172,
-    -0.010688,0.988794,0.148900,
-    0.455272,0.736752,0.499925,
-    -0.220542,0.907193,-0.358277,
-    -0.220542,0.907193,-0.358277,
-    -0.044376,0.682946,-0.729120,
-    -0.010688,0.988794,0.148900,
-    -0.044376,0.682946,-0.729120,
-    -0.220542,0.907193,-0.358277,
-    -0.235919,0.657250,-0.715797,
-    -0.235919,0.657250,-0.715797,
-    0.122824,0.009233,-0.992385,
-    -0.044376,0.682946,-0.729120,
-    0.427528,-0.499343,0.753576,
-    0.535924,-0.844201,-0.010505,
-    0.728094,-0.685300,-0.015585,
-    0.728094,-0.685300,-0.015585,
-    0.481839,-0.180440,0.857480,
-    0.427528,-0.499343,0.753576,
-    0.481839,-0.180440,0.857480,
-    0.728094,-0.685300,-0.015585,
-    0.888738,0.458112,-0.016679,
-    0.888738,0.458112,-0.016679,
-    0.455272,0.736752,0.499925,
-    0.481839,-0.180440,0.857480,
-    0.455272,0.736752,0.499925,
-    0.888738,0.458112,-0.016679,
-    -0.260098,0.965582,0.000800,
-    -0.260098,0.965582,0.000800,
-    -0.220542,0.907193,-0.358277,
-    0.455272,0.736752,0.499925,
-    -0.220542,0.907193,-0.358277,
-    -0.260098,0.965582,0.000800,
-    -0.371611,0.928378,-0.004418,
-    -0.371611,0.928378,-0.004418,
-    -0.235919,0.657250,-0.715797,
-    -0.220542,0.907193,-0.358277,
-    0.535924,-0.844201,-0.010505,
-    0.418589,-0.500042,-0.758117,
-    0.480165,-0.178362,-0.858853,
-    0.480165,-0.178362,-0.858853,
-    0.728094,-0.685300,-0.015585,
-    0.535924,-0.844201,-0.010505,
-    0.728094,-0.685300,-0.015585,
-    0.480165,-0.178362,-0.858853,
-    0.488102,0.716802,-0.497947,
-    0.488102,0.716802,-0.497947,
-    0.888738,0.458112,-0.016679,
-    0.728094,-0.685300,-0.015585,
-    0.888738,0.458112,-0.016679,
-    0.488102,0.716802,-0.497947,
-    -0.222004,0.905399,0.361892,
-    -0.222004,0.905399,0.361892,
-    -0.260098,0.965582,0.000800,
-    0.888738,0.458112,-0.016679,
-    -0.260098,0.965582,0.000800,
-    -0.222004,0.905399,0.361892,
-    -0.235405,0.663180,0.710477,
-    -0.235405,0.663180,0.710477,
-    -0.371611,0.928378,-0.004418,
-    -0.260098,0.965582,0.000800,
-    0.418589,-0.500042,-0.758117,
-    0.003382,0.211115,-0.977455,
-    0.058720,0.437702,-0.897200,
-    0.058720,0.437702,-0.897200,
-    0.480165,-0.178362,-0.858853,
-    0.418589,-0.500042,-0.758117,
-    0.480165,-0.178362,-0.858853,
-    0.058720,0.437702,-0.897200,
-    0.001326,0.986459,-0.164002,
-    0.001326,0.986459,-0.164002,
-    0.488102,0.716802,-0.497947,
-    0.480165,-0.178362,-0.858853,
-    0.488102,0.716802,-0.497947,
-    0.001326,0.986459,-0.164002,
-    -0.044190,0.681675,0.730319,
-    -0.044190,0.681675,0.730319,
-    -0.222004,0.905399,0.361892,
-    0.488102,0.716802,-0.497947,
-    -0.222004,0.905399,0.361892,
-    -0.044190,0.681675,0.730319,
-    0.138801,-0.034188,0.989730,
-    0.138801,-0.034188,0.989730,
-    -0.235405,0.663180,0.710477,
-    -0.222004,0.905399,0.361892,
-    0.003382,0.211115,-0.977455,
-    -0.424306,0.612706,-0.666750,
-    -0.258890,0.797206,-0.545380,
-    -0.258890,0.797206,-0.545380,
-    0.058720,0.437702,-0.897200,
-    0.003382,0.211115,-0.977455,
-    0.058720,0.437702,-0.897200,
-    -0.258890,0.797206,-0.545380,
-    0.012270,0.999739,0.019287,
-    0.012270,0.999739,0.019287,
-    0.001326,0.986459,-0.164002,
-    0.058720,0.437702,-0.897200,
-    0.001326,0.986459,-0.164002,
-    0.012270,0.999739,0.019287,
-    0.398632,0.354890,0.845663,
-    0.398632,0.354890,0.845663,
-    -0.044190,0.681675,0.730319,
-    0.001326,0.986459,-0.164002,
-    -0.044190,0.681675,0.730319,
-    0.398632,0.354890,0.845663,
-    0.537564,-0.581398,0.610738,
-    0.537564,-0.581398,0.610738,
-    0.138801,-0.034188,0.989730,
-    -0.044190,0.681675,0.730319,
-    -0.424306,0.612706,-0.666750,
-    -0.696190,0.717439,0.024497,
-    -0.425801,0.904753,0.010805,
-    -0.425801,0.904753,0.010805,
-    -0.258890,0.797206,-0.545380,
-    -0.424306,0.612706,-0.666750,
-    -0.258890,0.797206,-0.545380,
-    -0.425801,0.904753,0.010805,
-    0.022046,0.999756,0.001623,
-    0.022046,0.999756,0.001623,
-    0.012270,0.999739,0.019287,
-    -0.258890,0.797206,-0.545380,
-    0.012270,0.999739,0.019287,
-    0.022046,0.999756,0.001623,
-    0.999598,0.025875,0.011556,
-    0.999598,0.025875,0.011556,
-    0.398632,0.354890,0.845663,
-    0.012270,0.999739,0.019287,
-    0.398632,0.354890,0.845663,
-    0.999598,0.025875,0.011556,
-    0.709587,-0.704552,0.009672,
-    0.709587,-0.704552,0.009672,
-    0.537564,-0.581398,0.610738,
-    0.398632,0.354890,0.845663,
-    0.762640,0.565035,0.314825,
-    0.824540,0.565804,0.000017,
-    0.000000,1.000000,0.000000,
-    0.824540,0.565804,0.000017,
-    0.762640,0.565035,0.314825,
-    0.847982,-0.397998,0.350034,
-    0.847982,-0.397998,0.350034,
-    0.917701,-0.397272,0.000034,
-    0.824540,0.565804,0.000017,
-    0.917701,-0.397272,0.000034,
-    0.847982,-0.397998,0.350034,
-    0.864141,-0.355261,0.356441,
-    0.864141,-0.355261,0.356441,
-    0.935269,-0.353939,0.000113,
-    0.917701,-0.397272,0.000034,
-    0.935269,-0.353939,0.000113,
-    0.864141,-0.355261,0.356441,
-    0.720992,0.625625,0.297933,
-    0.720992,0.625625,0.297933,
-    0.780712,0.624890,0.000075,
-    0.935269,-0.353939,0.000113,
-    0.583357,0.565165,0.583338,
-    0.762640,0.565035,0.314825,
-    0.000000,1.000000,0.000000,
-    0.762640,0.565035,0.314825,
-    0.583357,0.565165,0.583338,
-    0.648485,-0.398726,0.648448,
-    0.648485,-0.398726,0.648448,
-    0.847982,-0.397998,0.350034,
-    0.762640,0.565035,0.314825,
-    0.847982,-0.397998,0.350034,
-    0.648485,-0.398726,0.648448,
-    0.660872,-0.355894,0.660748,
-    0.660872,-0.355894,0.660748,
-    0.864141,-0.355261,0.356441,
-    0.847982,-0.397998,0.350034,
-    0.864141,-0.355261,0.356441,
-    0.660872,-0.355894,0.660748,
-    0.551862,0.625290,0.551780,
-    0.551862,0.625290,0.551780,
-    0.720992,0.625625,0.297933,
-    0.864141,-0.355261,0.356441,
-    0.314824,0.565051,0.762629,
-    0.583357,0.565165,0.583338,
-    0.000000,1.000000,0.000000,
-    0.583357,0.565165,0.583338,
-    0.314824,0.565051,0.762629,
-    0.350045,-0.397976,0.847988,
-    0.350045,-0.397976,0.847988,
-    0.648485,-0.398726,0.648448,
-    0.583357,0.565165,0.583338,
-    0.648485,-0.398726,0.648448,
-    0.350045,-0.397976,0.847988,
-    0.356474,-0.355199,0.864153,
-    0.356474,-0.355199,0.864153,
-    0.660872,-0.355894,0.660748,
-    0.648485,-0.398726,0.648448,
-    0.660872,-0.355894,0.660748,
-    0.356474,-0.355199,0.864153,
-    0.297983,0.625515,0.721067,
-    0.297983,0.625515,0.721067,
-    0.551862,0.625290,0.551780,
-    0.660872,-0.355894,0.660748,
-    -0.000017,0.565804,0.824540,
-    0.314824,0.565051,0.762629,
-    0.000000,1.000000,0.000000,
-    0.314824,0.565051,0.762629,
-    -0.000017,0.565804,0.824540,
-    -0.000034,-0.397272,0.917701,
-    -0.000034,-0.397272,0.917701,
-    0.350045,-0.397976,0.847988,
-    0.314824,0.565051,0.762629,
-    0.350045,-0.397976,0.847988,
-    -0.000034,-0.397272,0.917701,
-    -0.000113,-0.353939,0.935269,
-    -0.000113,-0.353939,0.935269,
-    0.356474,-0.355199,0.864153,
-    0.350045,-0.397976,0.847988,
-    0.356474,-0.355199,0.864153,
-    -0.000113,-0.353939,0.935269,
-    -0.000075,0.624890,0.780712,
-    -0.000075,0.624890,0.780712,
-    0.297983,0.625515,0.721067,
-    0.356474,-0.355199,0.864153,
-    -0.314825,0.565035,0.762640,
-    -0.000017,0.565804,0.824540,
-    0.000000,1.000000,0.000000,
-    -0.000017,0.565804,0.824540,
-    -0.314825,0.565035,0.762640,
-    -0.350034,-0.397998,0.847982,
-    -0.350034,-0.397998,0.847982,
-    -0.000034,-0.397272,0.917701,
-    -0.000017,0.565804,0.824540,
-    -0.000034,-0.397272,0.917701,
-    -0.350034,-0.397998,0.847982,
-    -0.356441,-0.355261,0.864141,
-    -0.356441,-0.355261,0.864141,
-    -0.000113,-0.353939,0.935269,
-    -0.000034,-0.397272,0.917701,
-    -0.000113,-0.353939,0.935269,
-    -0.356441,-0.355261,0.864141,
-    -0.297933,0.625625,0.720992,
-    -0.297933,0.625625,0.720992,
-    -0.000075,0.624890,0.780712,
-    -0.000113,-0.353939,0.935269,
-    -0.583338,0.565165,0.583357,
-    -0.314825,0.565035,0.762640,
-    0.000000,1.000000,0.000000,
-    -0.314825,0.565035,0.762640,
-    -0.583338,0.565165,0.583357,
-    -0.648448,-0.398726,0.648485,
-    -0.648448,-0.398726,0.648485,
-    -0.350034,-0.397998,0.847982,
-    -0.314825,0.565035,0.762640,
-    -0.350034,-0.397998,0.847982,
-    -0.648448,-0.398726,0.648485,
-    -0.660748,-0.355894,0.660872,
-    -0.660748,-0.355894,0.660872,
-    -0.356441,-0.355261,0.864141,
-    -0.350034,-0.397998,0.847982,
-    -0.356441,-0.355261,0.864141,
-    -0.660748,-0.355894,0.660872,
-    -0.551780,0.625290,0.551862,
-    -0.551780,0.625290,0.551862,
-    -0.297933,0.625625,0.720992,
-    -0.356441,-0.355261,0.864141,
-    -0.762629,0.565051,0.314824,
-    -0.583338,0.565165,0.583357,
-    0.000000,1.000000,0.000000,
-    -0.583338,0.565165,0.583357,
-    -0.762629,0.565051,0.314824,
-    -0.847988,-0.397976,0.350045,
-    -0.847988,-0.397976,0.350045,
-    -0.648448,-0.398726,0.648485,
-    -0.583338,0.565165,0.583357,
-    -0.648448,-0.398726,0.648485,
-    -0.847988,-0.397976,0.350045,
-    -0.864153,-0.355199,0.356474,
-    -0.864153,-0.355199,0.356474,
-    -0.660748,-0.355894,0.660872,
-    -0.648448,-0.398726,0.648485,
-    -0.660748,-0.355894,0.660872,
-    -0.864153,-0.355199,0.356474,
-    -0.721067,0.625515,0.297983,
-    -0.721067,0.625515,0.297983,
-    -0.551780,0.625290,0.551862,
-    -0.660748,-0.355894,0.660872,
-    -0.824540,0.565804,-0.000017,
-    -0.762629,0.565051,0.314824,
-    0.000000,1.000000,0.000000,
-    -0.762629,0.565051,0.314824,
-    -0.824540,0.565804,-0.000017,
-    -0.917701,-0.397272,-0.000034,
-    -0.917701,-0.397272,-0.000034,
-    -0.847988,-0.397976,0.350045,
-    -0.762629,0.565051,0.314824,
-    -0.847988,-0.397976,0.350045,
-    -0.917701,-0.397272,-0.000034,
-    -0.935269,-0.353939,-0.000113,
-    -0.935269,-0.353939,-0.000113,
-    -0.864153,-0.355199,0.356474,
-    -0.847988,-0.397976,0.350045,
-    -0.864153,-0.355199,0.356474,
-    -0.935269,-0.353939,-0.000113,
-    -0.780712,0.624890,-0.000075,
-    -0.780712,0.624890,-0.000075,
-    -0.721067,0.625515,0.297983,
-    -0.864153,-0.355199,0.356474,
-    -0.762640,0.565035,-0.314825,
-    -0.824540,0.565804,-0.000017,
-    0.000000,1.000000,0.000000,
-    -0.824540,0.565804,-0.000017,
-    -0.762640,0.565035,-0.314825,
-    -0.847982,-0.397998,-0.350034,
-    -0.847982,-0.397998,-0.350034,
-    -0.917701,-0.397272,-0.000034,
-    -0.824540,0.565804,-0.000017,
-    -0.917701,-0.397272,-0.000034,
-    -0.847982,-0.397998,-0.350034,
-    -0.864141,-0.355261,-0.356441,
-    -0.864141,-0.355261,-0.356441,
-    -0.935269,-0.353939,-0.000113,
-    -0.917701,-0.397272,-0.000034,
-    -0.935269,-0.353939,-0.000113,
-    -0.864141,-0.355261,-0.356441,
-    -0.720992,0.625625,-0.297933,
-    -0.720992,0.625625,-0.297933,
-    -0.780712,0.624890,-0.000075,
-    -0.935269,-0.353939,-0.000113,
-    -0.583357,0.565165,-0.583338,
-    -0.762640,0.565035,-0.314825,
-    0.000000,1.000000,0.000000,
-    -0.762640,0.565035,-0.314825,
-    -0.583357,0.565165,-0.583338,
-    -0.648485,-0.398726,-0.648448,
-    -0.648485,-0.398726,-0.648448,
-    -0.847982,-0.397998,-0.350034,
-    -0.762640,0.565035,-0.314825,
-    -0.847982,-0.397998,-0.350034,
-    -0.648485,-0.398726,-0.648448,
-    -0.660872,-0.355894,-0.660748,
-    -0.660872,-0.355894,-0.660748,
-    -0.864141,-0.355261,-0.356441,
-    -0.847982,-0.397998,-0.350034,
-    -0.864141,-0.355261,-0.356441,
-    -0.660872,-0.355894,-0.660748,
-    -0.551862,0.625290,-0.551780,
-    -0.551862,0.625290,-0.551780,
-    -0.720992,0.625625,-0.297933,
-    -0.864141,-0.355261,-0.356441,
-    -0.314824,0.565051,-0.762629,
-    -0.583357,0.565165,-0.583338,
-    0.000000,1.000000,0.000000,
-    -0.583357,0.565165,-0.583338,
-    -0.314824,0.565051,-0.762629,
-    -0.350045,-0.397976,-0.847988,
-    -0.350045,-0.397976,-0.847988,
-    -0.648485,-0.398726,-0.648448,
-    -0.583357,0.565165,-0.583338,
-    -0.648485,-0.398726,-0.648448,
-    -0.350045,-0.397976,-0.847988,
-    -0.356474,-0.355199,-0.864153,
-    -0.356474,-0.355199,-0.864153,
-    -0.660872,-0.355894,-0.660748,
-    -0.648485,-0.398726,-0.648448,
-    -0.660872,-0.355894,-0.660748,
-    -0.356474,-0.355199,-0.864153,
-    -0.297983,0.625515,-0.721067,
-    -0.297983,0.625515,-0.721067,
-    -0.551862,0.625290,-0.551780,
-    -0.660872,-0.355894,-0.660748,
-    0.000017,0.565804,-0.824540,
-    -0.314824,0.565051,-0.762629,
-    0.000000,1.000000,0.000000,
-    -0.314824,0.565051,-0.762629,
-    0.000017,0.565804,-0.824540,
-    0.000034,-0.397272,-0.917701,
-    0.000034,-0.397272,-0.917701,
-    -0.350045,-0.397976,-0.847988,
-    -0.314824,0.565051,-0.762629,
-    -0.350045,-0.397976,-0.847988,
-    0.000034,-0.397272,-0.917701,
-    0.000113,-0.353939,-0.935269,
-    0.000113,-0.353939,-0.935269,
-    -0.356474,-0.355199,-0.864153,
-    -0.350045,-0.397976,-0.847988,
-    -0.356474,-0.355199,-0.864153,
-    0.000113,-0.353939,-0.935269,
-    0.000075,0.624890,-0.780712,
-    0.000075,0.624890,-0.780712,
-    -0.297983,0.625515,-0.721067,
-    -0.356474,-0.355199,-0.864153,
-    0.314825,0.565035,-0.762640,
-    0.000017,0.565804,-0.824540,
-    0.000000,1.000000,0.000000,
-    0.000017,0.565804,-0.824540,
-    0.314825,0.565035,-0.762640,
-    0.350034,-0.397998,-0.847982,
-    0.350034,-0.397998,-0.847982,
-    0.000034,-0.397272,-0.917701,
-    0.000017,0.565804,-0.824540,
-    0.000034,-0.397272,-0.917701,
-    0.350034,-0.397998,-0.847982,
-    0.356441,-0.355261,-0.864141,
-    0.356441,-0.355261,-0.864141,
-    0.000113,-0.353939,-0.935269,
-    0.000034,-0.397272,-0.917701,
-    0.000113,-0.353939,-0.935269,
-    0.356441,-0.355261,-0.864141,
-    0.297933,0.625625,-0.720992,
-    0.297933,0.625625,-0.720992,
-    0.000075,0.624890,-0.780712,
-    0.000113,-0.353939,-0.935269,
-    0.583338,0.565165,-0.583357,
-    0.314825,0.565035,-0.762640,
-    0.000000,1.000000,0.000000,
-    0.314825,0.565035,-0.762640,
-    0.583338,0.565165,-0.583357,
-    0.648448,-0.398726,-0.648485,
-    0.648448,-0.398726,-0.648485,
-    0.350034,-0.397998,-0.847982,
-    0.314825,0.565035,-0.762640,
-    0.350034,-0.397998,-0.847982,
-    0.648448,-0.398726,-0.648485,
-    0.660748,-0.355894,-0.660872,
-    0.660748,-0.355894,-0.660872,
-    0.356441,-0.355261,-0.864141,
-    0.350034,-0.397998,-0.847982,
-    0.356441,-0.355261,-0.864141,
-    0.660748,-0.355894,-0.660872,
-    0.551780,0.625290,-0.551862,
-    0.551780,0.625290,-0.551862,
-    0.297933,0.625625,-0.720992,
-    0.356441,-0.355261,-0.864141,
-    0.762629,0.565051,-0.314824,
-    0.583338,0.565165,-0.583357,
-    0.000000,1.000000,0.000000,
-    0.583338,0.565165,-0.583357,
-    0.762629,0.565051,-0.314824,
-    0.847988,-0.397976,-0.350045,
-    0.847988,-0.397976,-0.350045,
-    0.648448,-0.398726,-0.648485,
-    0.583338,0.565165,-0.583357,
-    0.648448,-0.398726,-0.648485,
-    0.847988,-0.397976,-0.350045,
-    0.864153,-0.355199,-0.356474,
-    0.864153,-0.355199,-0.356474,
-    0.660748,-0.355894,-0.660872,
-    0.648448,-0.398726,-0.648485,
-    0.660748,-0.355894,-0.660872,
-    0.864153,-0.355199,-0.356474,
-    0.721067,0.625515,-0.297983,
-    0.721067,0.625515,-0.297983,
-    0.551780,0.625290,-0.551862,
-    0.660748,-0.355894,-0.660872,
-    0.824540,0.565804,0.000017,
-    0.762629,0.565051,-0.314824,
-    0.000000,1.000000,0.000000,
-    0.762629,0.565051,-0.314824,
-    0.824540,0.565804,0.000017,
-    0.917701,-0.397272,0.000034,
-    0.917701,-0.397272,0.000034,
-    0.847988,-0.397976,-0.350045,
-    0.762629,0.565051,-0.314824,
-    0.847988,-0.397976,-0.350045,
-    0.917701,-0.397272,0.000034,
-    0.935269,-0.353939,0.000113,
-    0.935269,-0.353939,0.000113,
-    0.864153,-0.355199,-0.356474,
-    0.847988,-0.397976,-0.350045,
-    0.864153,-0.355199,-0.356474,
-    0.935269,-0.353939,0.000113,
-    0.780712,0.624890,0.000075,
-    0.780712,0.624890,0.000075,
-    0.721067,0.625515,-0.297983,
-    0.864153,-0.355199,-0.356474,
-    0.780712,0.624890,0.000075,
-    0.720992,0.625625,0.297933,
-    0.217978,0.971775,0.090216,
-    0.217978,0.971775,0.090216,
-    0.236583,0.971611,0.000000,
-    0.780712,0.624890,0.000075,
-    0.236583,0.971611,0.000000,
-    0.217978,0.971775,0.090216,
-    0.159589,0.984977,0.065961,
-    0.159589,0.984977,0.065961,
-    0.173084,0.984907,0.000000,
-    0.236583,0.971611,0.000000,
-    0.173084,0.984907,0.000000,
-    0.159589,0.984977,0.065961,
-    0.350498,0.925311,0.144740,
-    0.350498,0.925311,0.144740,
-    0.379703,0.925108,0.000000,
-    0.173084,0.984907,0.000000,
-    0.379703,0.925108,0.000000,
-    0.350498,0.925311,0.144740,
-    0.485590,0.850653,0.201474,
-    0.485590,0.850653,0.201474,
-    0.526673,0.850068,0.000000,
-    0.379703,0.925108,0.000000,
-    0.720992,0.625625,0.297933,
-    0.551862,0.625290,0.551780,
-    0.166631,0.971838,0.166631,
-    0.166631,0.971838,0.166631,
-    0.217978,0.971775,0.090216,
-    0.720992,0.625625,0.297933,
-    0.217978,0.971775,0.090216,
-    0.166631,0.971838,0.166631,
-    0.121908,0.985026,0.121908,
-    0.121908,0.985026,0.121908,
-    0.159589,0.984977,0.065961,
-    0.217978,0.971775,0.090216,
-    0.159589,0.984977,0.065961,
-    0.121908,0.985026,0.121908,
-    0.267668,0.925585,0.267668,
-    0.267668,0.925585,0.267668,
-    0.350498,0.925311,0.144740,
-    0.159589,0.984977,0.065961,
-    0.350498,0.925311,0.144740,
-    0.267668,0.925585,0.267668,
-    0.371315,0.851029,0.371315,
-    0.371315,0.851029,0.371315,
-    0.485590,0.850653,0.201474,
-    0.350498,0.925311,0.144740,
-    0.551862,0.625290,0.551780,
-    0.297983,0.625515,0.721067,
-    0.090216,0.971775,0.217978,
-    0.090216,0.971775,0.217978,
-    0.166631,0.971838,0.166631,
-    0.551862,0.625290,0.551780,
-    0.166631,0.971838,0.166631,
-    0.090216,0.971775,0.217978,
-    0.065961,0.984977,0.159589,
-    0.065961,0.984977,0.159589,
-    0.121908,0.985026,0.121908,
-    0.166631,0.971838,0.166631,
-    0.121908,0.985026,0.121908,
-    0.065961,0.984977,0.159589,
-    0.144740,0.925311,0.350498,
-    0.144740,0.925311,0.350498,
-    0.267668,0.925585,0.267668,
-    0.121908,0.985026,0.121908,
-    0.267668,0.925585,0.267668,
-    0.144740,0.925311,0.350498,
-    0.201475,0.850653,0.485590,
-    0.201475,0.850653,0.485590,
-    0.371315,0.851029,0.371315,
-    0.267668,0.925585,0.267668,
-    0.297983,0.625515,0.721067,
-    -0.000075,0.624890,0.780712,
-    0.000000,0.971611,0.236583,
-    0.000000,0.971611,0.236583,
-    0.090216,0.971775,0.217978,
-    0.297983,0.625515,0.721067,
-    0.090216,0.971775,0.217978,
-    0.000000,0.971611,0.236583,
-    0.000000,0.984907,0.173084,
-    0.000000,0.984907,0.173084,
-    0.065961,0.984977,0.159589,
-    0.090216,0.971775,0.217978,
-    0.065961,0.984977,0.159589,
-    0.000000,0.984907,0.173084,
-    0.000000,0.925108,0.379703,
-    0.000000,0.925108,0.379703,
-    0.144740,0.925311,0.350498,
-    0.065961,0.984977,0.159589,
-    0.144740,0.925311,0.350498,
-    0.000000,0.925108,0.379703,
-    0.000000,0.850068,0.526673,
-    0.000000,0.850068,0.526673,
-    0.201475,0.850653,0.485590,
-    0.144740,0.925311,0.350498,
-    -0.000075,0.624890,0.780712,
-    -0.297933,0.625625,0.720992,
-    -0.090216,0.971775,0.217978,
-    -0.090216,0.971775,0.217978,
-    0.000000,0.971611,0.236583,
-    -0.000075,0.624890,0.780712,
-    0.000000,0.971611,0.236583,
-    -0.090216,0.971775,0.217978,
-    -0.065961,0.984977,0.159589,
-    -0.065961,0.984977,0.159589,
-    0.000000,0.984907,0.173084,
-    0.000000,0.971611,0.236583,
-    0.000000,0.984907,0.173084,
-    -0.065961,0.984977,0.159589,
-    -0.144740,0.925311,0.350498,
-    -0.144740,0.925311,0.350498,
-    0.000000,0.925108,0.379703,
-    0.000000,0.984907,0.173084,
-    0.000000,0.925108,0.379703,
-    -0.144740,0.925311,0.350498,
-    -0.201474,0.850653,0.485590,
-    -0.201474,0.850653,0.485590,
-    0.000000,0.850068,0.526673,
-    0.000000,0.925108,0.379703,
-    -0.297933,0.625625,0.720992,
-    -0.551780,0.625290,0.551862,
-    -0.166631,0.971838,0.166631,
-    -0.166631,0.971838,0.166631,
-    -0.090216,0.971775,0.217978,
-    -0.297933,0.625625,0.720992,
-    -0.090216,0.971775,0.217978,
-    -0.166631,0.971838,0.166631,
-    -0.121908,0.985026,0.121908,
-    -0.121908,0.985026,0.121908,
-    -0.065961,0.984977,0.159589,
-    -0.090216,0.971775,0.217978,
-    -0.065961,0.984977,0.159589,
-    -0.121908,0.985026,0.121908,
-    -0.267668,0.925585,0.267668,
-    -0.267668,0.925585,0.267668,
-    -0.144740,0.925311,0.350498,
-    -0.065961,0.984977,0.159589,
-    -0.144740,0.925311,0.350498,
-    -0.267668,0.925585,0.267668,
-    -0.371315,0.851029,0.371315,
-    -0.371315,0.851029,0.371315,
-    -0.201474,0.850653,0.485590,
-    -0.144740,0.925311,0.350498,
-    -0.551780,0.625290,0.551862,
-    -0.721067,0.625515,0.297983,
-    -0.217978,0.971775,0.090216,
-    -0.217978,0.971775,0.090216,
-    -0.166631,0.971838,0.166631,
-    -0.551780,0.625290,0.551862,
-    -0.166631,0.971838,0.166631,
-    -0.217978,0.971775,0.090216,
-    -0.159589,0.984977,0.065961,
-    -0.159589,0.984977,0.065961,
-    -0.121908,0.985026,0.121908,
-    -0.166631,0.971838,0.166631,
-    -0.121908,0.985026,0.121908,
-    -0.159589,0.984977,0.065961,
-    -0.350498,0.925311,0.144740,
-    -0.350498,0.925311,0.144740,
-    -0.267668,0.925585,0.267668,
-    -0.121908,0.985026,0.121908,
-    -0.267668,0.925585,0.267668,
-    -0.350498,0.925311,0.144740,
-    -0.485590,0.850653,0.201475,
-    -0.485590,0.850653,0.201475,
-    -0.371315,0.851029,0.371315,
-    -0.267668,0.925585,0.267668,
-    -0.721067,0.625515,0.297983,
-    -0.780712,0.624890,-0.000075,
-    -0.236583,0.971611,0.000000,
-    -0.236583,0.971611,0.000000,
-    -0.217978,0.971775,0.090216,
-    -0.721067,0.625515,0.297983,
-    -0.217978,0.971775,0.090216,
-    -0.236583,0.971611,0.000000,
-    -0.173084,0.984907,0.000000,
-    -0.173084,0.984907,0.000000,
-    -0.159589,0.984977,0.065961,
-    -0.217978,0.971775,0.090216,
-    -0.159589,0.984977,0.065961,
-    -0.173084,0.984907,0.000000,
-    -0.379703,0.925108,0.000000,
-    -0.379703,0.925108,0.000000,
-    -0.350498,0.925311,0.144740,
-    -0.159589,0.984977,0.065961,
-    -0.350498,0.925311,0.144740,
-    -0.379703,0.925108,0.000000,
-    -0.526673,0.850068,0.000000,
-    -0.526673,0.850068,0.000000,
-    -0.485590,0.850653,0.201475,
-    -0.350498,0.925311,0.144740,
-    -0.780712,0.624890,-0.000075,
-    -0.720992,0.625625,-0.297933,
-    -0.217978,0.971775,-0.090216,
-    -0.217978,0.971775,-0.090216,
-    -0.236583,0.971611,0.000000,
-    -0.780712,0.624890,-0.000075,
-    -0.236583,0.971611,0.000000,
-    -0.217978,0.971775,-0.090216,
-    -0.159589,0.984977,-0.065961,
-    -0.159589,0.984977,-0.065961,
-    -0.173084,0.984907,0.000000,
-    -0.236583,0.971611,0.000000,
-    -0.173084,0.984907,0.000000,
-    -0.159589,0.984977,-0.065961,
-    -0.350498,0.925311,-0.144740,
-    -0.350498,0.925311,-0.144740,
-    -0.379703,0.925108,0.000000,
-    -0.173084,0.984907,0.000000,
-    -0.379703,0.925108,0.000000,
-    -0.350498,0.925311,-0.144740,
-    -0.485590,0.850653,-0.201474,
-    -0.485590,0.850653,-0.201474,
-    -0.526673,0.850068,0.000000,
-    -0.379703,0.925108,0.000000,
-    -0.720992,0.625625,-0.297933,
-    -0.551862,0.625290,-0.551780,
-    -0.166631,0.971838,-0.166631,
-    -0.166631,0.971838,-0.166631,
-    -0.217978,0.971775,-0.090216,
-    -0.720992,0.625625,-0.297933,
-    -0.217978,0.971775,-0.090216,
-    -0.166631,0.971838,-0.166631,
-    -0.121908,0.985026,-0.121908,
-    -0.121908,0.985026,-0.121908,
-    -0.159589,0.984977,-0.065961,
-    -0.217978,0.971775,-0.090216,
-    -0.159589,0.984977,-0.065961,
-    -0.121908,0.985026,-0.121908,
-    -0.267668,0.925585,-0.267668,
-    -0.267668,0.925585,-0.267668,
-    -0.350498,0.925311,-0.144740,
-    -0.159589,0.984977,-0.065961,
-    -0.350498,0.925311,-0.144740,
-    -0.267668,0.925585,-0.267668,
-    -0.371315,0.851029,-0.371315,
-    -0.371315,0.851029,-0.371315,
-    -0.485590,0.850653,-0.201474,
-    -0.350498,0.925311,-0.144740,
-    -0.551862,0.625290,-0.551780,
-    -0.297983,0.625515,-0.721067,
-    -0.090216,0.971775,-0.217978,
-    -0.090216,0.971775,-0.217978,
-    -0.166631,0.971838,-0.166631,
-    -0.551862,0.625290,-0.551780,
-    -0.166631,0.971838,-0.166631,
-    -0.090216,0.971775,-0.217978,
-    -0.065961,0.984977,-0.159589,
-    -0.065961,0.984977,-0.159589,
-    -0.121908,0.985026,-0.121908,
-    -0.166631,0.971838,-0.166631,
-    -0.121908,0.985026,-0.121908,
-    -0.065961,0.984977,-0.159589,
-    -0.144740,0.925311,-0.350498,
-    -0.144740,0.925311,-0.350498,
-    -0.267668,0.925585,-0.267668,
-    -0.121908,0.985026,-0.121908,
-    -0.267668,0.925585,-0.267668,
-    -0.144740,0.925311,-0.350498,
-    -0.201475,0.850653,-0.485590,
-    -0.201475,0.850653,-0.485590,
-    -0.371315,0.851029,-0.371315,
-    -0.267668,0.925585,-0.267668,
-    -0.297983,0.625515,-0.721067,
-    0.000075,0.624890,-0.780712,
-    0.000000,0.971611,-0.236583,
-    0.000000,0.971611,-0.236583,
-    -0.090216,0.971775,-0.217978,
-    -0.297983,0.625515,-0.721067,
-    -0.090216,0.971775,-0.217978,
-    0.000000,0.971611,-0.236583,
-    0.000000,0.984907,-0.173084,
-    0.000000,0.984907,-0.173084,
-    -0.065961,0.984977,-0.159589,
-    -0.090216,0.971775,-0.217978,
-    -0.065961,0.984977,-0.159589,
-    0.000000,0.984907,-0.173084,
-    0.000000,0.925108,-0.379703,
-    0.000000,0.925108,-0.379703,
-    -0.144740,0.925311,-0.350498,
-    -0.065961,0.984977,-0.159589,
-    -0.144740,0.925311,-0.350498,
-    0.000000,0.925108,-0.379703,
-    0.000000,0.850068,-0.526673,
-    0.000000,0.850068,-0.526673,
-    -0.201475,0.850653,-0.485590,
-    -0.144740,0.925311,-0.350498,
-    0.000075,0.624890,-0.780712,
-    0.297933,0.625625,-0.720992,
-    0.090216,0.971775,-0.217978,
-    0.090216,0.971775,-0.217978,
-    0.000000,0.971611,-0.236583,
-    0.000075,0.624890,-0.780712,
-    0.000000,0.971611,-0.236583,
-    0.090216,0.971775,-0.217978,
-    0.065961,0.984977,-0.159589,
-    0.065961,0.984977,-0.159589,
-    0.000000,0.984907,-0.173084,
-    0.000000,0.971611,-0.236583,
-    0.000000,0.984907,-0.173084,
-    0.065961,0.984977,-0.159589,
-    0.144740,0.925311,-0.350498,
-    0.144740,0.925311,-0.350498,
-    0.000000,0.925108,-0.379703,
-    0.000000,0.984907,-0.173084,
-    0.000000,0.925108,-0.379703,
-    0.144740,0.925311,-0.350498,
-    0.201474,0.850653,-0.485590,
-    0.201474,0.850653,-0.485590,
-    0.000000,0.850068,-0.526673,
-    0.000000,0.925108,-0.379703,
-    0.297933,0.625625,-0.720992,
-    0.551780,0.625290,-0.551862,
-    0.166631,0.971838,-0.166631,
-    0.166631,0.971838,-0.166631,
-    0.090216,0.971775,-0.217978,
-    0.297933,0.625625,-0.720992,
-    0.090216,0.971775,-0.217978,
-    0.166631,0.971838,-0.166631,
-    0.121908,0.985026,-0.121908,
-    0.121908,0.985026,-0.121908,
-    0.065961,0.984977,-0.159589,
-    0.090216,0.971775,-0.217978,
-    0.065961,0.984977,-0.159589,
-    0.121908,0.985026,-0.121908,
-    0.267668,0.925585,-0.267668,
-    0.267668,0.925585,-0.267668,
-    0.144740,0.925311,-0.350498,
-    0.065961,0.984977,-0.159589,
-    0.144740,0.925311,-0.350498,
-    0.267668,0.925585,-0.267668,
-    0.371315,0.851029,-0.371315,
-    0.371315,0.851029,-0.371315,
-    0.201474,0.850653,-0.485590,
-    0.144740,0.925311,-0.350498,
-    0.551780,0.625290,-0.551862,
-    0.721067,0.625515,-0.297983,
-    0.217978,0.971775,-0.090216,
-    0.217978,0.971775,-0.090216,
-    0.166631,0.971838,-0.166631,
-    0.551780,0.625290,-0.551862,
-    0.166631,0.971838,-0.166631,
-    0.217978,0.971775,-0.090216,
-    0.159589,0.984977,-0.065961,
-    0.159589,0.984977,-0.065961,
-    0.121908,0.985026,-0.121908,
-    0.166631,0.971838,-0.166631,
-    0.121908,0.985026,-0.121908,
-    0.159589,0.984977,-0.065961,
-    0.350498,0.925311,-0.144740,
-    0.350498,0.925311,-0.144740,
-    0.267668,0.925585,-0.267668,
-    0.121908,0.985026,-0.121908,
-    0.267668,0.925585,-0.267668,
-    0.350498,0.925311,-0.144740,
-    0.485590,0.850653,-0.201475,
-    0.485590,0.850653,-0.201475,
-    0.371315,0.851029,-0.371315,
-    0.267668,0.925585,-0.267668,
-    0.721067,0.625515,-0.297983,
-    0.780712,0.624890,0.000075,
-    0.236583,0.971611,0.000000,
-    0.236583,0.971611,0.000000,
-    0.217978,0.971775,-0.090216,
-    0.721067,0.625515,-0.297983,
-    0.217978,0.971775,-0.090216,
-    0.236583,0.971611,0.000000,
-    0.173084,0.984907,0.000000,
-    0.173084,0.984907,0.000000,
-    0.159589,0.984977,-0.065961,
-    0.217978,0.971775,-0.090216,
-    0.159589,0.984977,-0.065961,
-    0.173084,0.984907,0.000000,
-    0.379703,0.925108,0.000000,
-    0.379703,0.925108,0.000000,
-    0.350498,0.925311,-0.144740,
-    0.159589,0.984977,-0.065961,
-    0.350498,0.925311,-0.144740,
-    0.379703,0.925108,0.000000,
-    0.526673,0.850068,0.000000,
-    0.526673,0.850068,0.000000,
-    0.485590,0.850653,-0.201475,
-    0.350498,0.925311,-0.144740,
-];
@@ -0,0 +1,279 @@
+use std::ops::Deref;
+
+use image::RgbaImage;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Response, WebGlRenderingContext};
+
+use crate::backend::Backend;
+
+// drop で delete_texture するテクスチャ
+pub struct Texture<C: Backend = WebGlRenderingContext> {
+    context: C,
+    texture: C::Texture,
+}
+
+impl<C: Backend> Texture<C> {
+    pub fn new(context: &C) -> Result<Texture<C>, String> {
+        let texture = context
+            .create_texture()
+            .ok_or("failed create texture")?;
+
+        Ok(Texture {
+            context: context.clone(),
+            texture,
+        })
+    }
+}
+
+impl<C: Backend> Deref for Texture<C> {
+    type Target = C::Texture;
+
+    fn deref(&self) -> &C::Texture {
+        &self.texture
+    }
+}
+
+impl<C: Backend> Drop for Texture<C> {
+    fn drop(&mut self) {
+        self.context.delete_texture(Some(&self.texture));
+    }
+}
+
+// TEXTURE_CUBE_MAP_POSITIVE_X から順に +X, -X, +Y, -Y, +Z, -Z
+pub const FACE_NAMES: [&str; 6] = [
+    "positive_x",
+    "negative_x",
+    "positive_y",
+    "negative_y",
+    "positive_z",
+    "negative_z",
+];
+
+pub const FACE_TARGETS: [u32; 6] = [
+    WebGlRenderingContext::TEXTURE_CUBE_MAP_POSITIVE_X,
+    WebGlRenderingContext::TEXTURE_CUBE_MAP_NEGATIVE_X,
+    WebGlRenderingContext::TEXTURE_CUBE_MAP_POSITIVE_Y,
+    WebGlRenderingContext::TEXTURE_CUBE_MAP_NEGATIVE_Y,
+    WebGlRenderingContext::TEXTURE_CUBE_MAP_POSITIVE_Z,
+    WebGlRenderingContext::TEXTURE_CUBE_MAP_NEGATIVE_Z,
+];
+
+// 6 面の画像 (png / jpeg など) を展開し、check_faces で大きさを確かめる
+pub fn decode_faces(sources: &[&[u8]]) -> Result<Vec<RgbaImage>, String> {
+    if sources.len() != 6 {
+        return Err(format!("cubemap needs 6 faces, got {}", sources.len()));
+    }
+
+    let mut faces: Vec<RgbaImage> = Vec::with_capacity(6);
+    for (name, source) in FACE_NAMES.iter().zip(sources.iter()) {
+        let face = image::load_from_memory(source)
+            .map_err(|e| format!("{}: {}", name, e))?
+            .into_rgba8();
+        faces.push(face);
+    }
+
+    check_faces(&faces)?;
+    Ok(faces)
+}
+
+// 6 面が全て同じ大きさの、空でない正方形か
+pub fn check_faces(faces: &[RgbaImage]) -> Result<(), String> {
+    if faces.len() != 6 {
+        return Err(format!("cubemap needs 6 faces, got {}", faces.len()));
+    }
+
+    let first = &faces[0];
+    for (name, face) in FACE_NAMES.iter().zip(faces.iter()) {
+        let (width, height) = face.dimensions();
+        if width == 0 || height == 0 {
+            return Err(format!("{}: face is empty", name));
+        }
+        if width != height {
+            return Err(format!("{}: face must be square, got {}x{}", name, width, height));
+        }
+        if first.dimensions() != face.dimensions() {
+            return Err(format!(
+                "{}: face is {}x{} but {} is {}x{}",
+                name,
+                width,
+                height,
+                FACE_NAMES[0],
+                first.width(),
+                first.height()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn create_cubemap<C: Backend>(context: &C, faces: &[RgbaImage]) -> Result<Texture<C>, String> {
+    if faces.len() != 6 {
+        return Err(format!("cubemap needs 6 faces, got {}", faces.len()));
+    }
+
+    let tex = Texture::new(context)?;
+    context
+        .bind_texture(WebGlRenderingContext::TEXTURE_CUBE_MAP, Some(&tex));
+
+    for (target, face) in FACE_TARGETS.iter().zip(faces.iter()) {
+        context.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+                *target, 
+                0, 
+                WebGlRenderingContext::RGBA as i32, 
+                face.width() as i32,
+                face.height() as i32,
+                0,
+                WebGlRenderingContext::RGBA,
+                WebGlRenderingContext::UNSIGNED_BYTE,
+                Some(face.as_raw())
+            )?;
+    }
+
+    // WebGL 1 では 2 のべき乗でないと mipmap を作れない
+    if faces[0].width().is_power_of_two() {
+        context
+            .generate_mipmap(WebGlRenderingContext::TEXTURE_CUBE_MAP);
+    }
+
+    context
+        .tex_parameteri(
+            WebGlRenderingContext::TEXTURE_CUBE_MAP,
+            WebGlRenderingContext::TEXTURE_MIN_FILTER,
+            WebGlRenderingContext::LINEAR as i32,
+        );
+    
+    context
+        .tex_parameteri(
+            WebGlRenderingContext::TEXTURE_CUBE_MAP,
+            WebGlRenderingContext::TEXTURE_MAG_FILTER,
+            WebGlRenderingContext::LINEAR as i32,
+        );
+    
+    context
+        .tex_parameteri(
+            WebGlRenderingContext::TEXTURE_CUBE_MAP,
+            WebGlRenderingContext::TEXTURE_WRAP_S,
+            WebGlRenderingContext::CLAMP_TO_EDGE as i32,
+        );
+    
+    context
+        .tex_parameteri(
+            WebGlRenderingContext::TEXTURE_CUBE_MAP,
+            WebGlRenderingContext::TEXTURE_WRAP_T,
+            WebGlRenderingContext::CLAMP_TO_EDGE as i32,
+        );
+
+    context
+        .bind_texture(WebGlRenderingContext::TEXTURE_CUBE_MAP, None);
+   
+    Ok(tex)
+}
+
+// levels[0] から 1x1 まで全ての mip level を与える。大きさは 2 のべき乗
+pub fn create_cubemap_levels<C: Backend>(context: &C, levels: &[Vec<RgbaImage>]) -> Result<Texture<C>, String> {
+    let size = levels
+        .first()
+        .and_then(|faces| faces.first())
+        .map(|face| face.width())
+        .ok_or("cubemap needs at least one level")?;
+    if !size.is_power_of_two() || levels.len() != size.trailing_zeros() as usize + 1 {
+        return Err(format!("{} levels do not make a mip chain for {}x{}", levels.len(), size, size));
+    }
+
+    let tex = Texture::new(context)?;
+    context
+        .bind_texture(WebGlRenderingContext::TEXTURE_CUBE_MAP, Some(&tex));
+
+    for (level, faces) in levels.iter().enumerate() {
+        if faces.len() != 6 {
+            return Err(format!("level {}: cubemap needs 6 faces, got {}", level, faces.len()));
+        }
+
+        for (target, face) in FACE_TARGETS.iter().zip(faces.iter()) {
+            context.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+                    *target,
+                    level as i32,
+                    WebGlRenderingContext::RGBA as i32,
+                    face.width() as i32,
+                    face.height() as i32,
+                    0,
+                    WebGlRenderingContext::RGBA,
+                    WebGlRenderingContext::UNSIGNED_BYTE,
+                    Some(face.as_raw())
+                )?;
+        }
+    }
+
+    set_parameters(context, WebGlRenderingContext::TEXTURE_CUBE_MAP, WebGlRenderingContext::LINEAR_MIPMAP_LINEAR);
+
+    context
+        .bind_texture(WebGlRenderingContext::TEXTURE_CUBE_MAP, None);
+
+    Ok(tex)
+}
+
+// mipmap なしの 2D テクスチャ (参照テーブルなど)
+pub fn create_texture<C: Backend>(context: &C, image: &RgbaImage) -> Result<Texture<C>, String> {
+    let tex = Texture::new(context)?;
+    context
+        .bind_texture(WebGlRenderingContext::TEXTURE_2D, Some(&tex));
+
+    context.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+            WebGlRenderingContext::TEXTURE_2D,
+            0,
+            WebGlRenderingContext::RGBA as i32,
+            image.width() as i32,
+            image.height() as i32,
+            0,
+            WebGlRenderingContext::RGBA,
+            WebGlRenderingContext::UNSIGNED_BYTE,
+            Some(image.as_raw())
+        )?;
+
+    set_parameters(context, WebGlRenderingContext::TEXTURE_2D, WebGlRenderingContext::LINEAR);
+
+    context
+        .bind_texture(WebGlRenderingContext::TEXTURE_2D, None);
+
+    Ok(tex)
+}
+
+// 拡大は LINEAR、端は CLAMP_TO_EDGE
+fn set_parameters<C: Backend>(context: &C, target: u32, min_filter: u32) {
+    context.tex_parameteri(target, WebGlRenderingContext::TEXTURE_MIN_FILTER, min_filter as i32);
+    context.tex_parameteri(target, WebGlRenderingContext::TEXTURE_MAG_FILTER, WebGlRenderingContext::LINEAR as i32);
+    context.tex_parameteri(target, WebGlRenderingContext::TEXTURE_WRAP_S, WebGlRenderingContext::CLAMP_TO_EDGE as i32);
+    context.tex_parameteri(target, WebGlRenderingContext::TEXTURE_WRAP_T, WebGlRenderingContext::CLAMP_TO_EDGE as i32);
+}
+
+pub async fn fetch_bytes(url: &str) -> Result<Vec<u8>, JsValue> {
+    let window = web_sys::window().ok_or("no window")?;
+    let response: Response = JsFuture::from(window.fetch_with_str(url))
+        .await?
+        .dyn_into()?;
+
+    if !response.ok() {
+        return Err(JsValue::from(format!("{}: HTTP {}", url, response.status())));
+    }
+
+    let buffer = JsFuture::from(response.array_buffer()?).await?;
+
+    Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+}
+
+// FACE_NAMES の順に 6 枚取得する
+pub async fn fetch_faces(urls: &[String]) -> Result<Vec<Vec<u8>>, JsValue> {
+    if urls.len() != 6 {
+        return Err(JsValue::from(format!("cubemap needs 6 urls, got {}", urls.len())));
+    }
+
+    let mut sources = Vec::with_capacity(6);
+    for url in urls.iter() {
+        sources.push(fetch_bytes(url).await?);
+    }
+
+    Ok(sources)
+}
@@ -0,0 +1,383 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlCanvasElement, WebGlRenderingContext};
+
+use crate::animation::Animation;
+use crate::controls::Controls;
+use crate::equirect::{self, Panorama};
+use crate::graph::Transform;
+use crate::light::Light;
+use crate::material::{BlinnPhong, Glass, Material, Pbr};
+use crate::probe::ProbeUpdate;
+use crate::resize;
+use crate::scene::Scene;
+use crate::shadow::ShadowOptions;
+use crate::texture;
+
+// JavaScript から teapot viewer を組み込むためのハンドル
+#[wasm_bindgen]
+pub struct Viewer {
+    scene: Rc<RefCell<Scene>>,
+    animation: Animation,
+}
+
+#[wasm_bindgen]
+impl Viewer {
+    // canvas は要素の id か HTMLCanvasElement
+    #[wasm_bindgen(constructor)]
+    pub fn new(canvas: JsValue) -> Result<Viewer, JsValue> {
+        let canvas = match canvas.as_string() {
+            Some(id) => web_sys::window()
+                .and_then(|window| window.document())
+                .ok_or("no document")?
+                .get_element_by_id(&id)
+                .ok_or_else(|| format!("canvas `{}` not found", id))?
+                .dyn_into::<HtmlCanvasElement>()?,
+            None => canvas.dyn_into::<HtmlCanvasElement>()?,
+        };
+
+        let context = canvas
+            .get_context("webgl")?
+            .ok_or("webgl is not supported")?
+            .dyn_into::<WebGlRenderingContext>()?;
+
+        resize::fit_canvas(&canvas);
+        let width = canvas.width() as i32;
+        let height = canvas.height() as i32;
+        context.viewport(0, 0, width, height);
+
+        context.clear_depth(1.0);
+        context.enable(WebGlRenderingContext::DEPTH_TEST);
+        context.depth_func(WebGlRenderingContext::LEQUAL);
+
+        let scene = Rc::new(RefCell::new(Scene::new_with_context(width, height, &context)?));
+        let camera = scene.borrow().camera();
+
+        let animation = Animation::start(scene.clone())?;
+        animation.set_controls(Controls::attach(&canvas, camera)?);
+        animation.observe_resize(canvas)?;
+
+        Ok(Viewer { scene, animation })
+    }
+
+    #[wasm_bindgen(js_name = setCamera)]
+    pub fn set_camera(&self, eye_x: f32, eye_y: f32, eye_z: f32, target_x: f32, target_y: f32, target_z: f32) {
+        let eye = nalgebra_glm::vec3(eye_x, eye_y, eye_z);
+        let target = nalgebra_glm::vec3(target_x, target_y, target_z);
+
+        self.scene.borrow().camera().borrow_mut().look_at(&eye, &target);
+    }
+
+    // 画角 (radian)
+    #[wasm_bindgen(js_name = setFov)]
+    pub fn set_fov(&self, fov: f32) {
+        self.scene.borrow().camera().borrow_mut().fov = fov;
+    }
+
+    // 列優先の 4x4 行列。平行移動・回転・拡大に分解して teapot の node に設定する
+    #[wasm_bindgen(js_name = setModelTransform)]
+    pub fn set_model_transform(&self, matrix: &[f32]) -> Result<(), JsValue> {
+        if matrix.len() != 16 {
+            return Err(JsValue::from(format!("expected 16 elements, got {}", matrix.len())));
+        }
+        let model = nalgebra_glm::make_mat4(matrix);
+
+        self.scene.borrow_mut().set_model(model).map_err(JsValue::from)
+    }
+
+    // 名前で探した node の親に対する変形。rotation は四元数 [x, y, z, w]
+    #[wasm_bindgen(js_name = setNodeTransform)]
+    pub fn set_node_transform(&self, name: &str, translation: &[f32], rotation: &[f32], scale: &[f32]) -> Result<(), JsValue> {
+        if rotation.len() != 4 {
+            return Err(JsValue::from(format!("expected 4 elements, got {}", rotation.len())));
+        }
+        let transform = Transform {
+            translation: vec3(translation)?,
+            rotation: nalgebra_glm::quat_normalize(&nalgebra_glm::quat(rotation[0], rotation[1], rotation[2], rotation[3])),
+            scale: vec3(scale)?,
+        };
+
+        let mut scene = self.scene.borrow_mut();
+        let graph = scene.graph_mut();
+        let node = graph
+            .find(name)
+            .ok_or_else(|| JsValue::from(format!("no node named {}", name)))?;
+
+        Ok(graph.set_transform(node, transform)?)
+    }
+
+    // radian/秒
+    #[wasm_bindgen(js_name = setSpin)]
+    pub fn set_spin(&self, spin: f32) {
+        self.scene.borrow_mut().set_spin(spin);
+    }
+
+    #[wasm_bindgen(js_name = setClearColor)]
+    pub fn set_clear_color(&self, r: f32, g: f32, b: f32, a: f32) {
+        self.scene.borrow_mut().set_clear_color([r, g, b, a]);
+    }
+
+    // 戻り値はライトの番号。direction は光の進む向き
+    #[wasm_bindgen(js_name = addDirectionalLight)]
+    pub fn add_directional_light(&self, direction: &[f32], color: &[f32], intensity: f32) -> Result<usize, JsValue> {
+        let light = Light::directional(vec3(direction)?, vec3(color)?, intensity);
+
+        Ok(self.scene.borrow_mut().add_light(light))
+    }
+
+    // attenuation は [constant, linear, quadratic]
+    #[wasm_bindgen(js_name = addPointLight)]
+    pub fn add_point_light(
+        &self,
+        position: &[f32],
+        color: &[f32],
+        intensity: f32,
+        attenuation: &[f32],
+    ) -> Result<usize, JsValue> {
+        let light = Light {
+            attenuation: vec3(attenuation)?,
+            ..Light::point(vec3(position)?, vec3(color)?, intensity)
+        };
+
+        Ok(self.scene.borrow_mut().add_light(light))
+    }
+
+    // inner_angle, outer_angle は円錐の半角 (radian)
+    #[wasm_bindgen(js_name = addSpotLight)]
+    pub fn add_spot_light(
+        &self,
+        position: &[f32],
+        direction: &[f32],
+        color: &[f32],
+        intensity: f32,
+        inner_angle: f32,
+        outer_angle: f32,
+    ) -> Result<usize, JsValue> {
+        let light = Light::spot(vec3(position)?, vec3(direction)?, vec3(color)?, intensity, inner_angle, outer_angle);
+
+        Ok(self.scene.borrow_mut().add_light(light))
+    }
+
+    #[wasm_bindgen(js_name = setLightIntensity)]
+    pub fn set_light_intensity(&self, index: usize, intensity: f32) -> Result<(), JsValue> {
+        let mut scene = self.scene.borrow_mut();
+        let light = scene
+            .light_mut(index)
+            .ok_or_else(|| JsValue::from(format!("no light at index {}", index)))?;
+        light.intensity = intensity;
+
+        Ok(())
+    }
+
+    // 影を落とすのは cast が true の最初の平行光源か spot
+    #[wasm_bindgen(js_name = setLightCastShadow)]
+    pub fn set_light_cast_shadow(&self, index: usize, cast: bool) -> Result<(), JsValue> {
+        let mut scene = self.scene.borrow_mut();
+        let light = scene
+            .light_mut(index)
+            .ok_or_else(|| JsValue::from(format!("no light at index {}", index)))?;
+        light.cast_shadow = cast;
+
+        Ok(())
+    }
+
+    // size: shadow map の一辺, bias: 深度の比較に足す値, pcf_radius: 0..2 (texel)
+    #[wasm_bindgen(js_name = setShadowOptions)]
+    pub fn set_shadow_options(&self, size: i32, bias: f32, pcf_radius: i32) -> Result<(), JsValue> {
+        if size <= 0 {
+            return Err(JsValue::from(format!("shadow map size must be positive, got {}", size)));
+        }
+
+        self.scene
+            .borrow_mut()
+            .set_shadow_options(ShadowOptions { size, bias, pcf_radius })
+            .map_err(JsValue::from)
+    }
+
+    // y = height に一辺 size の床を置く
+    #[wasm_bindgen(js_name = setGround)]
+    pub fn set_ground(&self, height: f32, size: f32) -> Result<(), JsValue> {
+        self.scene.borrow_mut().set_ground(height, size).map_err(JsValue::from)
+    }
+
+    #[wasm_bindgen(js_name = removeGround)]
+    pub fn remove_ground(&self) {
+        self.scene.borrow_mut().remove_ground();
+    }
+
+    #[wasm_bindgen(js_name = removeLight)]
+    pub fn remove_light(&self, index: usize) -> bool {
+        self.scene.borrow_mut().remove_light(index).is_some()
+    }
+
+    #[wasm_bindgen(js_name = clearLights)]
+    pub fn clear_lights(&self) {
+        self.scene.borrow_mut().clear_lights();
+    }
+
+    // Blinn-Phong の係数。reflectivity は環境マップの映り込みの割合 (0..1)
+    #[wasm_bindgen(js_name = setMaterial)]
+    pub fn set_material(
+        &self,
+        ambient: &[f32],
+        diffuse: &[f32],
+        specular: &[f32],
+        shininess: f32,
+        reflectivity: f32,
+    ) -> Result<(), JsValue> {
+        self.scene.borrow_mut().set_material(Material::BlinnPhong(BlinnPhong {
+            ambient: vec3(ambient)?,
+            diffuse: vec3(diffuse)?,
+            specular: vec3(specular)?,
+            shininess: shininess.max(1.0),
+            reflectivity: reflectivity.clamp(0.0, 1.0),
+        }));
+
+        Ok(())
+    }
+
+    // metallic-roughness の PBR。base_color はリニア、他は 0..1
+    #[wasm_bindgen(js_name = setPbrMaterial)]
+    pub fn set_pbr_material(
+        &self,
+        base_color: &[f32],
+        metallic: f32,
+        roughness: f32,
+        ambient_occlusion: f32,
+    ) -> Result<(), JsValue> {
+        self.scene.borrow_mut().set_material(Material::Pbr(Pbr {
+            base_color: vec3(base_color)?,
+            metallic,
+            roughness,
+            ambient_occlusion,
+        }));
+
+        Ok(())
+    }
+
+    // ior: 屈折率, dispersion: R と B の屈折率の差の半分 (0 で分散なし), tint: 屈折光の色
+    #[wasm_bindgen(js_name = setGlassMaterial)]
+    pub fn set_glass_material(&self, ior: f32, dispersion: f32, tint: &[f32]) -> Result<(), JsValue> {
+        if ior <= 0.0 {
+            return Err(JsValue::from(format!("index of refraction must be positive, got {}", ior)));
+        }
+
+        self.scene.borrow_mut().set_material(Material::Glass(Glass {
+            ior,
+            dispersion,
+            tint: vec3(tint)?,
+        }));
+
+        Ok(())
+    }
+
+    // teapot の中心から床と背景を size x size の 6 面に描き、静的な環境マップの代わりに映す
+    // every_frame が false の時は updateDynamicReflection を呼んだ次のフレームだけ描き直す
+    #[wasm_bindgen(js_name = enableDynamicReflection)]
+    pub fn enable_dynamic_reflection(&self, size: u32, every_frame: bool) -> Result<(), JsValue> {
+        let update = if every_frame {
+            ProbeUpdate::EveryFrame
+        } else {
+            ProbeUpdate::OnDemand
+        };
+
+        self.scene.borrow_mut().set_reflection_probe(Some(size), update).map_err(JsValue::from)
+    }
+
+    #[wasm_bindgen(js_name = disableDynamicReflection)]
+    pub fn disable_dynamic_reflection(&self) -> Result<(), JsValue> {
+        self.scene
+            .borrow_mut()
+            .set_reflection_probe(None, ProbeUpdate::OnDemand)
+            .map_err(JsValue::from)
+    }
+
+    #[wasm_bindgen(js_name = updateDynamicReflection)]
+    pub fn update_dynamic_reflection(&self) {
+        self.scene.borrow_mut().request_reflection_update();
+    }
+
+    // png / jpeg などの画像データを 6 面に使う
+    #[wasm_bindgen(js_name = setEnvironmentMap)]
+    pub fn set_environment_map(&self, image: &[u8]) -> Result<(), JsValue> {
+        self.scene.borrow_mut().set_environment(&[image; 6]).map_err(JsValue::from)
+    }
+
+    #[wasm_bindgen(js_name = setEnvironmentFaces)]
+    pub fn set_environment_faces(
+        &self,
+        positive_x: &[u8],
+        negative_x: &[u8],
+        positive_y: &[u8],
+        negative_y: &[u8],
+        positive_z: &[u8],
+        negative_z: &[u8],
+    ) -> Result<(), JsValue> {
+        self.scene.borrow_mut().set_environment(&[
+            positive_x, negative_x, positive_y, negative_y, positive_z, negative_z,
+        ])
+        .map_err(JsValue::from)
+    }
+
+    // 正距円筒図法のパノラマ (.hdr / .png など) を face_size x face_size の 6 面に変換して使う
+    #[wasm_bindgen(js_name = setEnvironmentPanorama)]
+    pub fn set_environment_panorama(&self, image: &[u8], face_size: u32) -> Result<(), JsValue> {
+        let panorama = Panorama::decode(image)?;
+        let faces = equirect::to_cubemap(&panorama, face_size.max(1));
+
+        self.scene.borrow_mut().set_environment_faces(&faces).map_err(JsValue::from)
+    }
+
+    // +X, -X, +Y, -Y, +Z, -Z の順の URL を取得して環境マップにする
+    #[wasm_bindgen(js_name = loadEnvironment)]
+    pub fn load_environment(&self, urls: js_sys::Array) -> js_sys::Promise {
+        // 文字列でない要素を飛ばすと面が足りないまま読んでしまうので、Promise を reject する
+        let urls: Result<Vec<String>, JsValue> = urls
+            .iter()
+            .enumerate()
+            .map(|(i, url)| url.as_string().ok_or_else(|| JsValue::from(format!("url {} is not a string", i))))
+            .collect();
+        let scene = self.scene.clone();
+
+        wasm_bindgen_futures::future_to_promise(async move {
+            let urls = urls?;
+            let sources = texture::fetch_faces(&urls).await?;
+            let sources: Vec<&[u8]> = sources.iter().map(|source| source.as_slice()).collect();
+            scene.borrow_mut().set_environment(&sources)?;
+
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    pub fn render(&self) -> Result<(), JsValue> {
+        self.animation.redraw()
+    }
+
+    pub fn pause(&self) -> Result<(), JsValue> {
+        self.animation.pause()
+    }
+
+    pub fn resume(&self) -> Result<(), JsValue> {
+        self.animation.resume()
+    }
+
+    pub fn stop(&self) -> Result<(), JsValue> {
+        self.animation.stop()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn paused(&self) -> bool {
+        self.animation.paused()
+    }
+}
+
+fn vec3(values: &[f32]) -> Result<nalgebra_glm::Vec3, JsValue> {
+    if values.len() != 3 {
+        return Err(JsValue::from(format!("expected 3 elements, got {}", values.len())));
+    }
+
+    Ok(nalgebra_glm::make_vec3(values))
+}
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use crate::geometry::Geometry;
+
+pub struct Welded {
+    pub geometry: Geometry,
+    pub before: usize,
+    pub after: usize,
+}
+
+// position / normal / uv が全て一致する頂点を 1 つにまとめ、index を張り直す
+pub fn weld(geometry: &Geometry) -> Welded {
+    let mut welded = Geometry::default();
+    let mut table: HashMap<[u32; 8], u16> = HashMap::new();
+
+    let has_normal = !geometry.normal.is_empty();
+    let has_uv = !geometry.uv.is_empty();
+
+    for &index in geometry.index.iter() {
+        let i = index as usize;
+        let position = &geometry.vertex[i * 3..i * 3 + 3];
+        let normal = if has_normal { &geometry.normal[i * 3..i * 3 + 3] } else { &[0.0; 3] };
+        let uv = if has_uv { &geometry.uv[i * 2..i * 2 + 2] } else { &[0.0; 2] };
+
+        let mut key = [0u32; 8];
+        for (k, v) in key.iter_mut().zip(position.iter().chain(normal).chain(uv)) {
+            // -0.0 と 0.0 を同一視する
+            *k = if *v == 0.0 { 0 } else { v.to_bits() };
+        }
+
+        let next = welded.vertex_count() as u16;
+        let shared = *table.entry(key).or_insert_with(|| {
+            welded.vertex.extend_from_slice(position);
+            if has_normal {
+                welded.normal.extend_from_slice(normal);
+            }
+            if has_uv {
+                welded.uv.extend_from_slice(uv);
+            }
+            next
+        });
+        welded.index.push(shared);
+    }
+
+    Welded {
+        before: geometry.vertex_count(),
+        after: welded.vertex_count(),
+        geometry: welded,
+    }
+}
@@ -0,0 +1,7 @@
+use teapot::equirect::Panorama;
+
+#[test]
+fn decode_rejects_empty_panorama() {
+    let source = b"#?RADIANCE\nFORMAT=32-bit_rle_rgbe\n\n-Y 0 +X 0\n";
+    assert_eq!(Panorama::decode(source).err().as_deref(), Some("empty panorama"));
+}
@@ -0,0 +1,85 @@
+use teapot::recording::{Command, Handle, Recorder, UniformValue};
+use teapot::scene::Scene;
+
+// Scene を作ってから 1 フレーム描き、render が出した命令だけを残す
+fn render() -> (Scene<Recorder>, Recorder) {
+    let recorder = Recorder::with_extensions(&["WEBGL_depth_texture"]);
+    let mut scene = Scene::new_with_context(320, 240, &recorder).unwrap();
+    recorder.clear_commands();
+    scene.render(0.0, 0.0).unwrap();
+
+    (scene, recorder)
+}
+
+// program の fragment shader の library での名前
+fn fragment(recorder: &Recorder, program: Handle) -> String {
+    recorder
+        .program_labels(program)
+        .into_iter()
+        .find(|label| label.ends_with(".frag"))
+        .unwrap_or_default()
+}
+
+#[test]
+fn render_draws_shadow_teapot_and_skybox() {
+    let (_, recorder) = render();
+    let commands = recorder.commands();
+
+    assert_eq!(recorder.draw_count(), 3);
+
+    let programs: Vec<String> = commands
+        .iter()
+        .filter_map(|c| match c {
+            Command::UseProgram(Some(program)) => Some(fragment(&recorder, *program)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(programs, ["depth.frag", "blinn_phong.frag", "skybox.frag"]);
+
+    // 影は shadow map の framebuffer に、残りは画面に描く
+    let mut framebuffer = None;
+    let mut targets = Vec::new();
+    for command in commands.iter() {
+        match command {
+            Command::BindFramebuffer { framebuffer: bound, .. } => framebuffer = *bound,
+            Command::DrawElements { .. } | Command::DrawArrays { .. } => targets.push(framebuffer.is_some()),
+            _ => {}
+        }
+    }
+    assert_eq!(targets, [true, false, false]);
+}
+
+#[test]
+fn render_uploads_teapot_mvp_matrix() {
+    let (scene, recorder) = render();
+
+    let expected = {
+        let camera = scene.camera();
+        let camera = camera.borrow();
+        let world = scene.graph().get(scene.teapot()).unwrap().world_matrix();
+        camera.projection_matrix() * camera.view_matrix() * world
+    };
+
+    // 1 つ目は影の pass で光源から見た行列
+    let values = recorder.uniform_values("uMVPMatrix");
+    assert_eq!(values.len(), 2);
+    match values.last() {
+        Some(UniformValue::Mat4(matrix)) => {
+            for (a, e) in matrix.iter().zip(expected.as_slice()) {
+                assert!((a - e).abs() < 1e-4, "{:?} != {:?}", matrix, expected.as_slice());
+            }
+        }
+        other => panic!("uMVPMatrix is not a mat4: {:?}", other),
+    }
+}
+
+// 失敗は JsValue を作らずに Err で返るので、wasm 以外でもそのまま調べられる
+#[test]
+fn set_environment_with_undecodable_bytes_is_an_error() {
+    let recorder = Recorder::new();
+    let mut scene = Scene::new_with_context(8, 8, &recorder).unwrap();
+    let garbage: &[u8] = b"not an image";
+
+    let error = scene.set_environment(&[garbage; 6]).unwrap_err();
+    assert!(error.starts_with("positive_x: "), "{}", error);
+}
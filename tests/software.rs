@@ -0,0 +1,121 @@
+use image::{Rgba, RgbaImage};
+use nalgebra_glm::{self as glm, Mat4, Vec3};
+use web_sys::WebGlRenderingContext;
+
+use teapot::backend::Backend;
+use teapot::cube;
+use teapot::mesh::{Attribute, Mesh};
+use teapot::shader;
+use teapot::skybox::Skybox;
+use teapot::software::Software;
+use teapot::texture::{self, Texture};
+
+const SIZE: u32 = 32;
+const CLEAR: [u8; 4] = [0, 0, 0, 255];
+
+// +X, -X, +Y, -Y, +Z, -Z の順に面ごとの単色
+const FACE_COLORS: [[u8; 4]; 6] = [
+    [255, 0, 0, 255],
+    [0, 255, 0, 255],
+    [0, 0, 255, 255],
+    [255, 255, 0, 255],
+    [0, 255, 255, 255],
+    [255, 0, 255, 255],
+];
+
+// viewer と同じ深度の設定で、単色の面の cubemap を用意する
+fn setup(depth_func: u32) -> (Software, Texture<Software>) {
+    let gl = Software::new(SIZE, SIZE);
+    gl.clear_depth(1.0);
+    gl.enable(WebGlRenderingContext::DEPTH_TEST);
+    gl.depth_func(depth_func);
+
+    let faces: Vec<RgbaImage> = FACE_COLORS.iter().map(|&c| RgbaImage::from_pixel(4, 4, Rgba(c))).collect();
+    let cubemap = texture::create_cubemap(&gl, &faces).unwrap();
+
+    (gl, cubemap)
+}
+
+fn clear(gl: &Software) {
+    gl.clear_color(0.0, 0.0, 0.0, 1.0);
+    gl.clear(WebGlRenderingContext::COLOR_BUFFER_BIT | WebGlRenderingContext::DEPTH_BUFFER_BIT);
+}
+
+fn projection() -> Mat4 {
+    glm::perspective(1.0, std::f32::consts::FRAC_PI_3, 0.1, 10.0)
+}
+
+fn pixel(gl: &Software, x: u32, y: u32) -> [u8; 4] {
+    gl.image().get_pixel(x, y).0
+}
+
+#[test]
+fn skybox_samples_the_face_in_the_view_direction() {
+    let (gl, cubemap) = setup(WebGlRenderingContext::LEQUAL);
+    let skybox = Skybox::new(&gl).unwrap();
+
+    let directions = [
+        (Vec3::x(), Vec3::y()),
+        (-Vec3::x(), Vec3::y()),
+        (Vec3::y(), Vec3::z()),
+        (-Vec3::y(), Vec3::z()),
+        (Vec3::z(), Vec3::y()),
+        (-Vec3::z(), Vec3::y()),
+    ];
+    for (face, (direction, up)) in directions.iter().enumerate() {
+        clear(&gl);
+        let view = glm::look_at(&Vec3::zeros(), direction, up);
+        skybox.render(&view, &projection(), Some(&cubemap)).unwrap();
+
+        assert_eq!(pixel(&gl, SIZE / 2, SIZE / 2), FACE_COLORS[face], "face {}", face);
+    }
+}
+
+// skybox の深度はちょうど 1.0 なので、1.0 で clear した後は LESS では描かれない
+#[test]
+fn skybox_passes_the_depth_test_only_with_lequal() {
+    for &(func, expected) in [(WebGlRenderingContext::LESS, CLEAR), (WebGlRenderingContext::LEQUAL, FACE_COLORS[5])].iter() {
+        let (gl, cubemap) = setup(func);
+        let skybox = Skybox::new(&gl).unwrap();
+
+        clear(&gl);
+        let view = glm::look_at(&Vec3::zeros(), &-Vec3::z(), &Vec3::y());
+        skybox.render(&view, &projection(), Some(&cubemap)).unwrap();
+
+        assert_eq!(pixel(&gl, SIZE / 2, SIZE / 2), expected);
+    }
+}
+
+// MVP で画面の中央 1/2 に縮めた立方体を描き、後から描く skybox が手前の立方体を上書きしないか
+#[test]
+fn cube_is_placed_by_the_mvp_matrix_and_occludes_the_skybox() {
+    let (gl, cubemap) = setup(WebGlRenderingContext::LEQUAL);
+    let skybox = Skybox::new(&gl).unwrap();
+
+    let program = shader::build_program(&gl, "depth.vert", "depth.frag", &[]).unwrap();
+    let mut mesh = Mesh::new(&gl, WebGlRenderingContext::TRIANGLES);
+    mesh.add_vertex_buffer(cube::VERTEX, vec![Attribute::float("aPosition", 3)]).unwrap();
+    mesh.set_index(cube::INDEX).unwrap();
+
+    clear(&gl);
+    let mvp = glm::scale(&Mat4::identity(), &glm::vec3(0.5, 0.5, 0.5));
+    program.bind();
+    {
+        let _binding = mesh.bind(&program);
+        program.set_mat4("uMVPMatrix", mvp.as_slice()).unwrap();
+        program.set_bool("uPackDepth", false).unwrap();
+        mesh.draw();
+    }
+
+    let view = glm::look_at(&Vec3::zeros(), &-Vec3::z(), &Vec3::y());
+    skybox.render(&view, &projection(), Some(&cubemap)).unwrap();
+
+    // 立方体は NDC の [-0.5, 0.5]、32 画素では 8..24 を覆う
+    let white = [255, 255, 255, 255];
+    for &(x, y) in [(8, 8), (16, 16), (23, 23), (8, 23), (23, 8)].iter() {
+        assert_eq!(pixel(&gl, x, y), white, "({}, {})", x, y);
+    }
+    for &(x, y) in [(7, 16), (24, 16), (16, 7), (16, 24), (0, 0), (31, 31)].iter() {
+        assert_eq!(pixel(&gl, x, y), FACE_COLORS[5], "({}, {})", x, y);
+    }
+}